@@ -6,6 +6,7 @@ fn main() {
     println!("Hello, Rust world!");
     
     // VARIABLES AND MUTABILITY
+    println!("\n--- Variables and Mutability ---");
     // By default, variables in Rust are immutable (cannot be changed)
     let x = 5;
     println!("The value of x is: {}", x);
@@ -20,6 +21,7 @@ fn main() {
     println!("The new value of y is: {}", y);
     
     // VARIABLE SHADOWING
+    println!("\n--- Variable Shadowing ---");
     // You can declare a new variable with the same name as a previous variable
     let z = 20;        // Create first variable 'z' with value 20
     let z = z + 5;     // Create NEW variable 'z' with value (old z + 5) = 25
@@ -42,17 +44,22 @@ fn main() {
     //     Learning/prototyping: When you're just experimenting
     //     When you're 100% sure it won't fail: Very rare in real code
     //     Examples and tutorials: To keep code simple
+    // tools/prompt.rs runs this same pipeline on real user input without unwrap():
+    // bad input gets an explanation and a second chance instead of a crash.
 
 
     // Stack: Fast, limited size, automatic cleanup, stores fixed-size data
     // Heap: Slower, large size, manual management, stores variable-size data
+    // To see real stack and heap addresses for a String, run lesson 3 as: ./lesson3 memory
     
     // CONSTANTS
+    println!("\n--- Constants ---");
     // Constants are always immutable and must have a type annotation
     const MAX_POINTS: u32 = 100_000;
     println!("Maximum points: {}", MAX_POINTS);
     
     // DATA TYPES
+    println!("\n--- Data Types ---");
     // Rust is statically typed, but can often infer types
     
     // Integers
@@ -88,6 +95,7 @@ fn main() {
     println!("Emoji: {}", emoji);
     
     // STRING TYPES
+    println!("\n--- String Types ---");
     // String literals (stored in the program binary)
     // "Stored in program binary" means the string is embedded directly in your executable file and loaded into a read-only section of memory when your program starts.
     let greeting = "Hello, Rust!";
@@ -99,6 +107,7 @@ fn main() {
     println!("Dynamic string: {}", dynamic_string);
     
     // BASIC ARITHMETIC
+    println!("\n--- Basic Arithmetic ---");
     let sum = 5 + 10;
     let difference = 95.5 - 4.3;
     let product = 4 * 30;
@@ -122,10 +131,10 @@ fn main() {
 }
 
 // LESSON 1 SUMMARY:
-// - Every Rust program starts with a main() function
-// - Variables are immutable by default, use 'mut' to make them mutable
-// - Variable shadowing allows reusing variable names
-// - Constants are always immutable and need type annotations
-// - Rust has various data types: integers, floats, booleans, characters, strings
-// - println! macro is used for printing (note the exclamation mark!)
-// - Rust is statically typed but can infer types in many cases
+// - Every Rust program starts with a main() function (see Variables and Mutability)
+// - Variables are immutable by default, use 'mut' to make them mutable (see Variables and Mutability)
+// - Variable shadowing allows reusing variable names (see Variable Shadowing)
+// - Constants are always immutable and need type annotations (see Constants)
+// - Rust has various data types: integers, floats, booleans, characters, strings (see Data Types)
+// - println! macro is used for printing (note the exclamation mark!) (see Variables and Mutability)
+// - Rust is statically typed but can infer types in many cases (see Data Types)
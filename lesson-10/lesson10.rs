@@ -0,0 +1,269 @@
+// Lesson 10: Closures and Iterator Adaptors
+// To compile and run: rustc lesson10.rs && ./lesson10 (or lesson10.exe on Windows)
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== Lesson 10: Closures and Iterator Adaptors ===");
+
+    // CLOSURES - Functions without a name that can use variables around them
+    // |parameters| body. Types are usually inferred, like with let.
+    println!("\n--- Closures ---");
+
+    let double = |x: i32| x * 2;
+    let add = |a, b| a + b; // types inferred from the first call below
+    println!("double(4) = {}, add(2, 3) = {}", double(4), add(2, 3));
+
+    let bonus = 5;
+    let with_bonus = |score: i32| score + bonus; // captures bonus from main
+    println!("85 with bonus: {}", with_bonus(85));
+
+    // FN, FNMUT AND FNONCE - How a closure uses what it captures
+    // Fn     only reads captured values   -> can be called many times, even at once
+    // FnMut  changes captured values      -> can be called many times, one at a time
+    // FnOnce moves captured values out    -> can be called once
+    // Like &T, &mut T and T for function parameters (lesson 3).
+    println!("\n--- Fn, FnMut and FnOnce ---");
+
+    let greeting = String::from("Hello");
+    let greet = |name: &str| format!("{}, {}!", greeting, name); // Fn: reads greeting
+    println!("{}", call_twice(greet, "Rust"));
+
+    let mut clicks = 0;
+    let mut click = || clicks += 1; // FnMut: changes clicks
+    repeat(3, &mut click);
+    println!("clicks after repeat(3): {}", clicks);
+
+    let report = String::from("final report");
+    let send = move || report; // FnOnce: gives its captured String away
+    println!("sent: {}", consume(send));
+    // send(); // ERROR E0382: use of moved value: `send` (it was already called once)
+
+    // MOVE CLOSURES - Take ownership of captured values
+    // Without move, a closure borrows; with move, it owns what it captures.
+    // Needed when the closure outlives the variables (threads, returned closures).
+    println!("\n--- move Closures ---");
+
+    let name = String::from("Ferris");
+    let owns_name = move || format!("{} owns this name now", name);
+    println!("{}", owns_name());
+    // println!("{}", name); // ERROR E0382: borrow of moved value: `name`
+
+    // ITERATOR ADAPTORS - Build a pipeline, then run it
+    // map, filter, enumerate, zip, take, skip... each returns a new iterator.
+    // They are lazy: nothing runs until a consumer like sum, count or collect.
+    println!("\n--- Iterator Adaptors ---");
+
+    let grades = vec![85, 92, 78, 96, 88];
+    let curved: Vec<i32> = grades.iter().map(|g| (g + 5).min(100)).collect();
+    println!("curved: {:?}", curved);
+
+    let high: Vec<&i32> = grades.iter().filter(|&&g| g >= 90).collect();
+    println!("90 and above: {:?}", high);
+
+    let subjects = ["math", "art", "history", "music", "science"];
+    for (i, (subject, grade)) in subjects.iter().zip(&grades).enumerate().skip(1).take(2) {
+        println!("{}. {} = {}", i + 1, subject, grade);
+    }
+
+    // Lazy: the map closure hasn't run yet, so nothing is printed here...
+    let pipeline = grades.iter().map(|g| {
+        println!("  looking at {}", g);
+        g * 2
+    });
+    println!("pipeline built, now running it:");
+    let first_big = pipeline.take_while(|&g| g < 180).count(); // ...until count() pulls values
+    println!("doubled grades before the first one of 180 or more: {}", first_big);
+
+    // EARLIER LOOPS, REWRITTEN - The same results with iterator chains
+    println!("\n--- Earlier Loops, Rewritten ---");
+
+    println!("average (loop):     {:.1}", calculate_average_loop(&grades));
+    println!("average (iterator): {:?}", calculate_average(&grades).map(|a| (a * 10.0).round() / 10.0));
+    println!("average of nothing: {:?}", calculate_average(&[]));
+
+    let numbers = [7, 13, 4, 9, 10];
+    println!("first even (loop):     {:?}", find_first_even_loop(&numbers));
+    println!("first even (iterator): {:?}", find_first_even(&numbers));
+
+    // Lesson 3's count_items is just len(); counting only some items is where
+    // a loop grows, and an iterator takes the test as a closure
+    let fruits = vec!["apple", "banana", "cherry", "avocado"];
+    println!("count_items: {}", count_items(&fruits));
+    println!("starting with 'a' (loop):     {}", count_starting_with_loop(&fruits, 'a'));
+    println!("starting with 'a' (iterator): {}", count_where(&fruits, |f| f.starts_with('a')));
+
+    // RETURNING CLOSURES - impl Fn and Box<dyn Fn>
+    // Every closure has its own unnamed type. impl Fn says "some closure
+    // type"; Box<dyn Fn> is needed when different closures may come back.
+    println!("\n--- Returning Closures ---");
+
+    let add_ten = make_adder(10);
+    println!("add_ten(5) = {}", add_ten(5));
+
+    let strict = make_grader(true);
+    let lenient = make_grader(false);
+    println!("88.5 strict: {}, lenient: {}", strict(88.5), lenient(88.5));
+
+    // Closures stored in a Vec, applied in order
+    let steps: Vec<Box<dyn Fn(i32) -> i32>> = vec![Box::new(|x| x + 1), Box::new(|x| x * 10), Box::new(make_adder(-3))];
+    let result = steps.iter().fold(4, |value, step| step(value));
+    println!("4 -> +1 -> *10 -> -3 = {}", result);
+
+    // EVENT CALLBACKS - Closures stored in a struct, called later
+    println!("\n--- Event Callbacks ---");
+
+    let mut events = EventBus::new();
+    let saved = Rc::new(RefCell::new(Vec::new())); // shared with a callback (lesson 9)
+
+    events.on("click", |data| println!("  logger: click at {}", data));
+    let log = Rc::clone(&saved);
+    events.on("save", move |data| log.borrow_mut().push(data.to_string()));
+    let mut count = 0;
+    events.on("click", move |_| {
+        count += 1; // each callback owns its own state
+        println!("  counter: click number {}", count);
+    });
+
+    for (event, data) in [("click", "(10, 20)"), ("save", "notes.txt"), ("click", "(5, 5)"), ("quit", "")] {
+        let handled = events.emit(event, data);
+        println!("{} handled by {} callback(s)", event, handled);
+    }
+    println!("saved files: {:?}", saved.borrow());
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Use map and collect to turn the grades into letter grades");
+    println!("2. Write make_multiplier(n) -> impl Fn(i32) -> i32 and use it with map");
+    println!("3. Add an off(event) method to EventBus that removes an event's callbacks");
+    println!("4. Rewrite find_max from the grader exercises with iter().max() and copied()");
+
+    println!("\n--- End of Lesson 10 ---");
+    println!("Next lesson will cover: Modules, Visibility and Crates");
+}
+
+// CALLING CLOSURES
+// Each bound asks for the least the function needs
+fn call_twice<F: Fn(&str) -> String>(f: F, name: &str) -> String {
+    format!("{} / {}", f(name), f(name))
+}
+
+fn repeat<F: FnMut()>(times: usize, mut f: F) {
+    for _ in 0..times {
+        f();
+    }
+}
+
+fn consume<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// LESSON 2'S calculate_average
+// With a loop, the way lesson 2 computes the sum by hand
+fn calculate_average_loop(numbers: &[i32]) -> f64 {
+    let mut sum = 0;
+    for n in numbers {
+        sum += n;
+    }
+    sum as f64 / numbers.len() as f64 // NaN for an empty list
+}
+
+// With an iterator; None for an empty list instead of NaN
+fn calculate_average(numbers: &[i32]) -> Option<f64> {
+    if numbers.is_empty() {
+        return None;
+    }
+    Some(numbers.iter().sum::<i32>() as f64 / numbers.len() as f64)
+}
+
+// LESSON 4'S find_first_even
+// Clippy would suggest find() here: that is what the next function does
+#[allow(clippy::manual_find)]
+fn find_first_even_loop(numbers: &[i32]) -> Option<i32> {
+    for &n in numbers {
+        if n % 2 == 0 {
+            return Some(n);
+        }
+    }
+    None
+}
+
+fn find_first_even(numbers: &[i32]) -> Option<i32> {
+    numbers.iter().copied().find(|n| n % 2 == 0)
+}
+
+// LESSON 3'S count_items
+fn count_items<T>(items: &[T]) -> usize {
+    items.len()
+}
+
+fn count_starting_with_loop(items: &[&str], letter: char) -> usize {
+    let mut count = 0;
+    for item in items {
+        if item.starts_with(letter) {
+            count += 1;
+        }
+    }
+    count
+}
+
+// Counting only some items: the test is a closure passed in by the caller
+fn count_where<T>(items: &[T], test: impl Fn(&T) -> bool) -> usize {
+    items.iter().filter(|item| test(item)).count()
+}
+
+// RETURNING CLOSURES
+// move: the closure keeps its own copy of n after make_adder returns
+fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// The two closures have different types, so they are boxed as dyn Fn
+fn make_grader(strict: bool) -> Box<dyn Fn(f64) -> char> {
+    if strict {
+        Box::new(|average| if average >= 90.0 { 'A' } else { 'B' })
+    } else {
+        Box::new(|average| if average >= 85.0 { 'A' } else { 'B' })
+    }
+}
+
+// EVENT CALLBACKS
+// Each callback is boxed, because every closure has a different type.
+// FnMut so callbacks may keep state; 'static so they can't borrow locals.
+type Handler = Box<dyn FnMut(&str)>;
+
+struct EventBus {
+    handlers: Vec<(String, Handler)>,
+}
+
+impl EventBus {
+    fn new() -> EventBus {
+        EventBus { handlers: Vec::new() }
+    }
+
+    fn on(&mut self, event: &str, handler: impl FnMut(&str) + 'static) {
+        self.handlers.push((event.to_string(), Box::new(handler)));
+    }
+
+    // Calls every callback registered for the event; returns how many ran
+    fn emit(&mut self, event: &str, data: &str) -> usize {
+        let mut called = 0;
+        for (name, handler) in self.handlers.iter_mut() {
+            if name == event {
+                handler(data);
+                called += 1;
+            }
+        }
+        called
+    }
+}
+
+// LESSON 10 SUMMARY:
+// - Closures |x| x + 1 are functions that can capture variables around them (see Closures)
+// - Fn reads captures, FnMut changes them, FnOnce moves them out (callable once) (see Fn, FnMut and FnOnce)
+// - move makes a closure own what it captures, so it can outlive the variables (see move Closures)
+// - Iterator adaptors (map, filter, zip, take...) are lazy until a consumer runs them (see Iterator Adaptors)
+// - find, count, sum and fold replace most hand-written search and count loops (see Earlier Loops, Rewritten)
+// - Return a closure as impl Fn, or Box<dyn Fn> when different closures may come back (see Returning Closures)
+// - Store callbacks as Box<dyn FnMut> to call them later, like an event system (see Event Callbacks)
@@ -0,0 +1,46 @@
+// Lesson 11: the accounts module (`mod accounts;` in lesson11.rs)
+// A file named accounts.rs next to lesson11.rs IS the module: no `mod { }`
+// braces here, its contents are the module's contents.
+
+// User from lesson 4, with private fields. Code outside this module can't
+// read or set name, age or active directly, and can't write User { ... }:
+// the only way to get a User is User::new, so every User is a valid one.
+pub struct User {
+    name: String,
+    age: u32,
+    active: bool,
+}
+
+impl User {
+    // Lesson 4's exercise 2: a constructor that sets active to true
+    pub fn new(name: &str, age: u32) -> User {
+        User { name: clean_name(name), age, active: true }
+    }
+
+    // Getters give read-only access; returning &str keeps the String private
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    // Another user with the same age and status, like lesson 4's ..user
+    pub fn with_name(&self, name: &str) -> User {
+        User { name: clean_name(name), age: self.age, active: self.active }
+    }
+}
+
+// Private: only code in this module (and modules inside it) can call it
+fn clean_name(name: &str) -> String {
+    name.trim().to_string()
+}
@@ -0,0 +1,19 @@
+// Lesson 11: the geometry module (`mod geometry;` in lesson11.rs)
+// A module with modules inside it: geometry.rs is the module itself, and the
+// geometry/ folder holds its children. `mod rectangle;` here loads
+// geometry/rectangle.rs. (Older code uses geometry/mod.rs instead of
+// geometry.rs; both work, but not both at once.)
+
+pub mod rectangle; // public: other modules may write geometry::rectangle::...
+mod checks; // private: only geometry and its children can use it
+
+// RE-EXPORT
+// Callers can write geometry::Rectangle instead of geometry::rectangle::Rectangle.
+// It is the same type under a shorter path, not a copy. (self:: because plain
+// rustc uses the 2015 edition, where use paths start at the crate root.)
+pub use self::rectangle::Rectangle;
+
+// Sums the areas of several rectangles
+pub fn total_area(rectangles: &[Rectangle]) -> u32 {
+    rectangles.iter().map(|r| r.area()).sum()
+}
@@ -0,0 +1,12 @@
+// Lesson 11: geometry::checks, a private helper module
+// Nothing outside geometry can reach this module, because geometry
+// declares it with a plain `mod checks;`.
+
+// pub(super): visible to the parent module (geometry) and everything in it,
+// including geometry::rectangle, but no further
+pub(super) fn is_valid_side(side: u32) -> bool {
+    side > 0 && side <= MAX_SIDE
+}
+
+// Private to this file; is_valid_side can use it, rectangle.rs can't
+const MAX_SIDE: u32 = 10_000;
@@ -0,0 +1,55 @@
+// Lesson 11: geometry::rectangle (`pub mod rectangle;` in geometry.rs)
+
+// super:: is the parent module (geometry), like .. for folders
+use super::checks;
+
+// Rectangle from lesson 4, with private fields. Rectangle::new checks the
+// sides, and since nothing outside this module can set width or height,
+// no Rectangle can ever have a side of 0.
+#[derive(Debug)]
+pub struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    // None if a side is 0 or too big
+    pub fn new(width: u32, height: u32) -> Option<Rectangle> {
+        if checks::is_valid_side(width) && checks::is_valid_side(height) {
+            Some(Rectangle { width, height })
+        } else {
+            None
+        }
+    }
+
+    pub fn square(size: u32) -> Option<Rectangle> {
+        Rectangle::new(size, size)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+
+    // Lesson 4's exercise 1
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    // pub(crate): usable anywhere in this program (the crate), but it would
+    // stay hidden from other crates if geometry were part of a library
+    pub(crate) fn describe(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+}
@@ -0,0 +1,167 @@
+// Lesson 11: Modules, Visibility and Crates
+// To compile and run: rustc lesson11.rs && ./lesson11 (or lesson11.exe on Windows)
+
+// This lesson is spread over several files. rustc only needs lesson11.rs:
+// each `mod name;` line below tells it to read name.rs from this folder.
+//   lesson11.rs              the crate root: main and the module declarations
+//   accounts.rs              mod accounts   (User)
+//   messages.rs              mod messages   (Message)
+//   geometry.rs              mod geometry   (re-exports Rectangle)
+//   geometry/rectangle.rs    mod geometry::rectangle
+//   geometry/checks.rs       mod geometry::checks (private)
+//   workspace/               a library crate and a program that uses it
+
+mod accounts;
+mod geometry;
+mod messages;
+
+// USE - Bring a path into scope so it can be written with a short name
+use accounts::User;
+use geometry::Rectangle; // the re-export; geometry::rectangle::Rectangle also works
+use messages::Message;
+
+fn main() {
+    println!("=== Lesson 11: Modules, Visibility and Crates ===");
+
+    // MODULES IN ONE FILE - mod name { ... } groups items under a name
+    // Everything in a module is private unless marked pub. Private means
+    // "only this module and the modules inside it can use it".
+    println!("\n--- Modules in One File ---");
+
+    println!("grading::letter(85) = {}", grading::letter(85));
+    println!("grading::letter(42) = {}", grading::letter(42));
+    println!("grading::PASS_MARK = {}", grading::PASS_MARK);
+    // grading::is_passing(85); // ERROR E0603: function `is_passing` is private
+
+    // MODULES IN OTHER FILES - mod name; loads name.rs
+    // The file's contents become the module, exactly as if they were written
+    // inside mod name { ... } here. Items are reached with module::item.
+    println!("\n--- Modules in Other Files ---");
+
+    let messages = [Message::Move { x: 10, y: 20 }, Message::Text(String::from("hello")), Message::Quit];
+    for message in &messages {
+        println!("{}", message.describe());
+    }
+
+    // PRIVATE FIELDS AND PUBLIC CONSTRUCTORS - Keep a type's data valid
+    // Fields are private by default even in a pub struct. Outside its module,
+    // a User can only be made with User::new and read through its methods.
+    println!("\n--- Private Fields and Public Constructors ---");
+
+    let mut alice = User::new("  Alice ", 30);
+    println!("{} (age {}), active: {}", alice.name(), alice.age(), alice.is_active());
+    alice.deactivate();
+    let bob = alice.with_name("Bob");
+    println!("{} (age {}), active: {}", bob.name(), bob.age(), bob.is_active());
+    // println!("{}", alice.name); // ERROR E0616: field `name` of struct `User` is private
+    // let eve = User { name: String::from("Eve"), age: 25, active: true }; // ERROR E0451: field `name` of struct `User` is private
+
+    // Rectangle::new refuses sides of 0, and the private fields mean there is
+    // no other way to build one, so every Rectangle has a real area
+    for (width, height) in [(10, 5), (0, 5), (4, 4)] {
+        match Rectangle::new(width, height) {
+            Some(rect) => println!("{}x{}: area {}, square: {}", rect.width(), rect.height(), rect.area(), rect.is_square()),
+            None => println!("{}x{}: not a valid rectangle", width, height),
+        }
+    }
+
+    // PATHS - Naming an item from anywhere in the crate
+    // crate::   starts at the crate root (this file)
+    // self::    starts at the current module
+    // super::   starts at the parent module
+    // Other paths start in the current module, like a relative path; but in
+    // a use line, plain rustc (the 2015 edition) starts them at the crate root.
+    println!("\n--- Paths and use ---");
+
+    let big = Rectangle::new(10, 5).unwrap();
+    let small = crate::geometry::Rectangle::square(4).unwrap(); // absolute path
+    println!("big holds small: {}", big.can_hold(&small));
+    println!("grading::report(&big) = {}", grading::report(&big)); // report uses super::
+
+    // `use ... as` renames an import, for clashes or clearer names
+    use geometry::rectangle::Rectangle as Rect;
+    let rects: Vec<Rect> = vec![big, small, Rectangle::new(3, 2).unwrap()];
+    println!("total area: {}", geometry::total_area(&rects)); // Rect and Rectangle are the same type
+
+    // RE-EXPORTS - pub use makes an item available under another path
+    // geometry.rs has `pub use self::rectangle::Rectangle;`, so callers don't need
+    // to know the rectangle module exists. Libraries do this to offer a short,
+    // stable path while keeping their files organized however they like.
+    println!("\n--- Re-exports with pub use ---");
+
+    let via_reexport: geometry::Rectangle = Rectangle::square(2).unwrap();
+    let via_full_path: geometry::rectangle::Rectangle = via_reexport;
+    println!("geometry::Rectangle and geometry::rectangle::Rectangle: {:?}", via_full_path);
+    // geometry::checks::is_valid_side(5); // ERROR E0603: module `checks` is private
+
+    // PUB(CRATE) AND PUB(SUPER) - Visible to part of the program
+    // pub          anyone, including other crates that use this one
+    // pub(crate)   anywhere in this crate, never outside it
+    // pub(super)   the parent module and everything in it
+    // (nothing)    this module and its children
+    println!("\n--- pub(crate) and pub(super) ---");
+
+    // Rectangle::describe is pub(crate), so main can call it...
+    println!("described inside the crate: {}", rects[0].describe());
+    // ...and checks::is_valid_side is pub(super): rectangle.rs may call it, main may not
+    println!("Rectangle::new(20000, 1) is {:?} (sides are checked in geometry::checks)", Rectangle::new(20000, 1));
+
+    // CRATES - The unit rustc compiles: a binary (has main) or a library
+    // Everything above is one binary crate. workspace/ next to this file holds
+    // two crates: shapes (a library with Rectangle) and area-report (a binary
+    // that depends on shapes). Between crates only pub items are visible:
+    // describe() is pub(crate), so area-report can't call it.
+    println!("\n--- Library and Binary Crates ---");
+
+    println!("workspace/shapes       library crate: src/lib.rs, re-exports shapes::Rectangle");
+    println!("workspace/area-report  binary crate: src/main.rs, has `use shapes::Rectangle;`");
+    println!("Build both with Cargo:  cd workspace && cargo run -p area-report -- 10x5 4x4");
+    println!("Or with rustc alone: see workspace/README.md");
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Add a perimeter() method to Rectangle in geometry/rectangle.rs and call it from main");
+    println!("2. Move grading out of this file into grading.rs and check nothing else changes");
+    println!("3. Add a geometry/circle.rs module with a Circle type and re-export it from geometry.rs");
+    println!("4. Give shapes a pub fn largest(&[Rectangle]) -> Option<&Rectangle> and use it in area-report");
+
+    println!("\n--- End of Lesson 11 ---");
+    println!("Next lesson will cover: Testing: Unit Tests, Doc-Tests and Integration Tests");
+}
+
+// AN INLINE MODULE
+// The same thing as a grading.rs file with `mod grading;` here
+mod grading {
+    // super:: is the crate root here, so this is the Rectangle imported at the top
+    use super::Rectangle;
+
+    pub const PASS_MARK: u32 = 50;
+
+    pub fn letter(score: u32) -> char {
+        if !is_passing(score) {
+            'F'
+        } else if score >= 80 {
+            'A'
+        } else {
+            'B'
+        }
+    }
+
+    // Private: letter() can call it, main can't
+    fn is_passing(score: u32) -> bool {
+        score >= PASS_MARK
+    }
+
+    pub fn report(rect: &Rectangle) -> String {
+        format!("{} has area {}", rect.describe(), rect.area())
+    }
+}
+
+// LESSON 11 SUMMARY:
+// - mod name { ... } makes a module; mod name; loads it from name.rs (or name/mod.rs) (see Modules in One File)
+// - Child modules go in a folder named after the parent: geometry/rectangle.rs (see Modules in Other Files)
+// - Everything is private by default; pub makes it visible outside its module (see Private Fields and Public Constructors)
+// - Struct fields are private too, so a pub constructor can keep every value valid (see Private Fields and Public Constructors)
+// - Paths start with crate::, self:: or super::; use brings them into scope, as renames (see Paths and use)
+// - pub use re-exports an item under a shorter path; pub(crate) and pub(super) limit who sees it (see Re-exports with pub use)
+// - A crate is a library or a binary; a workspace builds several crates together (see Library and Binary Crates)
@@ -0,0 +1,19 @@
+// Lesson 11: the messages module (`mod messages;` in lesson11.rs)
+
+// Message from lesson 4. An enum's variants are public whenever the enum
+// is: a caller that can't see a variant couldn't match on it.
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Text(String),
+}
+
+impl Message {
+    pub fn describe(&self) -> String {
+        match self {
+            Message::Quit => String::from("Quit"),
+            Message::Move { x, y } => format!("Move to ({}, {})", x, y),
+            Message::Text(s) => format!("Text: {}", s),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Lesson 11: area-report, a binary crate that uses the shapes library
+// Run with: cargo run -p area-report -- 10x5 4x4 3x2
+
+use shapes::Rectangle; // the re-export from shapes/src/lib.rs
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        eprintln!("Usage: area-report WIDTHxHEIGHT...   e.g. area-report 10x5 4x4");
+        process::exit(2);
+    }
+
+    let mut rectangles: Vec<Rectangle> = Vec::new();
+    for arg in &args {
+        match shapes::parse(arg) {
+            Some(rect) => rectangles.push(rect),
+            None => {
+                eprintln!("Error: '{}' is not a rectangle like 10x5", arg);
+                process::exit(2);
+            }
+        }
+    }
+
+    // rect.describe() would not compile here: it is pub(crate) in shapes,
+    // but the pub function shapes::summary may call it for us
+    println!("rectangles: {}", shapes::summary(&rectangles));
+    for rect in &rectangles {
+        println!("{:>5} x {:<5} area {}", rect.width(), rect.height(), rect.area());
+    }
+    println!("total area: {}", shapes::total_area(&rectangles));
+
+    // Rectangles that fit inside every other one
+    for (i, rect) in rectangles.iter().enumerate() {
+        if rectangles.iter().all(|other| other.can_hold(rect)) {
+            println!("rectangle {} fits inside all the others", i + 1);
+        }
+    }
+}
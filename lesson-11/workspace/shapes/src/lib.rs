@@ -0,0 +1,23 @@
+// Lesson 11: the shapes library crate
+// A library has no main: it is lib.rs plus its modules, and other crates
+// use its pub items. Only what is pub here (directly or through pub use)
+// is part of the library's API.
+
+mod rectangle; // private module...
+pub use rectangle::Rectangle; // ...but its Rectangle is public as shapes::Rectangle
+
+// Sums the areas of several rectangles
+pub fn total_area(rectangles: &[Rectangle]) -> u32 {
+    rectangles.iter().map(|r| r.area()).sum()
+}
+
+// "10x5, 4x4": uses Rectangle::describe, which only this crate can call
+pub fn summary(rectangles: &[Rectangle]) -> String {
+    rectangles.iter().map(|r| r.describe()).collect::<Vec<_>>().join(", ")
+}
+
+// "10x5" -> Rectangle 10 wide and 5 high; None if it isn't two valid sides
+pub fn parse(text: &str) -> Option<Rectangle> {
+    let (width, height) = text.split_once('x')?;
+    Rectangle::new(width.trim().parse().ok()?, height.trim().parse().ok()?)
+}
@@ -0,0 +1,40 @@
+// Lesson 11: shapes::rectangle, the same Rectangle as lesson-11/geometry
+
+#[derive(Debug)]
+pub struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    // None if a side is 0
+    pub fn new(width: u32, height: u32) -> Option<Rectangle> {
+        if width > 0 && height > 0 {
+            Some(Rectangle { width, height })
+        } else {
+            None
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    // pub(crate): lib.rs and rectangle.rs may call it, area-report may not
+    // (ERROR E0624: method `describe` is private)
+    pub(crate) fn describe(&self) -> String {
+        format!("{}x{}", self.width, self.height)
+    }
+}
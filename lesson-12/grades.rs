@@ -0,0 +1,199 @@
+//! Lesson 12: functions from lessons 2, 3 and 6, with their tests.
+//!
+//! This file is a module of lesson12.rs (`mod grades;`), and on its own it is
+//! also a small library, which is what rustdoc needs to run the examples in
+//! the /// comments as doc-tests. rustdoc links each example against the
+//! compiled library, so build that first:
+//!
+//! ```text
+//! rustc --crate-type lib grades.rs && rustdoc --test grades.rs -L .
+//! ```
+//!
+//! The #[cfg(test)] module at the bottom holds the unit tests; they run with
+//! the rest of the lesson's tests (`rustc --test lesson12.rs`).
+
+/// Adds two numbers (lesson 2).
+///
+/// ```
+/// assert_eq!(grades::add_numbers(2, 3), 5);
+/// ```
+pub fn add_numbers(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+/// Divides and returns `(quotient, remainder)` (lesson 2).
+///
+/// Like `/` and `%`, it rounds toward zero, so the remainder has the same
+/// sign as the dividend, and `quotient * divisor + remainder == dividend`.
+///
+/// ```
+/// assert_eq!(grades::divide_with_remainder(17, 5), (3, 2));
+/// assert_eq!(grades::divide_with_remainder(-17, 5), (-3, -2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `divisor` is 0.
+pub fn divide_with_remainder(dividend: i32, divisor: i32) -> (i32, i32) {
+    let quotient = dividend / divisor;
+    let remainder = dividend % divisor;
+    (quotient, remainder)
+}
+
+/// The letter for an average from 0 to 100, exactly as lesson 2 wrote it.
+///
+/// The ranges leave gaps: 89.95 is in none of them, so it falls through to
+/// 'F'. The unit tests below only try "round" numbers and miss this; the
+/// property test in lesson12.rs finds it.
+///
+/// ```
+/// assert_eq!(grades::get_letter_grade(92.5), 'A');
+/// assert_eq!(grades::get_letter_grade(85.0), 'B');
+/// ```
+pub fn get_letter_grade(average: f64) -> char {
+    match average {
+        90.0..=100.0 => 'A',
+        80.0..=89.9 => 'B',
+        70.0..=79.9 => 'C',
+        60.0..=69.9 => 'D',
+        _ => 'F',
+    }
+}
+
+/// The text up to the first space, or all of it if there is no space
+/// (lesson 3's function, taking &str so it also works with literals).
+///
+/// ```
+/// use grades::get_first_word;
+///
+/// assert_eq!(get_first_word("hello world"), "hello");
+/// assert_eq!(get_first_word("rust"), "rust");
+/// ```
+///
+/// The result borrows from the input, so it is as long-lived as the String:
+///
+/// ```
+/// let sentence = String::from("borrowed, not copied");
+/// let word = grades::get_first_word(&sentence);
+/// assert_eq!(word, "borrowed,");
+/// assert_eq!(word.as_ptr(), sentence.as_ptr());
+/// ```
+///
+/// Only a space counts, not other whitespace, and a leading space gives "":
+///
+/// ```
+/// assert_eq!(grades::get_first_word("tab\tseparated"), "tab\tseparated");
+/// assert_eq!(grades::get_first_word(" leading"), "");
+/// ```
+pub fn get_first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    s
+}
+
+/// The largest item of a list (lesson 6).
+///
+/// ```
+/// assert_eq!(grades::largest(&[34, 50, 25, 100, 65]), 100);
+/// assert_eq!(grades::largest(&['y', 'm', 'a', 'q']), 'y');
+/// ```
+///
+/// # Panics
+///
+/// Panics if the list is empty: there is no largest item to return.
+///
+/// ```should_panic
+/// let nothing: [i32; 0] = [];
+/// grades::largest(&nothing);
+/// ```
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    let mut biggest = list[0];
+    for &item in list {
+        if item > biggest {
+            biggest = item;
+        }
+    }
+    biggest
+}
+
+// UNIT TESTS
+// #[cfg(test)]: only compiled by `rustc --test`, so the tests cost nothing
+// in the normal program. Being inside this file, they could test private
+// functions too. `use super::*` brings in everything above.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_numbers_adds() {
+        assert_eq!(add_numbers(2, 3), 5);
+        assert_eq!(add_numbers(-4, 4), 0);
+        assert_eq!(add_numbers(0, 0), 0);
+    }
+
+    #[test]
+    fn divide_with_remainder_exact() {
+        assert_eq!(divide_with_remainder(20, 5), (4, 0));
+    }
+
+    #[test]
+    fn divide_with_remainder_leaves_remainder() {
+        assert_eq!(divide_with_remainder(17, 5), (3, 2));
+    }
+
+    #[test]
+    fn divide_with_remainder_rounds_toward_zero() {
+        assert_eq!(divide_with_remainder(-17, 5), (-3, -2));
+        assert_eq!(divide_with_remainder(17, -5), (-3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn divide_with_remainder_by_zero_panics() {
+        divide_with_remainder(1, 0);
+    }
+
+    #[test]
+    fn letter_grade_at_each_boundary() {
+        assert_eq!(get_letter_grade(100.0), 'A');
+        assert_eq!(get_letter_grade(90.0), 'A');
+        assert_eq!(get_letter_grade(89.9), 'B');
+        assert_eq!(get_letter_grade(80.0), 'B');
+        assert_eq!(get_letter_grade(79.9), 'C');
+        assert_eq!(get_letter_grade(70.0), 'C');
+        assert_eq!(get_letter_grade(69.9), 'D');
+        assert_eq!(get_letter_grade(60.0), 'D');
+        assert_eq!(get_letter_grade(59.9), 'F');
+        assert_eq!(get_letter_grade(0.0), 'F');
+    }
+
+    #[test]
+    fn letter_grade_outside_0_to_100_is_f() {
+        assert_eq!(get_letter_grade(-5.0), 'F');
+        assert_eq!(get_letter_grade(100.5), 'F');
+    }
+
+    #[test]
+    fn first_word_of_empty_string_is_empty() {
+        assert_eq!(get_first_word(""), "");
+    }
+
+    #[test]
+    fn largest_finds_the_biggest_anywhere() {
+        assert_eq!(largest(&[9, 1, 2]), 9);
+        assert_eq!(largest(&[1, 9, 2]), 9);
+        assert_eq!(largest(&[1, 2, 9]), 9);
+        assert_eq!(largest(&[-3, -1, -2]), -1);
+        assert_eq!(largest(&[1.5, 0.5]), 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn largest_of_empty_list_panics() {
+        largest::<i32>(&[]);
+    }
+}
@@ -0,0 +1,242 @@
+// Lesson 12: Testing: Unit Tests, Doc-Tests and Integration Tests
+// To compile and run: rustc lesson12.rs && ./lesson12 (or lesson12.exe on Windows)
+
+// This lesson has tests of its own. Run each kind from this folder:
+//   unit tests:         rustc --test lesson12.rs -o lesson12-tests && ./lesson12-tests
+//   doc-tests:          rustc --crate-type lib grades.rs && rustdoc --test grades.rs -L .
+//   integration tests:  rustc --test tests/lesson_binaries.rs -o lesson-binaries && ./lesson-binaries
+// (In a Cargo project, `cargo test` runs all three kinds at once; this folder
+// has no Cargo.toml, so each is run with rustc or rustdoc as above.)
+// grades.rs holds the functions under test, property.rs the property tester.
+
+mod grades;
+mod property;
+
+use grades::{add_numbers, divide_with_remainder, get_first_word, get_letter_grade, largest};
+use std::panic;
+
+fn main() {
+    println!("=== Lesson 12: Testing: Unit Tests, Doc-Tests and Integration Tests ===");
+
+    // UNIT TESTS - Small functions marked #[test] that check one thing
+    // A test passes if it returns without panicking. assert!, assert_eq! and
+    // assert_ne! panic with a message when what they check is false.
+    // rustc --test builds a program that runs every #[test] instead of main.
+    println!("\n--- Unit Tests ---");
+
+    // The same checks the unit tests in grades.rs make:
+    assert_eq!(add_numbers(2, 3), 5);
+    assert_eq!(divide_with_remainder(17, 5), (3, 2));
+    assert_eq!(get_letter_grade(85.0), 'B');
+    println!("add_numbers(2, 3) = {}", add_numbers(2, 3));
+    println!("divide_with_remainder(17, 5) = {:?}", divide_with_remainder(17, 5));
+    println!("get_letter_grade(85.0) = {}", get_letter_grade(85.0));
+
+    // A failing assert_eq! shows both sides, and an optional message:
+    //     assert_eq!(add_numbers(2, 2), 5, "adding {} and {}", 2, 2);
+    //     panicked: assertion `left == right` failed: adding 2 and 2
+    //       left: 4
+    //      right: 5
+    // assert_eq!(divide_with_remainder(17, 5), 3); // ERROR E0308: mismatched types (a tuple is not an integer)
+
+    // TESTING PANICS - #[should_panic] passes only if the test panics
+    // expected = "..." also checks the panic message contains that text,
+    // so the test can't pass by panicking for some other reason.
+    println!("\n--- Testing Panics ---");
+
+    // What #[should_panic] sees, caught here with catch_unwind. The default
+    // panic message is switched off so only our own lines are printed.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let empty: [i32; 0] = [];
+    let results = [
+        ("largest(&[])", panic::catch_unwind(|| largest(&empty))),
+        ("largest(&[3, 8, 1])", panic::catch_unwind(|| largest(&[3, 8, 1]))),
+    ];
+    panic::set_hook(default_hook);
+    for (call, result) in &results {
+        match result {
+            Ok(value) => println!("{} returned {}", call, value),
+            Err(payload) => println!("{} panicked: {}", call, panic_message(payload.as_ref())),
+        }
+    }
+    println!("divide_with_remainder(1, 0) would panic too: attempt to divide by zero");
+
+    // DOC-TESTS - Examples in /// comments that are compiled and run
+    // rustdoc runs every ``` block in a doc comment as a test, so the examples
+    // in the documentation can never go out of date. See get_first_word in grades.rs.
+    println!("\n--- Doc-Tests ---");
+
+    for text in ["hello world", "rust", " leading", ""] {
+        println!("get_first_word({:?}) = {:?}", text, get_first_word(text));
+    }
+
+    // INTEGRATION TESTS - Test a program from the outside
+    // Unit tests sit next to the code and may use private items. Integration
+    // tests live in a separate tests/ folder and only use what the outside
+    // world can: a library's pub API, or here, running the lesson programs.
+    println!("\n--- Integration Tests ---");
+
+    println!("tests/lesson_binaries.rs compiles every lesson with rustc and runs it, checking that:");
+    println!("  - each lesson compiles and exits with status 0");
+    println!("  - it starts with its \"=== Lesson N: Title ===\" banner and ends with \"--- End of Lesson N ---\"");
+    println!("  - its \"Next lesson will cover:\" line names the next lesson's title");
+    println!("  - lesson 3's trace mode and this lesson's output look right");
+
+    // PROPERTY TESTS - Check a rule against many generated inputs
+    // property.rs generates inputs from a seeded Rng, so every run tries the
+    // same inputs and any failure can be reproduced.
+    println!("\n--- Property Tests ---");
+
+    // check() panics if the rule breaks, so it is what the tests use
+    let seed = 2024;
+    property::check(
+        "q * d + r == n",
+        seed,
+        1000,
+        |rng| (rng.range(i32::MIN + 1, i32::MAX), nonzero(rng.range(-1000, 1000))),
+        |&(n, d)| {
+            let (q, r) = divide_with_remainder(n, d);
+            q * d + r == n
+        },
+    );
+    println!("q * d + r == n: held for 1000 random (n, d) pairs");
+
+    // A rule lesson 2's grader should follow: 60 or more never gets an F.
+    // The boundary unit tests all pass, but random averages find the gaps
+    // between its ranges (like 79.95, in neither 70.0..=79.9 nor 80.0..=89.9).
+    // find_counterexample() returns the failing input instead of panicking
+    let passing = property::find_counterexample(seed, 1000, |rng| rng.float(60.0, 100.0), |&average| get_letter_grade(average) != 'F');
+    match passing {
+        None => println!("60 or more never gets an F: held for 1000 random averages"),
+        Some(failure) => println!(
+            "60 or more never gets an F: failed on case {} with {:.3} -> '{}'",
+            failure.case,
+            failure.input,
+            get_letter_grade(failure.input)
+        ),
+    }
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Write unit tests for calculate_average from lesson 2, including an empty list");
+    println!("2. Add a doc-test to divide_with_remainder that shows the # Panics case with should_panic");
+    println!("3. Fix get_letter_grade's gaps and run the ignored test with ./lesson12-tests --ignored");
+    println!("4. Make property::check retry a failure with smaller inputs to find the simplest one");
+
+    println!("\n--- End of Lesson 12 ---");
+    println!("Next lesson will cover: Declarative Macros with macro_rules!");
+}
+
+// A divisor for the property test: anything but 0
+fn nonzero(d: i32) -> i32 {
+    if d == 0 {
+        1
+    } else {
+        d
+    }
+}
+
+// A panic's message is a &str or a String, depending on how it was raised
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("(no message)")
+    }
+}
+
+// PROPERTY TESTS
+// The properties from main, as tests. Unit tests for each function are in
+// grades.rs; these check rules over many generated inputs instead.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::property::check;
+
+    #[test]
+    fn quotient_times_divisor_plus_remainder_is_dividend() {
+        check(
+            "q * d + r == n",
+            1,
+            1000,
+            |rng| (rng.range(i32::MIN + 1, i32::MAX), nonzero(rng.range(-1000, 1000))),
+            |&(n, d)| {
+                let (q, r) = divide_with_remainder(n, d);
+                q * d + r == n
+            },
+        );
+    }
+
+    #[test]
+    fn remainder_is_smaller_than_divisor_with_dividends_sign() {
+        check(
+            "|r| < |d| and r has n's sign",
+            2,
+            1000,
+            |rng| (rng.range(-100_000, 100_000), nonzero(rng.range(-50, 50))),
+            |&(n, d)| {
+                let (_, r) = divide_with_remainder(n, d);
+                r.abs() < d.abs() && (r == 0 || (r < 0) == (n < 0))
+            },
+        );
+    }
+
+    #[test]
+    fn add_numbers_is_commutative() {
+        check(
+            "a + b == b + a",
+            3,
+            1000,
+            |rng| (rng.range(-1_000_000, 1_000_000), rng.range(-1_000_000, 1_000_000)),
+            |&(a, b)| add_numbers(a, b) == add_numbers(b, a),
+        );
+    }
+
+    #[test]
+    fn first_word_is_a_prefix_without_spaces() {
+        check(
+            "first word is a prefix with no space",
+            4,
+            500,
+            |rng| (0..rng.range(0, 12)).map(|_| ['a', 'b', ' '][rng.range(0, 2) as usize]).collect::<String>(),
+            |text| {
+                let word = get_first_word(text);
+                text.starts_with(word) && !word.contains(' ')
+            },
+        );
+    }
+
+    #[test]
+    fn largest_is_in_the_list_and_not_smaller_than_any_item() {
+        check(
+            "largest is in the list and >= every item",
+            5,
+            500,
+            |rng| (0..rng.range(1, 20)).map(|_| rng.range(-100, 100)).collect::<Vec<i32>>(),
+            |list| {
+                let biggest = largest(list);
+                list.contains(&biggest) && list.iter().all(|&item| item <= biggest)
+            },
+        );
+    }
+
+    // Fails with lesson 2's ranges: ignored so the other tests stay green.
+    // Run it with ./lesson12-tests --ignored to see the failing input.
+    #[test]
+    #[ignore = "lesson 2's grade ranges have gaps (exercise 3 fixes them)"]
+    fn sixty_or_more_never_gets_an_f() {
+        check("60 or more never gets an F", 6, 1000, |rng| rng.float(60.0, 100.0), |&average| get_letter_grade(average) != 'F');
+    }
+}
+
+// LESSON 12 SUMMARY:
+// - #[test] marks a test; it passes unless it panics; rustc --test (or cargo test) runs them (see Unit Tests)
+// - assert!, assert_eq! and assert_ne! panic with a helpful message when a check fails (see Unit Tests)
+// - #[should_panic(expected = "...")] tests that code panics, with the right message (see Testing Panics)
+// - Unit tests go in a #[cfg(test)] mod tests next to the code and can see private items (see Unit Tests)
+// - Doc-tests are ``` examples in /// comments, run by rustdoc so docs stay correct (see Doc-Tests)
+// - Integration tests live in tests/ and use the code only from the outside (see Integration Tests)
+// - Property tests check a rule against many seeded random inputs and find edge cases (see Property Tests)
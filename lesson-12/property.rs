@@ -0,0 +1,135 @@
+// Lesson 12: a hand-rolled property tester (`mod property;` in lesson12.rs)
+// A unit test checks a few examples we picked. A property test checks a
+// rule that must hold for EVERY input ("q * d + r == n") against hundreds of
+// generated inputs, including ones we would never think of trying.
+// Crates like proptest and quickcheck do this (and much more) for real code.
+// Its own tests run with the lesson's, or on their own:
+//   rustc --test property.rs -o property-tests && ./property-tests
+
+use std::fmt::Debug;
+
+// A seeded random number generator (xorshift64*). The same seed always gives
+// the same numbers, so a failure can be reproduced by running with that seed.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift never leaves 0, so 0 is replaced by any other number
+        Rng { state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // A whole number from low to high, both included
+    pub fn range(&mut self, low: i32, high: i32) -> i32 {
+        let size = (high as i64 - low as i64 + 1) as u64;
+        (low as i64 + (self.next_u64() % size) as i64) as i32
+    }
+
+    // A decimal number from low up to (but not including) high
+    pub fn float(&mut self, low: f64, high: f64) -> f64 {
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        low + fraction * (high - low)
+    }
+}
+
+// A failed property: which case broke it, and the input that did
+#[derive(Debug)]
+pub struct Counterexample<T> {
+    pub case: usize,
+    pub input: T,
+}
+
+// Generates `cases` inputs and returns the first one the property rejects
+pub fn find_counterexample<T, G, P>(seed: u64, cases: usize, mut generate: G, property: P) -> Option<Counterexample<T>>
+where
+    G: FnMut(&mut Rng) -> T,
+    P: Fn(&T) -> bool,
+{
+    let mut rng = Rng::new(seed);
+    for case in 1..=cases {
+        let input = generate(&mut rng);
+        if !property(&input) {
+            return Some(Counterexample { case, input });
+        }
+    }
+    None
+}
+
+// The same, for use in #[test] functions: panics with everything needed to
+// reproduce the failure
+pub fn check<T, G, P>(name: &str, seed: u64, cases: usize, generate: G, property: P)
+where
+    T: Debug,
+    G: FnMut(&mut Rng) -> T,
+    P: Fn(&T) -> bool,
+{
+    if let Some(failure) = find_counterexample(seed, cases, generate, property) {
+        panic!("property '{}' failed on case {} of {} (seed {}): input {:?}", name, failure.case, cases, seed, failure.input);
+    }
+}
+
+// The tester's own tests: a property tester that never fails (or fails on
+// everything) would make every property test above useless
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn seed_zero_still_gives_numbers() {
+        let mut rng = Rng::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn range_stays_inside_both_ends_and_reaches_them() {
+        let mut rng = Rng::new(7);
+        let values: Vec<i32> = (0..1000).map(|_| rng.range(-3, 3)).collect();
+        assert!(values.iter().all(|&v| (-3..=3).contains(&v)));
+        assert!(values.contains(&-3) && values.contains(&3));
+    }
+
+    #[test]
+    fn range_over_every_i32_does_not_overflow() {
+        let mut rng = Rng::new(9);
+        for _ in 0..1000 {
+            rng.range(i32::MIN, i32::MAX);
+        }
+    }
+
+    #[test]
+    fn float_stays_below_high() {
+        let mut rng = Rng::new(8);
+        assert!((0..1000).map(|_| rng.float(60.0, 100.0)).all(|f| (60.0..100.0).contains(&f)));
+    }
+
+    #[test]
+    fn finds_the_first_failing_case() {
+        let failure = find_counterexample(1, 100, |rng| rng.range(1, 10), |&n| n < 10).expect("10 comes up in 100 tries");
+        assert_eq!(failure.input, 10);
+        assert!(find_counterexample(1, 100, |rng| rng.range(1, 10), |&n| n <= 10).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "property 'below 10' failed")]
+    fn check_panics_on_a_counterexample() {
+        check("below 10", 1, 100, |rng| rng.range(1, 10), |&n| n < 10);
+    }
+}
@@ -0,0 +1,144 @@
+// Lesson 12: integration tests over the lesson programs
+// To compile and run (from lesson-12): rustc --test tests/lesson_binaries.rs -o lesson-binaries && ./lesson-binaries
+//
+// These tests only use the lessons from the outside, the way a learner does:
+// compile lessonN.rs with rustc, run it, and look at what it prints.
+// The programs are built into target/lesson-binaries/ once and shared by
+// every test.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+struct Lesson {
+    number: u32,
+    title: String, // from the "// Lesson N: Title" first line
+    has_banner: bool, // the "=== Lesson N: Title ===" banner is optional (lesson 1 has none)
+    binary: PathBuf,
+}
+
+// The repository: the first folder (here or above) with a lesson-1 folder in it
+fn root() -> PathBuf {
+    let start = std::env::current_dir().expect("no current directory");
+    start
+        .ancestors()
+        .find(|dir| dir.join("lesson-1").is_dir())
+        .expect("run the tests from inside the repository")
+        .to_path_buf()
+}
+
+// Every lesson, compiled once (on first use) by whichever test asks first
+fn lessons() -> &'static [Lesson] {
+    static LESSONS: OnceLock<Vec<Lesson>> = OnceLock::new();
+    LESSONS.get_or_init(|| {
+        let root = root();
+        let out_dir = root.join("target").join("lesson-binaries");
+        fs::create_dir_all(&out_dir).expect("could not create target/lesson-binaries");
+        let mut lessons = Vec::new();
+        for number in 1.. {
+            let source = root.join(format!("lesson-{}", number)).join(format!("lesson{}.rs", number));
+            if !source.exists() {
+                break;
+            }
+            let binary = out_dir.join(format!("lesson{}", number));
+            compile(&source, &binary, &[]);
+            let text = fs::read_to_string(&source).expect("could not read lesson");
+            let has_banner = text.contains("println!(\"=== Lesson");
+            lessons.push(Lesson { number, title: title_of(&text), has_banner, binary });
+        }
+        lessons
+    })
+}
+
+fn compile(source: &Path, binary: &Path, extra_args: &[&str]) {
+    let output = Command::new("rustc")
+        .arg(source)
+        .arg("-o")
+        .arg(binary)
+        .args(extra_args)
+        .output()
+        .expect("could not run rustc");
+    assert!(output.status.success(), "{} did not compile:\n{}", source.display(), String::from_utf8_lossy(&output.stderr));
+}
+
+fn title_of(text: &str) -> String {
+    let first = text.lines().next().unwrap_or("");
+    let after = first.split_once(": ").map_or("", |(_, title)| title);
+    after.trim().to_string()
+}
+
+// Runs a binary and returns its stdout, failing the test if it didn't exit with 0
+fn run(binary: &Path, args: &[&str]) -> String {
+    let output = Command::new(binary).args(args).output().expect("could not run lesson");
+    assert!(output.status.success(), "{} {:?} failed: {}", binary.display(), args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).expect("lesson output is not UTF-8")
+}
+
+fn lesson(number: u32) -> &'static Lesson {
+    lessons().iter().find(|l| l.number == number).expect("no such lesson")
+}
+
+#[test]
+fn every_lesson_runs_and_prints_its_banners() {
+    assert!(lessons().len() >= 12, "expected at least 12 lessons");
+    for lesson in lessons() {
+        let output = run(&lesson.binary, &[]);
+        if lesson.has_banner {
+            let expected = format!("=== Lesson {}: {} ===", lesson.number, lesson.title);
+            assert_eq!(output.lines().next(), Some(expected.as_str()), "lesson {}", lesson.number);
+        }
+        assert!(output.contains(&format!("--- End of Lesson {} ---", lesson.number)), "lesson {} has no end banner", lesson.number);
+    }
+}
+
+#[test]
+fn next_lesson_line_names_the_next_lesson() {
+    for pair in lessons().windows(2) {
+        let output = run(&pair[0].binary, &[]);
+        let next = output.lines().find_map(|line| line.strip_prefix("Next lesson will cover: "));
+        assert_eq!(next, Some(pair[1].title.as_str()), "lesson {}", pair[0].number);
+    }
+}
+
+#[test]
+fn lesson_runs_are_repeatable() {
+    // The snapshots (tools/snapshots.rs) depend on this: same program, same output
+    let lesson = lesson(8); // threads, the most likely to vary
+    assert_eq!(run(&lesson.binary, &[]), run(&lesson.binary, &[]));
+}
+
+#[test]
+fn lesson_3_trace_mode_prints_a_timeline() {
+    let output = run(&lesson(3).binary, &["trace"]);
+    assert!(output.starts_with("=== Lesson 3 (trace mode)"), "{}", output.lines().next().unwrap_or(""));
+    assert!(output.contains("+ create s1"));
+    assert!(output.contains("> move   s1 -> s2"));
+    assert!(output.contains("- drop"));
+}
+
+#[test]
+fn lesson_12_property_test_finds_the_grade_gap() {
+    let output = run(&lesson(12).binary, &[]);
+    assert!(output.contains("q * d + r == n: held for 1000 random (n, d) pairs"));
+    assert!(output.contains("60 or more never gets an F: failed on case"));
+}
+
+#[test]
+fn lesson_12_unit_tests_pass() {
+    let root = root();
+    let binary = root.join("target").join("lesson-binaries").join("lesson12-tests");
+    compile(&root.join("lesson-12").join("lesson12.rs"), &binary, &["--test"]);
+    let output = run(&binary, &[]);
+    assert!(output.contains("test result: ok."), "{}", output);
+    assert!(output.contains("1 ignored"), "the grade gap test should still be ignored:\n{}", output);
+}
+
+#[test]
+fn lesson_16_unit_tests_pass() {
+    let root = root();
+    let binary = root.join("target").join("lesson-binaries").join("lesson16-tests");
+    compile(&root.join("lesson-16").join("lesson16.rs"), &binary, &["--test"]);
+    let output = run(&binary, &[]);
+    assert!(output.contains("test result: ok."), "{}", output);
+}
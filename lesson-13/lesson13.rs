@@ -0,0 +1,307 @@
+// Lesson 13: Declarative Macros with macro_rules!
+// To compile and run: rustc lesson13.rs && ./lesson13 (or lesson13.exe on Windows)
+
+// Lesson 1 says println! is a macro "(note the exclamation mark!)". This
+// lesson explains what that means by writing macros of our own.
+
+use std::collections::HashMap;
+use std::panic;
+
+// MACROS ARE DEFINED BEFORE THEY ARE USED
+// Functions can be written anywhere in a file, but a macro_rules! macro only
+// exists below its definition. So this lesson's macros come first, and main
+// is further down.
+
+// SECTION! - The first macro: one rule, one argument
+// A rule is (pattern) => { code }. $title:expr matches any expression and
+// names it $title; the code is pasted in with $title replaced.
+// section!("Hygiene") becomes println!("\n--- {} ---", "Hygiene").
+macro_rules! section {
+    ($title:expr) => {
+        println!("\n--- {} ---", $title)
+    };
+}
+
+// MY_VEC! - Repetition with $( ... ),*
+// $( $item:expr ),* matches zero or more expressions separated by commas.
+// In the code, $( v.push($item); )* repeats once for each one matched.
+// $(,)? allows an optional trailing comma, like vec![1, 2, 3,].
+macro_rules! my_vec {
+    () => {
+        Vec::new()
+    };
+    ($( $item:expr ),+ $(,)?) => {{
+        // Clippy would suggest vec![...] for these pushes, but vec! is what
+        // we're rebuilding. An allow only takes effect on a statement around
+        // them, so the Vec is built in an inner block.
+        #[allow(clippy::vec_init_then_push)]
+        let v = {
+            let mut v = Vec::new();
+            $( v.push($item); )+
+            v
+        };
+        v
+    }};
+    // my_vec![0; 3]: the rules are tried in order, the first that matches wins
+    ($item:expr; $count:expr) => {{
+        let mut v = Vec::new();
+        for _ in 0..$count {
+            v.push($item.clone());
+        }
+        v
+    }};
+}
+
+// HASHMAP! - A map literal, with => between each key and value
+// Two things repeat together: $key and $value. The double braces {{ }} make
+// the expansion one block expression, so it can be used after let x = .
+// ::std::collections::HashMap is a full path, so it works even where the
+// caller hasn't written `use std::collections::HashMap`.
+macro_rules! hashmap {
+    ($( $key:expr => $value:expr ),* $(,)?) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $( map.insert($key, $value); )*
+        map
+    }};
+}
+
+// MAX! - A macro that calls itself
+// With one argument it is just that argument; with more, it compares the
+// first against max! of the rest. This is how a macro handles "any number
+// of arguments" when each step needs the result of the next.
+macro_rules! max {
+    ($x:expr) => {
+        $x
+    };
+    ($x:expr, $( $rest:expr ),+) => {{
+        let first = $x;
+        let rest = max!($( $rest ),+);
+        if first > rest {
+            first
+        } else {
+            rest
+        }
+    }};
+}
+
+// NEWTYPE! - Fragments other than expr
+// $name:ident matches a name, $inner:ty a type. The macro writes a whole
+// struct and impl, which no function could do.
+macro_rules! newtype {
+    ($name:ident, $inner:ty, $unit:expr) => {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        struct $name($inner);
+
+        impl $name {
+            fn describe(&self) -> String {
+                format!("{}{}", self.0, $unit)
+            }
+        }
+    };
+}
+
+newtype!(Celsius, f64, "°C");
+newtype!(Points, u32, " points");
+
+// DOUBLE_IT! - Hygiene
+// The macro's own `x` and the caller's `x` are different variables, even
+// though they have the same name, so a macro can't mix them up by accident.
+macro_rules! double_it {
+    ($e:expr) => {{
+        let x = 2;
+        $e * x
+    }};
+}
+
+// ASSERT_APPROX_EQ! - assert_eq! for floats, where 0.1 + 0.2 is not exactly 0.3
+// Each argument is evaluated once, into a variable, so assert_approx_eq!(f(), 1.0)
+// calls f() once even though the message uses the value twice.
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        assert_approx_eq!($left, $right, 1e-9)
+    };
+    ($left:expr, $right:expr, $tolerance:expr) => {{
+        // f64, so the compiler knows which .abs() to call on a plain 0.3
+        let (left, right, tolerance): (f64, f64, f64) = ($left, $right, $tolerance);
+        if (left - right).abs() > tolerance {
+            panic!(
+                "assertion `left ≈ right` failed: {} vs {}\n  left: {:?}\n right: {:?}\n  tolerance: {:?}",
+                stringify!($left),
+                stringify!($right),
+                left,
+                right,
+                tolerance
+            );
+        }
+    }};
+}
+
+// MY_PRINTLN! - println! itself, nearly
+// $( $arg:tt )* takes any tokens at all ("token trees") and hands them on,
+// untouched, to println!, which checks the format string at compile time.
+macro_rules! my_println {
+    ($( $arg:tt )*) => {
+        println!($( $arg )*)
+    };
+}
+
+// SHOW_MY_VEC! - Seeing an expansion as text
+// stringify! turns tokens into a string without running them, and concat!
+// glues string literals together, so this prints the inner block my_vec!
+// writes (the part that builds the Vec).
+macro_rules! show_my_vec {
+    ($( $item:expr ),*) => {
+        concat!("{ let mut v = Vec::new(); ", $( "v.push(", stringify!($item), "); ", )* "v }")
+    };
+}
+
+fn main() {
+    println!("=== Lesson 13: Declarative Macros with macro_rules! ===");
+
+    // WHY PRINTLN! IS A MACRO - Things a function can't do
+    // A function has a fixed number of parameters with fixed types. println!
+    // takes any number of arguments of any printable types, and checks the
+    // "{}" placeholders against them while compiling. A macro can do that
+    // because it runs at compile time: it turns its input into Rust code.
+    section!("Why println! Is a Macro");
+
+    let name = "Ferris";
+    let age = 7;
+    println!("{} is {} years old", name, age);
+    my_println!("{} is {} years old (printed by my_println!)", name, age);
+    // println!("{} is {} years old", name); // ERROR: 2 positional arguments in format string, but there is 1 argument
+
+    // YOUR FIRST MACRO - section!
+    // Every lesson so far printed its banners with println!("\n--- ... ---").
+    // This one uses section!, defined at the top of the file.
+    section!("Your First Macro: section!");
+
+    let topic = "Ownership";
+    println!("section!(topic) with topic = {:?} prints:", topic);
+    section!(topic);
+    section!(format!("Built from {} pieces", 2)); // any expression works
+
+    // REPETITION - my_vec!
+    section!("Repetition: my_vec!");
+
+    let empty: Vec<i32> = my_vec![];
+    let grades = my_vec![85, 92, 78, 96];
+    let words = my_vec!["macros", "are", "code", "that writes code",]; // trailing comma
+    let zeros = my_vec![0; 3];
+    println!("empty = {:?}", empty);
+    println!("grades = {:?}", grades);
+    println!("words = {:?}", words);
+    println!("zeros = {:?}", zeros);
+    println!("same as vec!: {}", grades == vec![85, 92, 78, 96]);
+
+    // A MAP LITERAL - hashmap!
+    section!("A Map Literal: hashmap!");
+
+    let scores = hashmap! {
+        "Alice" => 92,
+        "Bob" => 78,
+        "Carol" => 85,
+    };
+    let scores: HashMap<&str, i32> = scores; // the same type HashMap::new() gives
+    // A HashMap has no fixed order, so the names are sorted before printing
+    let mut names: Vec<&&str> = scores.keys().collect();
+    names.sort();
+    for name in names {
+        println!("{}: {}", name, scores[name]);
+    }
+
+    // RECURSION AND FRAGMENT TYPES - max! and newtype!
+    // Fragment types say what a $name may match:
+    //   expr  an expression: 1 + 2, f(x), "text"
+    //   ident a name: Celsius, total
+    //   ty    a type: f64, Vec<String>
+    //   tt    a single token, or a whole ( ), [ ] or { } group
+    //   (also: literal, pat, block, stmt, path, item, lifetime, vis, meta)
+    section!("Recursion and Fragment Types");
+
+    println!("max!(3) = {}", max!(3));
+    println!("max!(3, 17, 8, 12) = {}", max!(3, 17, 8, 12));
+    println!("max!(2.5, 1.5) = {}", max!(2.5, 1.5));
+
+    let boiling = Celsius(100.0);
+    let room = Celsius(21.5);
+    println!("{:?} and {:?}; warmer: {}", boiling, room, max!(boiling, room).describe());
+    println!("{}", Points(42).describe());
+
+    // HYGIENE - A macro's variables don't clash with yours
+    // In C, a macro is plain text substitution, and double_it!(x + 1) would
+    // become x + 1 * x. In Rust, $e is one expression, and the macro's x is
+    // a different variable from the caller's x.
+    section!("Hygiene");
+
+    let x = 10;
+    println!("double_it!(x + 1) with x = 10: {}", double_it!(x + 1)); // (10 + 1) * 2, not 10 + 1 * 2
+    println!("the caller's x is still {}", x);
+
+    // Hygiene works the other way too: a macro can't see the caller's
+    // variables unless they are passed in as arguments.
+    // macro_rules! print_total { () => { println!("{}", total) }; }
+    // let total = 5;
+    // print_total!(); // ERROR E0425: cannot find value `total` in this scope
+
+    // ASSERT_APPROX_EQ! - Comparing floats in tests
+    section!("assert_approx_eq!");
+
+    let sum = 0.1 + 0.2;
+    println!("0.1 + 0.2 = {:?}, == 0.3: {}", sum, sum == 0.3);
+    assert_approx_eq!(sum, 0.3);
+    println!("assert_approx_eq!(0.1 + 0.2, 0.3) passed");
+    assert_approx_eq!(1.0 / 3.0, 0.333, 0.001);
+    println!("assert_approx_eq!(1.0 / 3.0, 0.333, 0.001) passed");
+
+    // A failing one, caught so the lesson can print its message
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failed = panic::catch_unwind(|| assert_approx_eq!(1.0 / 3.0, 0.333));
+    panic::set_hook(default_hook);
+    if let Err(payload) = failed {
+        if let Some(message) = payload.downcast_ref::<String>() {
+            println!("assert_approx_eq!(1.0 / 3.0, 0.333) panicked:\n{}", message);
+        }
+    }
+
+    // SEEING THE EXPANSION - Debugging a macro
+    // A macro's expansion is ordinary Rust code. To see it for a whole file:
+    //   RUSTC_BOOTSTRAP=1 rustc -Zunpretty=expanded lesson13.rs
+    // (-Z options are meant for nightly Rust; RUSTC_BOOTSTRAP=1 unlocks them on
+    // stable. With Cargo, the cargo-expand tool does the same.) For
+    // `let grades = my_vec![85, 92, 78];` it prints:
+    //   let grades =
+    //       {
+    //           #[allow(clippy :: vec_init_then_push)]
+    //           let v = { let mut v = Vec::new(); v.push(85); v.push(92); v.push(78); v };
+    //           v
+    //       };
+    // and shows every println! turned into a call to std::io::_print.
+    // Inside the program, stringify! shows a macro's tokens as text:
+    section!("Seeing the Expansion");
+
+    println!("my_vec![85, 92, 78] builds its Vec with:");
+    println!("  {}", show_my_vec!(85, 92, 78));
+    println!("stringify!(max!(1, 2)) = {}", stringify!(max!(1, 2)));
+    // my_vec![1, 2; 3]; // ERROR: no rules expected `;` (no rule's pattern matches)
+
+    // EXERCISES FOR YOU TO TRY:
+    section!("Exercises");
+    println!("1. Add a min! macro next to max! and test it with three or more numbers");
+    println!("2. Give hashmap! a rule for an empty map: hashmap!{{}} with a type annotation");
+    println!("3. Write a square! macro and check double_it!-style hygiene with square!(x + 1)");
+    println!("4. Add a newtype!(Meters, f64, \"m\") and a macro that adds two values of the same newtype");
+
+    section!("End of Lesson 13");
+    println!("Next lesson will cover: File I/O and Custom Error Types");
+}
+
+// LESSON 13 SUMMARY:
+// - Macros like println! run at compile time and turn their input into Rust code (see Why println! Is a Macro)
+// - macro_rules! name { (pattern) => { code }; ... } tries each rule in order (see Your First Macro: section!)
+// - $x:expr, $n:ident, $t:ty and $t:tt match pieces of code of different kinds (see Recursion and Fragment Types)
+// - $( ... ),* matches a repeated pattern, and $( ... )* repeats code once per match (see Repetition: my_vec!)
+// - Macros can call themselves, like max!, to work through their arguments (see Recursion and Fragment Types)
+// - Hygiene: variables made inside a macro never clash with the caller's variables (see Hygiene)
+// - See what a macro writes with stringify! or rustc -Zunpretty=expanded (see Seeing the Expansion)
@@ -0,0 +1,339 @@
+// Lesson 14: File I/O and Custom Error Types
+// To compile and run: rustc lesson14.rs && ./lesson14 (or lesson14.exe on Windows)
+
+// Lesson 5's errors were Strings from arithmetic. Here they come from the
+// file system, where things go wrong that no program can rule out: a file is
+// missing, a disk is full, someone typed "eighty" instead of 80.
+// Every file this lesson writes goes in a folder under the system's temp
+// folder, which is deleted again at the end.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::num::ParseFloatError;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("=== Lesson 14: File I/O and Custom Error Types ===");
+
+    // main returns nothing, so it can't use ? itself: the lesson runs in
+    // run(), and main reports whatever error comes back
+    // fn main() { fs::read_to_string("notes.txt")?; } // ERROR E0277: the `?` operator can only be used in a function that returns `Result` or `Option`
+    let dir = std::env::temp_dir().join(format!("rust-lesson14-{}", std::process::id()));
+    let result = run(&dir);
+    let _ = fs::remove_dir_all(&dir); // clean up even if run() failed
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+// Box<dyn Error> holds any error type, and ? converts each one into it
+fn run(dir: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+
+    // WHOLE FILES - fs::write and fs::read_to_string
+    // The simplest way in and out: the whole file at once, as one String.
+    // Both return io::Result<T>, which is Result<T, io::Error>.
+    println!("\n--- Reading and Writing Whole Files ---");
+
+    let notes = dir.join("notes.txt");
+    fs::write(&notes, "Rust files are UTF-8 text.\nThis is line two.\n")?; // creates or replaces
+    let text = fs::read_to_string(&notes)?;
+    println!("notes.txt has {} bytes, {} lines", fs::metadata(&notes)?.len(), text.lines().count());
+    println!("first line: {}", text.lines().next().unwrap_or(""));
+
+    // Appending needs the longer form: OpenOptions says how to open the file
+    let mut file = fs::OpenOptions::new().append(true).open(&notes)?;
+    writeln!(file, "Appended line three.")?; // writeln! works on files (needs io::Write)
+    println!("after appending: {} lines", count_lines(&notes)?);
+
+    // IO ERRORS - io::Error and its kind()
+    // An io::Error says what the operating system reported. kind() sorts it
+    // into NotFound, PermissionDenied, AlreadyExists... so code can react.
+    println!("\n--- I/O Errors ---");
+
+    match fs::read_to_string(dir.join("missing.txt")) {
+        Ok(text) => println!("read {} bytes", text.len()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => println!("missing.txt: not found, starting with an empty list"),
+        Err(e) => return Err(e.into()), // anything else is a real problem
+    }
+
+    // ? passes an io::Error up unchanged, but the message doesn't say which file
+    match count_lines(&dir.join("missing.txt")) {
+        Ok(n) => println!("{} lines", n),
+        Err(e) => println!("count_lines failed with kind {:?}, and no file name in the error", e.kind()),
+    }
+
+    // BUFFERED I/O - BufWriter and BufReader
+    // Every write to a File is a request to the operating system. BufWriter
+    // collects small writes in memory and sends them in big pieces; BufReader
+    // reads a big piece at a time and hands out lines from it.
+    println!("\n--- Buffered Reading and Writing ---");
+
+    let squares = dir.join("squares.txt");
+    {
+        let mut writer = BufWriter::new(File::create(&squares)?);
+        for n in 1..=1000 {
+            writeln!(writer, "{} squared is {}", n, n * n)?;
+        }
+        writer.flush()?; // dropping a BufWriter flushes too, but ignores any error
+    }
+
+    let reader = BufReader::new(File::open(&squares)?);
+    let mut longest = String::new();
+    let mut count = 0;
+    for line in reader.lines() {
+        let line = line?; // each line is an io::Result<String>: reading can fail midway
+        count += 1;
+        if line.len() > longest.len() {
+            longest = line;
+        }
+    }
+    println!("squares.txt: {} lines, the longest is \"{}\"", count, longest);
+
+    // CUSTOM ERRORS WITH CONTEXT - An enum of everything that can go wrong
+    // LoadError (below main) says which file and which line, and keeps the
+    // original error as its source() so nothing is lost.
+    println!("\n--- Custom Error Types ---");
+
+    let errors = [
+        LoadError::Io { action: "open", path: PathBuf::from("grades.csv"), source: io::Error::from(io::ErrorKind::NotFound) },
+        LoadError::Line { number: 3, text: String::from("Carol,eighty"), source: LineError::BadNumber("eighty".parse::<f64>().unwrap_err()) },
+        LoadError::Line { number: 4, text: String::from("Dave"), source: LineError::MissingComma },
+        LoadError::Line { number: 5, text: String::from("Eve,104"), source: LineError::OutOfRange(104.0) },
+    ];
+    for error in &errors {
+        print_error(error);
+    }
+
+    // FROM CONVERSIONS - Let ? change one error type into another
+    // parse_line's error is LineError, but str::parse gives ParseFloatError.
+    // impl From<ParseFloatError> for LineError tells ? how to convert it.
+    println!("\n--- From Conversions ---");
+
+    for text in ["Alice,92.5", "Bob, 78", "Carol,eighty", "Dave", "Eve,104"] {
+        match parse_line(text) {
+            Ok((name, score)) => println!("{:<14} -> Ok: {} scored {}", text, name, score),
+            Err(e) => println!("{:<14} -> Err: {}", text, e),
+        }
+    }
+    // run() returns Box<dyn Error>, and std has a From for every error type
+    // into it. That is why ? works on io::Error, LineError and LoadError alike.
+    let boxed: Box<dyn Error> = parse_line("Dave").unwrap_err().into();
+    println!("as Box<dyn Error>: {}", boxed);
+
+    // fn read_grades(path: &Path) -> Result<String, String> {
+    //     Ok(fs::read_to_string(path)?) // ERROR E0277: `?` couldn't convert the error to `String`
+    // }
+    // (with a String error type, write .map_err(|e| e.to_string())? instead)
+
+    // SAVE AND LOAD - The grade list, on disk
+    // save() writes "name,score" lines to a new file, then renames it over
+    // the old one, so a crash halfway never leaves a half-written list behind.
+    println!("\n--- Saving and Loading the Grade List ---");
+
+    let grades_path = dir.join("grades.csv");
+    let mut book = match GradeBook::load(&grades_path) {
+        Ok(book) => book,
+        Err(LoadError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => GradeBook::new(),
+        Err(e) => return Err(e.into()),
+    };
+    println!("loaded {} students (no file yet)", book.students.len());
+
+    book.add("Alice", 92.5);
+    book.add("Bob", 78.0);
+    book.add("Carol", 85.0);
+    book.save(&grades_path)?;
+    println!("saved grades.csv:");
+    for line in fs::read_to_string(&grades_path)?.lines() {
+        println!("  {}", line);
+    }
+
+    let loaded = GradeBook::load(&grades_path)?;
+    println!("loaded {} students again, average {:.1}", loaded.students.len(), loaded.average().unwrap_or(0.0));
+    for (name, score) in &loaded.students {
+        println!("  {:<6} {:>5.1}", name, score);
+    }
+
+    // Someone edits the file by hand and makes a mistake on line 4
+    fs::write(&grades_path, "# name,score\nAlice,92.5\nBob,78\nCarol,eighty\n")?;
+    match GradeBook::load(&grades_path) {
+        Ok(book) => println!("loaded {} students", book.students.len()),
+        Err(e) => print_error(&e),
+    }
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Add a LineError::EmptyName variant for lines like \",90\" and report it");
+    println!("2. Make GradeBook::load skip bad lines, collecting their errors in a Vec instead of stopping");
+    println!("3. Write a function that copies a file with BufReader and BufWriter, line by line");
+    println!("4. Add a --file PATH option and keep the grade list in that file between runs");
+
+    println!("\n--- End of Lesson 14 ---");
+    println!("Next lesson will cover: Advanced Pattern Matching");
+    Ok(())
+}
+
+// io::Result<T> is short for Result<T, io::Error>. ? returns the error as is.
+fn count_lines(path: &Path) -> io::Result<usize> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut count = 0;
+    for line in reader.lines() {
+        line?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+// An error, then each source() it wraps, one per line
+fn print_error(error: &dyn Error) {
+    println!("error: {}", error);
+    let mut source = error.source();
+    while let Some(cause) = source {
+        println!("  caused by: {}", cause);
+        source = cause.source();
+    }
+}
+
+// ERRORS FOR ONE LINE
+// What can be wrong with a single "name,score" line
+#[derive(Debug)]
+enum LineError {
+    MissingComma,
+    BadNumber(ParseFloatError),
+    OutOfRange(f64),
+}
+
+// Display is the message for people; Debug (derived) is for programmers
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineError::MissingComma => write!(f, "expected name,score"),
+            LineError::BadNumber(_) => write!(f, "the score is not a number"),
+            LineError::OutOfRange(score) => write!(f, "{} is not between 0 and 100", score),
+        }
+    }
+}
+
+// Implementing Error makes it usable as Box<dyn Error> and as a source()
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LineError::BadNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// FROM
+// With this, ? turns a ParseFloatError into a LineError by itself
+impl From<ParseFloatError> for LineError {
+    fn from(e: ParseFloatError) -> LineError {
+        LineError::BadNumber(e)
+    }
+}
+
+// "Alice, 92.5" -> ("Alice", 92.5)
+fn parse_line(text: &str) -> Result<(String, f64), LineError> {
+    let (name, score) = text.split_once(',').ok_or(LineError::MissingComma)?;
+    let score: f64 = score.trim().parse()?; // ParseFloatError -> LineError via From
+    if !(0.0..=100.0).contains(&score) {
+        return Err(LineError::OutOfRange(score));
+    }
+    Ok((name.trim().to_string(), score))
+}
+
+// ERRORS FOR A WHOLE FILE
+// Each variant adds the context the lower-level error doesn't have:
+// which file and what we were doing, or which line number
+#[derive(Debug)]
+enum LoadError {
+    Io { action: &'static str, path: PathBuf, source: io::Error },
+    Line { number: usize, text: String, source: LineError },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io { action, path, .. } => {
+                // Only the file name, so the output is the same on every computer
+                let name = path.file_name().map_or(path.as_os_str(), |n| n).to_string_lossy();
+                write!(f, "could not {} {}", action, name)
+            }
+            LoadError::Line { number, text, .. } => write!(f, "line {}: {:?}", number, text),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            LoadError::Line { source, .. } => Some(source),
+        }
+    }
+}
+
+// THE GRADE BOOK
+struct GradeBook {
+    students: Vec<(String, f64)>,
+}
+
+impl GradeBook {
+    fn new() -> GradeBook {
+        GradeBook { students: Vec::new() }
+    }
+
+    fn add(&mut self, name: &str, score: f64) {
+        self.students.push((name.to_string(), score));
+    }
+
+    fn average(&self) -> Option<f64> {
+        if self.students.is_empty() {
+            return None;
+        }
+        Some(self.students.iter().map(|(_, score)| score).sum::<f64>() / self.students.len() as f64)
+    }
+
+    // Blank lines and # comments are skipped; any other bad line stops the load.
+    // An io::Error has no file name in it, and a LineError no line number, so
+    // there is no From here: map_err builds the LoadError where those are known.
+    fn load(path: &Path) -> Result<GradeBook, LoadError> {
+        let io_error = |action| move |source| LoadError::Io { action, path: path.to_path_buf(), source };
+        let file = File::open(path).map_err(io_error("open"))?;
+        let mut book = GradeBook::new();
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(io_error("read"))?;
+            let text = line.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (name, score) = parse_line(text).map_err(|source| LoadError::Line { number: i + 1, text: text.to_string(), source })?;
+            book.students.push((name, score));
+        }
+        Ok(book)
+    }
+
+    fn save(&self, path: &Path) -> Result<(), LoadError> {
+        let io_error = |action| move |source| LoadError::Io { action, path: path.to_path_buf(), source };
+        let temporary = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&temporary).map_err(io_error("create"))?);
+        writeln!(writer, "# name,score").map_err(io_error("write"))?;
+        for (name, score) in &self.students {
+            writeln!(writer, "{},{}", name, score).map_err(io_error("write"))?;
+        }
+        writer.flush().map_err(io_error("write"))?;
+        fs::rename(&temporary, path).map_err(io_error("replace"))
+    }
+}
+
+// LESSON 14 SUMMARY:
+// - fs::read_to_string and fs::write read or write a whole file in one call (see Reading and Writing Whole Files)
+// - File::open, File::create and OpenOptions give a File to read or write step by step (see Reading and Writing Whole Files)
+// - BufReader (with .lines()) and BufWriter make many small reads and writes fast (see Buffered Reading and Writing)
+// - I/O returns io::Result<T>; ? passes the io::Error up, kind() tells what went wrong (see I/O Errors)
+// - A custom error enum adds context (which file, which line) and keeps the cause in source() (see Custom Error Types)
+// - impl From<A> for B lets ? convert error A into error B automatically (see From Conversions)
+// - map_err adds context that From can't know, like the path being read (see Saving and Loading the Grade List)
@@ -0,0 +1,297 @@
+// Lesson 15: Advanced Pattern Matching
+// To compile and run: rustc lesson15.rs && ./lesson15 (or lesson15.exe on Windows)
+
+// Lesson 2's get_letter_grade matched literals and ranges, and lesson 4's
+// print_message took an enum apart. Patterns can do a lot more.
+
+fn main() {
+    println!("=== Lesson 15: Advanced Pattern Matching ===");
+
+    // MATCH GUARDS - if conditions on a match arm
+    // `pattern if condition =>` only matches when the condition is true too.
+    // Guards can use any expression, including ones a pattern can't express.
+    println!("\n--- Match Guards ---");
+
+    for n in [-4, 0, 7, 12] {
+        let description = match n {
+            0 => "zero",
+            n if n < 0 => "negative",
+            n if n % 2 == 0 => "positive and even",
+            _ => "positive and odd",
+        };
+        println!("{} is {}", n, description);
+    }
+
+    // The compiler can't see inside guards, so it needs a final arm that
+    // catches everything else:
+    // match n { n if n < 0 => "negative", n if n >= 0 => "not negative" } // ERROR E0004: non-exhaustive patterns: `i32::MIN..=i32::MAX` not covered
+
+    // @ BINDINGS - Test a value against a pattern AND keep it in a variable
+    // name @ pattern binds the whole matched value to name.
+    println!("\n--- @ Bindings ---");
+
+    for score in [100, 95, 83, 42] {
+        match score {
+            100 => println!("{}: perfect", score),
+            top @ 90..=99 => println!("{}: top band, {} away from perfect", top, 100 - top),
+            passed @ 50..=89 => println!("{}: passed", passed),
+            failed => println!("{}: failed by {}", failed, 50 - failed),
+        }
+    }
+
+    let moves = [Message::Move { x: 5, y: 80 }, Message::Move { x: 250, y: 3 }];
+    for message in &moves {
+        match message {
+            Message::Move { x: x @ 0..=100, y: y @ 0..=100 } => println!("move to ({}, {}): on the board", x, y),
+            Message::Move { x, y } => println!("move to ({}, {}): off the board", x, y),
+            other => println!("{}", describe(other)),
+        }
+    }
+
+    // NESTED DESTRUCTURING - Patterns inside patterns
+    // A pattern can follow the shape of the data as deep as it goes: an enum
+    // holding a struct holding an enum, all taken apart in one arm.
+    println!("\n--- Nested Destructuring ---");
+
+    let alice = User { name: String::from("Alice"), age: 30, active: true };
+    let bob = User { name: String::from("Bob"), age: 17, active: true };
+    let carol = User { name: String::from("Carol"), age: 25, active: false };
+    let commands = [
+        Command::Send { to: &alice, message: Message::Text(String::from("hello")) },
+        Command::Send { to: &bob, message: Message::Text(String::from("hi")) },
+        Command::Send { to: &carol, message: Message::Text(String::from("are you there?")) },
+        Command::Send { to: &alice, message: Message::Move { x: 0, y: 0 } },
+        Command::Send { to: &alice, message: Message::Quit },
+        Command::Broadcast(Message::Text(String::from("lesson 15 is out"))),
+    ];
+    for command in &commands {
+        println!("{}", route(command));
+    }
+
+    // SLICE PATTERNS - Match on the length and contents of a slice
+    // [a, b] matches exactly two items; .. stands for "any number in between";
+    // rest @ .. keeps those items as a smaller slice.
+    println!("\n--- Slice Patterns ---");
+
+    let lists: [&[i32]; 4] = [&[], &[7], &[85, 92], &[85, 92, 78, 96, 88]];
+    for list in lists {
+        let description = match list {
+            [] => String::from("empty"),
+            [only] => format!("just {}", only),
+            [first, last] => format!("two: {} and {}", first, last),
+            [first, .., last] => format!("{} items, from {} to {}", list.len(), first, last),
+        };
+        println!("{:?}: {}", list, description);
+    }
+    println!("sum of [85, 92, 78, 96, 88] = {}", sum(&[85, 92, 78, 96, 88]));
+
+    // Slice patterns on words make a tiny command parser
+    for line in ["add Carol 85", "remove Bob", "list", "add Dave", "add Eve eighty"] {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        println!("{:<16} -> {}", line, run_command(&words));
+    }
+
+    // LET ELSE - Match one pattern or leave
+    // let PATTERN = value else { ... }; binds the pattern's variables for the
+    // rest of the function, and the else block must leave (return, break,
+    // continue or panic) when the value doesn't match.
+    println!("\n--- let else ---");
+
+    for line in ["Alice,92.5", "Bob", "Carol,eighty"] {
+        match parse_grade(line) {
+            Ok((name, score)) => println!("{:<12} -> {} scored {}", line, name, score),
+            Err(e) => println!("{:<12} -> {}", line, e),
+        }
+    }
+    // let Some(x) = line.find(',') else { println!("no comma") }; // ERROR E0308: `else` clause of `let...else` does not diverge
+
+    // WHILE LET - Loop as long as a pattern matches
+    println!("\n--- while let ---");
+
+    let mut to_grade = vec![("Alice", 92.5), ("Bob", 78.0), ("Carol", 85.0)];
+    while let Some((name, score)) = to_grade.pop() {
+        println!("{}: {} ({} left to grade)", name, score, to_grade.len());
+    }
+
+    // Walk through pairs of neighbours with an iterator and a slice pattern
+    let grades = [78, 85, 85, 92];
+    let mut windows = grades.windows(2);
+    while let Some(&[before, after]) = windows.next() {
+        let change = match after - before {
+            0 => String::from("same"),
+            d if d > 0 => format!("up {}", d),
+            d => format!("down {}", -d),
+        };
+        println!("{} -> {}: {}", before, after, change);
+    }
+
+    // MATCHES! - A match that answers yes or no
+    // matches!(value, pattern) is true if the pattern matches; guards work too.
+    println!("\n--- matches! ---");
+
+    let letters = ['A', 'C', 'B', 'F', 'A'];
+    let good = letters.iter().filter(|&&letter| matches!(letter, 'A' | 'B')).count();
+    println!("{} of {} grades are A or B", good, letters.len());
+
+    let all = [Message::Quit, Message::Move { x: 3, y: -1 }, Message::Text(String::from("hi")), Message::Move { x: -2, y: 4 }];
+    let rightward = all.iter().filter(|m| matches!(m, Message::Move { x, .. } if *x > 0)).count();
+    println!("moves to the right: {}", rightward);
+    println!("has a Quit: {}", all.iter().any(|m| matches!(m, Message::Quit)));
+
+    // A GRADER WITH NO GAPS - Guards instead of closed float ranges
+    // Lesson 2 wrote 80.0..=89.9 => 'B' and 70.0..=79.9 => 'C'. Floats aren't
+    // only whole tenths, so 79.95 matched neither and got an 'F' (lesson 12's
+    // property test found this). Comparing with >= in guards leaves no space
+    // between the grades, and the first arms catch input that isn't a grade.
+    println!("\n--- A Grader with No Gaps ---");
+
+    println!("{:>8}  lesson 2  guards", "average");
+    for average in [95.0, 89.95, 85.0, 79.95, 69.99, 59.99, 100.0, 100.5, -3.0, f64::NAN] {
+        let new = match letter_grade(average) {
+            Some(letter) => letter.to_string(),
+            None => String::from("invalid"),
+        };
+        println!("{:>8}  {:>8}  {}", average, lesson2_letter_grade(average), new);
+    }
+
+    // Check every average from 0.00 to 100.00 in steps of 0.01
+    let mut problems = 0;
+    let mut previous = 'F';
+    for hundredths in 0..=10_000 {
+        let average = hundredths as f64 / 100.0;
+        let Some(letter) = letter_grade(average) else {
+            problems += 1;
+            continue;
+        };
+        // A higher average never gets a worse letter ('A' < 'B' < ... < 'F')
+        if letter > previous {
+            problems += 1;
+        }
+        previous = letter;
+    }
+    println!("checked 10001 averages from 0.00 to 100.00: {} problems", problems);
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Add a Message::ChangeColor(u8, u8, u8) and match grey colours (r == g && g == b) with a guard");
+    println!("2. Write second_largest(&[i32]) -> Option<i32> using slice patterns for the short cases");
+    println!("3. Give run_command a \"rename OLD NEW\" command and reject extra words with [.., extra]");
+    println!("4. Add + and - to letter_grade (87.0 -> B+) with guards, and check again for gaps");
+
+    println!("\n--- End of Lesson 15 ---");
+    println!("Next lesson will cover: Operator Overloading and Standard Traits");
+}
+
+// LESSON 4'S TYPES
+struct User {
+    name: String,
+    age: u32,
+    active: bool,
+}
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Text(String),
+}
+
+fn describe(message: &Message) -> String {
+    match message {
+        Message::Quit => String::from("Quit"),
+        Message::Move { x, y } => format!("Move to ({}, {})", x, y),
+        Message::Text(s) => format!("Text: {}", s),
+    }
+}
+
+// NESTED DESTRUCTURING
+// A message sent to one user, or to everyone
+enum Command<'a> {
+    Send { to: &'a User, message: Message },
+    Broadcast(Message),
+}
+
+fn route(command: &Command) -> String {
+    match command {
+        // Into the enum, into the User it points to, and into the Message
+        Command::Send { to: User { active: false, name, .. }, .. } => format!("{} is inactive, not sent", name),
+        Command::Send { to: User { name, age: 0..=17, .. }, message: Message::Text(text) } => {
+            format!("to {} (under 18, so a parent gets a copy): {}", name, text)
+        }
+        Command::Send { to: User { name, .. }, message: Message::Text(text) } => format!("to {}: {}", name, text),
+        Command::Send { to: User { name, .. }, message: Message::Quit } => format!("{} leaves", name),
+        Command::Send { to, message } => format!("to {}: {}", to.name, describe(message)),
+        Command::Broadcast(Message::Text(text)) => format!("to everyone: {}", text),
+        Command::Broadcast(other) => format!("to everyone: {}", describe(other)),
+    }
+}
+
+// SLICE PATTERNS
+// The first item plus the sum of the rest; an empty slice adds up to 0
+fn sum(list: &[i32]) -> i32 {
+    match list {
+        [] => 0,
+        [first, rest @ ..] => first + sum(rest),
+    }
+}
+
+fn run_command(words: &[&str]) -> String {
+    match words {
+        ["add", name, score] => match score.parse::<u32>() {
+            Ok(score @ 0..=100) => format!("added {} with {}", name, score),
+            Ok(score) => format!("{} is not a score from 0 to 100", score),
+            Err(_) => format!("'{}' is not a number", score),
+        },
+        ["add", ..] => String::from("usage: add NAME SCORE"),
+        ["remove", name] => format!("removed {}", name),
+        ["list"] => String::from("listing everyone"),
+        [] => String::from("nothing to do"),
+        [other, ..] => format!("unknown command '{}'", other),
+    }
+}
+
+// LET ELSE
+// Each step either gives a value to keep going with, or returns an error
+fn parse_grade(line: &str) -> Result<(&str, f64), String> {
+    let Some((name, score)) = line.split_once(',') else {
+        return Err(String::from("expected name,score"));
+    };
+    let Ok(score) = score.trim().parse::<f64>() else {
+        return Err(format!("'{}' is not a number", score));
+    };
+    Ok((name, score))
+}
+
+// A GRADER WITH NO GAPS
+// Lesson 2's version, unchanged, for comparison
+fn lesson2_letter_grade(average: f64) -> char {
+    match average {
+        90.0..=100.0 => 'A',
+        80.0..=89.9 => 'B',
+        70.0..=79.9 => 'C',
+        60.0..=69.9 => 'D',
+        _ => 'F',
+    }
+}
+
+// Each guard only says where a grade starts: whatever failed the arms above
+// is below that point, so every number from 0 to 100 lands somewhere.
+// NaN fails every comparison, so it is caught with is_nan() first.
+fn letter_grade(average: f64) -> Option<char> {
+    match average {
+        a if a.is_nan() || !(0.0..=100.0).contains(&a) => None,
+        a if a >= 90.0 => Some('A'),
+        a if a >= 80.0 => Some('B'),
+        a if a >= 70.0 => Some('C'),
+        a if a >= 60.0 => Some('D'),
+        _ => Some('F'),
+    }
+}
+
+// LESSON 15 SUMMARY:
+// - A match guard (pattern if condition) adds any test a pattern can't express (see Match Guards)
+// - Guards don't count toward exhaustiveness, so a match with guards needs a catch-all arm (see Match Guards)
+// - name @ pattern checks a value against the pattern and binds it to name (see @ Bindings)
+// - Patterns nest: one arm can take apart an enum, the struct inside it and more (see Nested Destructuring)
+// - Slice patterns match lengths and ends: [], [x], [first, .., last], [head, rest @ ..] (see Slice Patterns)
+// - let else binds a pattern or leaves; while let loops while a pattern matches (see let else)
+// - matches!(value, pattern) is a yes/no match; >= guards leave no gaps between float ranges (see matches!)
@@ -0,0 +1,538 @@
+// Lesson 16: Operator Overloading and Standard Traits
+// To compile and run: rustc lesson16.rs && ./lesson16 (or lesson16.exe on Windows)
+
+// Lesson 6 wrote a trait of its own, Animal. The standard library has traits
+// too, and implementing them makes our types work with {}, ==, <, +, sort(),
+// HashSet, .into() and for loops, just like the built-in types.
+// Every impl here has unit tests at the bottom of the file. Run them with:
+//   rustc --test lesson16.rs -o lesson16-tests && ./lesson16-tests
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Neg, Sub};
+
+fn main() {
+    println!("=== Lesson 16: Operator Overloading and Standard Traits ===");
+
+    // DISPLAY - How a type looks with {}
+    // {:?} (Debug) can be derived, but {} (Display) is for people, so Rust
+    // makes you write it. fmt gets a Formatter and write!s into it.
+    // to_string() comes free with Display.
+    println!("\n--- Display ---");
+
+    let alice = User { name: String::from("Alice"), age: 30, active: true };
+    let bob = User { name: String::from("Bob"), age: 17, active: false };
+    println!("{}", alice);
+    println!("{}", bob);
+    for message in [Message::Quit, Message::Move { x: 3, y: 4 }, Message::Text(String::from("hello"))] {
+        println!("message: {}", message);
+    }
+    let text: String = alice.to_string();
+    println!("to_string() gives a String: {:?}", text);
+    // println!("{}", Rectangle { width: 3, height: 4 }); // ERROR E0277: `Rectangle` doesn't implement `std::fmt::Display`
+
+    // COMPARING - PartialEq, Eq, PartialOrd, Ord and Hash
+    // == calls PartialEq::eq and < calls PartialOrd::partial_cmp. Ord (a total
+    // order) is what sort() and max() need, and HashSet needs Eq plus Hash.
+    // They must agree: values that are == must hash the same, and cmp must
+    // say Equal exactly when they are ==.
+    println!("\n--- Comparing ---");
+
+    let small = Rectangle { width: 2, height: 3 };
+    let wide = Rectangle { width: 6, height: 1 };
+    let square = Rectangle::square(4);
+    println!("{:?} == {:?}: {}", small, Rectangle { width: 2, height: 3 }, small == Rectangle { width: 2, height: 3 });
+    println!("{:?} != {:?}: {}", small, Rectangle { width: 3, height: 2 }, small != Rectangle { width: 3, height: 2 });
+    println!("{:?} < {:?}: {}", small, square, small < square);
+
+    // Ord for Rectangle goes by area first, so sort() puts small ones first
+    let mut rectangles = vec![square, wide, small, Rectangle { width: 3, height: 2 }];
+    rectangles.sort();
+    println!("sorted by area, then width: {:?}", rectangles);
+    println!("largest: {:?}", rectangles.iter().max());
+
+    // The same rectangle twice is only stored once
+    let unique = HashSet::from([small, square, small, wide, square]);
+    println!("{} different rectangles in a list of 5", unique.len());
+
+    // OPERATORS - +, - and unary - for a type of our own
+    // Each operator is a trait in std::ops: a + b calls Add::add(a, b),
+    // a - b calls Sub::sub, and -a calls Neg::neg. Output says what type the
+    // result is.
+    println!("\n--- Operators ---");
+
+    let start = Point { x: 1, y: 2 };
+    let step = Point { x: 3, y: -1 };
+    println!("{} + {} = {}", start, step, start + step);
+    println!("{} - {} = {}", start, step, start - step);
+    println!("-{} = {}", step, -step);
+    println!("Add::add({}, {}) = {}", start, step, Add::add(start, step));
+    println!("three steps from {}: {}", start, start + step + step + step);
+    // start + 1; // ERROR E0308: mismatched types: expected `Point`, found integer
+
+    // FROM AND TRYFROM - Conversions between types
+    // From<T> is a conversion that always works, and gives you .into() for
+    // free. TryFrom<T> may fail, so it returns a Result with its own Error.
+    println!("\n--- From and TryFrom ---");
+
+    let corner = Point::from((5, 5));
+    let target: Point = (2, -7).into();
+    println!("Point::from((5, 5)) = {}, (2, -7).into() = {}", corner, target);
+    let moved: Message = target.into();
+    println!("a Point into a Message: {}", moved);
+
+    for message in [Message::Move { x: 8, y: 1 }, Message::Quit, Message::Text(String::from("hi"))] {
+        match Point::try_from(&message) {
+            Ok(point) => println!("Point::try_from({}) = Ok({})", message, point),
+            Err(e) => println!("Point::try_from({}) = Err({})", message, e),
+        }
+    }
+    // let point: Point = Point::try_from(&Message::Quit); // ERROR E0308: mismatched types (it returns a Result)
+
+    // DEFAULT - A starting value for a type
+    // #[derive(Default)] uses each field's default (0, false, "", empty Vec).
+    // Write it yourself when those aren't the right starting values.
+    // ..Default::default() fills in every field you didn't name.
+    println!("\n--- Default ---");
+
+    println!("Point::default() = {}", Point::default());
+    println!("User::default() = {}", User::default());
+    let carol = User { name: String::from("Carol"), ..Default::default() };
+    println!("only the name set: {}", carol);
+    let empty = Route::default();
+    println!("Route::default() has {} points", empty.len());
+
+    // ITERATOR AND INTOITERATOR - Our own collection in a for loop
+    // Iterator needs just next(); map, filter, sum and the rest come free.
+    // A for loop calls IntoIterator::into_iter on what it is given, so
+    // implementing IntoIterator for Route and &Route makes both
+    // `for p in route` and `for p in &route` work.
+    println!("\n--- Iterator and IntoIterator ---");
+
+    let route = Route::new(vec![Point { x: 0, y: 0 }, Point { x: 3, y: 4 }, Point { x: 3, y: 9 }, Point { x: -1, y: 9 }]);
+    print!("points:");
+    for point in &route {
+        print!(" {}", point);
+    }
+    println!();
+
+    // legs() is our Iterator: each step is the next point minus this one
+    for (number, leg) in route.legs().enumerate() {
+        println!("leg {}: {}", number + 1, leg);
+    }
+    let total = route.legs().fold(Point::default(), |sum, leg| sum + leg);
+    println!("all legs added up: {} (the same as last - first)", total);
+    let rightward = route.legs().filter(|leg| leg.x > 0).count();
+    println!("legs that go right: {}", rightward);
+
+    // By value, the Route is used up and gives away its Points
+    let messages: Vec<Message> = route.into_iter().map(Message::from).collect();
+    println!("as messages: {}", messages.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(", "));
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Implement Mul<i32> for Point, so step * 3 scales it, and test it");
+    println!("2. Implement Display for Rectangle as \"3x4\" and print the sorted list with it");
+    println!("3. Implement FromIterator<Point> for Route, so .collect() can build one");
+    println!("4. Add TryFrom<(i64, i64)> for Point that fails when a number doesn't fit in an i32");
+
+    println!("\n--- End of Lesson 16 ---");
+    println!("This is the last lesson of the course. Try the exercises, then build something of your own!");
+}
+
+// DISPLAY
+// Lesson 4's User and Message, unchanged
+struct User {
+    name: String,
+    age: u32,
+    active: bool,
+}
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Text(String),
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = if self.active { "active" } else { "inactive" };
+        write!(f, "{} ({}, {})", self.name, self.age, status)
+    }
+}
+
+// The same text as lesson 4's print_message
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Quit => write!(f, "Quit"),
+            Message::Move { x, y } => write!(f, "Move to ({}, {})", x, y),
+            Message::Text(s) => write!(f, "Text: {}", s),
+        }
+    }
+}
+
+// COMPARING
+// Lesson 4's Rectangle. Debug, Clone and Copy are derived; the comparison
+// traits are written out to show what they do. #[derive(PartialEq, Eq, Hash)]
+// would write the same three, but a derived Ord compares fields in order
+// (width first), and we want the bigger area to be the bigger rectangle.
+#[derive(Debug, Clone, Copy)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    fn square(size: u32) -> Rectangle {
+        Rectangle { width: size, height: size }
+    }
+}
+
+// Equal when both sides match: 2x3 and 3x2 are different rectangles
+impl PartialEq for Rectangle {
+    fn eq(&self, other: &Rectangle) -> bool {
+        self.width == other.width && self.height == other.height
+    }
+}
+
+// Eq has no methods: it promises that a == a is always true (f64's NaN
+// breaks that, which is why f64 is only PartialEq)
+impl Eq for Rectangle {}
+
+// Hash the same fields eq compares, so equal rectangles hash the same
+impl Hash for Rectangle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+// Area first; then width and height, so only equal rectangles give Equal
+impl Ord for Rectangle {
+    fn cmp(&self, other: &Rectangle) -> Ordering {
+        self.area()
+            .cmp(&other.area())
+            .then(self.width.cmp(&other.width))
+            .then(self.height.cmp(&other.height))
+    }
+}
+
+// < and > use PartialOrd; with an Ord, it is always Some(cmp)
+impl PartialOrd for Rectangle {
+    fn partial_cmp(&self, other: &Rectangle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// OPERATORS
+// A position on a 2-D board, the x and y of Message::Move.
+// Copy means a + b doesn't use up a and b.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+// FROM AND TRYFROM
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        Point { x, y }
+    }
+}
+
+// Every Point can be a Move...
+impl From<Point> for Message {
+    fn from(point: Point) -> Message {
+        Message::Move { x: point.x, y: point.y }
+    }
+}
+
+// ...but only a Move can be a Point
+#[derive(Debug, PartialEq)]
+struct NotAMove(String);
+
+impl fmt::Display for NotAMove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\" is not a Move", self.0)
+    }
+}
+
+impl TryFrom<&Message> for Point {
+    type Error = NotAMove;
+
+    fn try_from(message: &Message) -> Result<Point, NotAMove> {
+        match message {
+            Message::Move { x, y } => Ok(Point { x: *x, y: *y }),
+            other => Err(NotAMove(other.to_string())),
+        }
+    }
+}
+
+// DEFAULT
+// A derived Default would give an empty name and active: false
+impl Default for User {
+    fn default() -> User {
+        User { name: String::from("guest"), age: 18, active: true }
+    }
+}
+
+// ITERATOR AND INTOITERATOR
+// A path across the board: the points a piece moves through, in order
+#[derive(Debug, Default)]
+struct Route {
+    points: Vec<Point>,
+}
+
+impl Route {
+    fn new(points: Vec<Point>) -> Route {
+        Route { points }
+    }
+
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    // Legs borrows the points, so it has a lifetime (lesson 7)
+    fn legs(&self) -> Legs<'_> {
+        Legs { rest: &self.points }
+    }
+}
+
+// The iterator: remembers which points are still to come
+struct Legs<'a> {
+    rest: &'a [Point],
+}
+
+impl<'a> Iterator for Legs<'a> {
+    type Item = Point;
+
+    // A slice pattern from lesson 15: two points left means one more leg
+    fn next(&mut self) -> Option<Point> {
+        match self.rest {
+            [from, to, ..] => {
+                let leg = *to - *from;
+                self.rest = &self.rest[1..];
+                Some(leg)
+            }
+            _ => None,
+        }
+    }
+}
+
+// for point in route: the Vec's own by-value iterator does the work
+impl IntoIterator for Route {
+    type Item = Point;
+    type IntoIter = std::vec::IntoIter<Point>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
+
+// for point in &route: borrows each Point
+impl<'a> IntoIterator for &'a Route {
+    type Item = &'a Point;
+    type IntoIter = std::slice::Iter<'a, Point>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.iter()
+    }
+}
+
+// UNIT TESTS
+// One group per trait, as in lesson 12
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of(rectangle: &Rectangle) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        rectangle.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn route() -> Route {
+        Route::new(vec![Point { x: 0, y: 0 }, Point { x: 3, y: 4 }, Point { x: 3, y: 9 }])
+    }
+
+    // Display
+    #[test]
+    fn user_display_shows_name_age_and_status() {
+        let bob = User { name: String::from("Bob"), age: 17, active: false };
+        assert_eq!(bob.to_string(), "Bob (17, inactive)");
+        assert_eq!(format!("{}", User::default()), "guest (18, active)");
+    }
+
+    #[test]
+    fn message_display_matches_print_message() {
+        assert_eq!(Message::Quit.to_string(), "Quit");
+        assert_eq!(Message::Move { x: 3, y: -4 }.to_string(), "Move to (3, -4)");
+        assert_eq!(Message::Text(String::from("hi")).to_string(), "Text: hi");
+    }
+
+    // PartialEq, Eq and Hash
+    #[test]
+    fn rectangles_are_equal_when_both_sides_are() {
+        assert_eq!(Rectangle { width: 2, height: 3 }, Rectangle { width: 2, height: 3 });
+        assert_ne!(Rectangle { width: 2, height: 3 }, Rectangle { width: 3, height: 2 });
+        assert_ne!(Rectangle { width: 2, height: 3 }, Rectangle { width: 2, height: 4 });
+    }
+
+    #[test]
+    fn equal_rectangles_hash_the_same() {
+        let a = Rectangle { width: 5, height: 7 };
+        let b = Rectangle { width: 5, height: 7 };
+        assert_eq!(hash_of(&a), hash_of(&b));
+        let set = HashSet::from([a, b, Rectangle::square(5)]);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Rectangle { width: 5, height: 7 }));
+    }
+
+    // Ord and PartialOrd
+    #[test]
+    fn rectangles_sort_by_area_then_width() {
+        let mut list = [Rectangle::square(4), Rectangle { width: 6, height: 1 }, Rectangle { width: 3, height: 2 }, Rectangle { width: 2, height: 3 }];
+        list.sort();
+        let sizes: Vec<(u32, u32)> = list.iter().map(|r| (r.width, r.height)).collect();
+        assert_eq!(sizes, vec![(2, 3), (3, 2), (6, 1), (4, 4)]);
+        assert!(Rectangle { width: 1, height: 1 } < Rectangle { width: 1, height: 2 });
+    }
+
+    #[test]
+    fn cmp_is_equal_exactly_when_eq() {
+        let sizes = [(0, 5), (0, 7), (2, 3), (3, 2), (6, 1), (1, 6), (4, 4)];
+        for &(w1, h1) in &sizes {
+            for &(w2, h2) in &sizes {
+                let a = Rectangle { width: w1, height: h1 };
+                let b = Rectangle { width: w2, height: h2 };
+                assert_eq!(a.cmp(&b) == Ordering::Equal, a == b, "{:?} and {:?}", a, b);
+                assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+            }
+        }
+    }
+
+    // Add, Sub and Neg
+    #[test]
+    fn points_add_subtract_and_negate() {
+        let a = Point { x: 1, y: 2 };
+        let b = Point { x: 3, y: -1 };
+        assert_eq!(a + b, Point { x: 4, y: 1 });
+        assert_eq!(a - b, Point { x: -2, y: 3 });
+        assert_eq!(-b, Point { x: -3, y: 1 });
+    }
+
+    #[test]
+    fn subtracting_undoes_adding() {
+        let a = Point { x: -8, y: 5 };
+        let b = Point { x: 13, y: 21 };
+        assert_eq!(a + b - b, a);
+        assert_eq!(a - b, a + -b);
+        assert_eq!(-(-a), a);
+    }
+
+    // From and TryFrom
+    #[test]
+    fn tuples_become_points_and_points_become_moves() {
+        let point: Point = (2, -7).into();
+        assert_eq!(point, Point { x: 2, y: -7 });
+        let message = Message::from(point);
+        assert_eq!(message.to_string(), "Move to (2, -7)");
+    }
+
+    #[test]
+    fn only_a_move_converts_to_a_point() {
+        assert_eq!(Point::try_from(&Message::Move { x: 8, y: 1 }), Ok(Point { x: 8, y: 1 }));
+        assert_eq!(Point::try_from(&Message::Quit), Err(NotAMove(String::from("Quit"))));
+        let error = Point::try_from(&Message::Text(String::from("hi"))).unwrap_err();
+        assert_eq!(error.to_string(), "\"Text: hi\" is not a Move");
+    }
+
+    #[test]
+    fn a_point_survives_the_round_trip_through_message() {
+        let point = Point { x: -4, y: 11 };
+        assert_eq!(Point::try_from(&Message::from(point)), Ok(point));
+    }
+
+    // Default
+    #[test]
+    fn defaults() {
+        assert_eq!(Point::default(), Point { x: 0, y: 0 });
+        let user = User { name: String::from("Carol"), ..Default::default() };
+        assert_eq!((user.name.as_str(), user.age, user.active), ("Carol", 18, true));
+        assert_eq!(Route::default().len(), 0);
+    }
+
+    // Iterator
+    #[test]
+    fn legs_are_the_differences_between_points() {
+        let legs: Vec<Point> = route().legs().collect();
+        assert_eq!(legs, vec![Point { x: 3, y: 4 }, Point { x: 0, y: 5 }]);
+    }
+
+    #[test]
+    fn short_routes_have_no_legs() {
+        assert_eq!(Route::default().legs().next(), None);
+        assert_eq!(Route::new(vec![Point { x: 1, y: 1 }]).legs().count(), 0);
+    }
+
+    // IntoIterator
+    #[test]
+    fn for_loops_work_by_reference_and_by_value() {
+        let route = route();
+        let mut borrowed = Vec::new();
+        for point in &route {
+            borrowed.push(*point);
+        }
+        let owned: Vec<Point> = route.into_iter().collect();
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned.len(), 3);
+    }
+}
+
+// LESSON 16 SUMMARY:
+// - Standard traits make your types work with Rust's own syntax and library (see Display)
+// - Display is written by hand for {} and gives to_string(); Debug is derived for {:?} (see Display)
+// - PartialEq/Eq give == and !=; Hash must agree with Eq for HashSet and HashMap keys (see Comparing)
+// - Ord gives sort() and max(), and PartialOrd gives < and >; they must agree with == (see Comparing)
+// - std::ops traits (Add, Sub, Neg, ...) overload operators, with Output as the result (see Operators)
+// - From gives .into() for free; TryFrom returns a Result for conversions that can fail (see From and TryFrom)
+// - Default gives a starting value; Iterator needs only next(), IntoIterator makes for loops work (see Default)
@@ -170,7 +170,9 @@ fn main() {
     println!("2. Create a vector of your favorite colors and print them with their positions");
     println!("3. Write a function that finds the maximum number in a vector");
     println!("4. Create a simple guessing game using loops and conditionals");
+    // (a reference solution for exercise 4 lives in tools/guess.rs)
     println!("5. Practice with string manipulation: reverse a string, count vowels, etc.");
+    // Exercises 1, 3 and 5 can be checked automatically: see `./grader --list` in tools/
     
     println!("\n--- End of Lesson 2 ---");
     println!("Next lesson will cover: Ownership, Borrowing, and Lifetimes");
@@ -222,15 +224,15 @@ fn get_letter_grade(average: f64) -> char {
 //     }
 
 // LESSON 2 SUMMARY:
-// - Functions are defined with 'fn' and can take parameters and return values
-// - Control flow: if/else expressions, loops (loop, while, for)
-// - Collections: Arrays (fixed size), Vectors (dynamic size)
-// - Strings: &str (string slices) vs String (owned strings)
-// - Pattern matching with 'match' is powerful for handling different cases
-// - Use & to borrow data instead of taking ownership
-// - Ranges: .. (exclusive end), ..= (inclusive end)
-// - {:?} for debug printing, {:.1} for formatted floating point
-// - Functions without semicolon on last line return that value
+// - Functions are defined with 'fn' and can take parameters and return values (see Functions)
+// - Control flow: if/else expressions, loops (loop, while, for) (see Control Flow: If/Else)
+// - Collections: Arrays (fixed size), Vectors (dynamic size) (see Collections)
+// - Strings: &str (string slices) vs String (owned strings) (see Strings)
+// - Pattern matching with 'match' is powerful for handling different cases (see Pattern Matching)
+// - Use & to borrow data instead of taking ownership (see Practical Example: Grade Calculator)
+// - Ranges: .. (exclusive end), ..= (inclusive end) (see Loops)
+// - {:?} for debug printing, {:.1} for formatted floating point (see Collections)
+// - Functions without semicolon on last line return that value (see Functions)
 
 
 
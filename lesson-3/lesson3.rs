@@ -1,6 +1,23 @@
 // Lesson 3: Ownership, Borrowing, and Lifetimes
+// To compile and run: rustc lesson3.rs && ./lesson3 (or lesson3.exe on Windows)
+
+// Extra modes that re-run this lesson's ideas with more detail:
+// trace.rs prints every create/move/drop, memory.rs draws the stack and heap
+mod memory;
+mod trace;
 
 fn main() {
+    // Run `./lesson3 trace` to watch values being created, moved and dropped
+    if std::env::args().any(|arg| arg == "trace") {
+        trace::run();
+        return;
+    }
+    // Run `./lesson3 memory` to see where a String's bytes actually live
+    if std::env::args().any(|arg| arg == "memory") {
+        memory::run();
+        return;
+    }
+
     println!("=== Lesson 3: Ownership, Borrowing, and Lifetimes ===");
     
     // OWNERSHIP - Rust's Most Important Feature
@@ -20,7 +37,7 @@ fn main() {
     
     // Copy vs Move
     let x = 5;
-    let y = x; // Integers implement Copy trait, so both x and y are validasdf
+    let y = x; // Integers implement Copy trait, so both x and y are valid
     println!("x: {}, y: {}", x, y);
     
     // Function ownership
@@ -48,6 +65,8 @@ fn main() {
     println!("\n--- Borrowing Rules ---");
     println!("1. You can have many immutable references OR one mutable reference");
     println!("2. References must always be valid (no dangling references)");
+    // To experiment with breaking these rules, try tools/borrowsim.rs:
+    // it checks small programs and explains each error using the rules above
     
     // Multiple immutable references - OK
     let data = String::from("Shareable data");
@@ -177,9 +196,10 @@ fn main() {
     println!("3. Practice with vector ownership: write functions that modify vectors");
     println!("4. Implement a function that safely accesses vector elements by index");
     println!("5. Create a text analyzer that counts vowels, consonants, and spaces");
+    // Exercises 1, 2, 4 and 5 can be checked automatically: see `./grader --list` in tools/
     
     println!("\n--- End of Lesson 3 ---");
-    println!("Next lesson will cover: Structs, Enums, and Pattern Matching");
+    println!("Next lesson will cover: Structs and Enums");
 }
 
 // FUNCTION DEFINITIONS
@@ -227,7 +247,9 @@ fn get_first_word(s: &String) -> &str {
 }
 
 // Takes ownership and returns ownership
-fn add_word_and_return(mut words: Vec<&str>, new_word: &str) -> Vec<&str> {
+// 'a says the returned words borrow from the same place as the inputs
+// (with two reference inputs, Rust can't guess this on its own)
+fn add_word_and_return<'a>(mut words: Vec<&'a str>, new_word: &'a str) -> Vec<&'a str> {
     words.push(new_word);
     words
 }
@@ -253,29 +275,29 @@ fn count_words(text: &str) -> usize {
 }
 
 // LESSON 3 SUMMARY:
-// - Ownership: Each value has one owner, owner is responsible for cleanup
-// - Move vs Copy: Some types move (String), others copy (i32)
-// - Borrowing: Use & to create references without taking ownership
-// - &T for immutable references, &mut T for mutable references
-// - Borrowing rules: Many immutable OR one mutable reference at a time
-// - String slices (&str) are references to parts of strings
-// - References must be valid for their entire lifetime
-// - Use clone() to create independent copies when needed
-// - Dereference with * to access/modify values through references
+// - Ownership: Each value has one owner, owner is responsible for cleanup (see Ownership Rules)
+// - Move vs Copy: Some types move (String), others copy (i32) (see Ownership Examples)
+// - Borrowing: Use & to create references without taking ownership (see Borrowing with References)
+// - &T for immutable references, &mut T for mutable references (see Borrowing with References)
+// - Borrowing rules: Many immutable OR one mutable reference at a time (see Borrowing Rules)
+// - String slices (&str) are references to parts of strings (see String Slices)
+// - References must be valid for their entire lifetime (see Reference Lifetimes)
+// - Use clone() to create independent copies when needed (see Practical Ownership Patterns)
+// - Dereference with * to access/modify values through references (see Borrowing Rules)
 
 // KEY CONCEPTS:
 // Stack vs Heap:
-// - Stack: Fast, fixed size, automatic cleanup (Copy types like i32)
-// - Heap: Flexible size, manual cleanup (Move types like String)
+// - Stack: Fast, fixed size, automatic cleanup (Copy types like i32) (see Ownership Examples)
+// - Heap: Flexible size, manual cleanup (Move types like String) (see Ownership Examples)
 // 
 // When to use what:
-// - Use & when you want to read data without taking ownership
-// - Use &mut when you want to modify data without taking ownership
-// - Use move (no &) when you want to transfer ownership
-// - Use clone() when you need independent copies
+// - Use & when you want to read data without taking ownership (see Borrowing with References)
+// - Use &mut when you want to modify data without taking ownership (see Borrowing with References)
+// - Use move (no &) when you want to transfer ownership (see Ownership Examples)
+// - Use clone() when you need independent copies (see Practical Ownership Patterns)
 // 
 // Common patterns:
-// - Functions that read: take &T
-// - Functions that modify: take &mut T
-// - Functions that consume: take T
-// - Functions that create: return T
\ No newline at end of file
+// - Functions that read: take &T (see Practical Ownership Patterns)
+// - Functions that modify: take &mut T (see Practical Ownership Patterns)
+// - Functions that consume: take T (see Practical Ownership Patterns)
+// - Functions that create: return T (see Practical Ownership Patterns)
\ No newline at end of file
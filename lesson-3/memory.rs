@@ -0,0 +1,206 @@
+// Lesson 3 memory mode: see the stack and the heap
+// Run with: rustc lesson3.rs && ./lesson3 memory
+//
+// A String or Vec is split in two:
+// - on the STACK: a small fixed-size header (pointer, length, capacity)
+// - on the HEAP: the actual bytes/elements, which can grow
+// This mode prints both halves with real addresses, so you can check for
+// yourself that a move keeps the heap data while clone() makes new heap data.
+// (Addresses change from run to run; what matters is whether they match.)
+
+use std::fmt::Debug;
+
+// Heap cells to draw before switching to "..."
+const MAX_CELLS: usize = 16;
+
+// What we remember about a value, so we can compare before/after
+#[derive(Clone, Copy)]
+struct Layout {
+    stack: usize,
+    heap: usize,
+    len: usize,
+    capacity: usize,
+}
+
+pub fn run() {
+    println!("=== Lesson 3 (memory mode): Stack vs Heap ===");
+
+    // COPY TYPES LIVE ENTIRELY ON THE STACK
+    println!("\n--- i32: stack only ---");
+    let x: i32 = 5;
+    let y = x;
+    println!("x = {} lives at {:p} (stack)", x, &x);
+    println!("y = {} lives at {:p} (stack) - a full copy, no heap involved", y, &y);
+
+    // STRING: HEADER ON THE STACK, TEXT ON THE HEAP
+    println!("\n--- String::new() ---");
+    let mut s1 = String::new();
+    let before = show_string("s1", &s1);
+
+    println!("\n--- s1.push_str(\"Hello\") ---");
+    s1.push_str("Hello");
+    let after = show_string("s1", &s1);
+    compare("s1 before", before, "s1 after", after);
+
+    // Needs more room than capacity 8: a bigger heap block is allocated
+    // (the allocator may be able to grow it in place, so the pointer can stay the same)
+    println!("\n--- s1.push_str(\", world\") ---");
+    let before = after;
+    s1.push_str(", world");
+    let after = show_string("s1", &s1);
+    compare("s1 before", before, "s1 after", after);
+
+    // MOVE: a new stack header pointing at the SAME heap data
+    println!("\n--- let s2 = s1; (move) ---");
+    let before = after;
+    let mut s2 = s1; // mut only so we can shrink_to_fit() below
+    let after = show_string("s2", &s2);
+    compare("s1 (moved away)", before, "s2", after);
+
+    // CLONE: new heap data with the same contents
+    println!("\n--- let s3 = s2.clone(); ---");
+    let s3 = s2.clone();
+    let cloned = show_string("s3", &s3);
+    compare("s2", after, "s3", cloned);
+
+    // SHRINK_TO_FIT: give back unused capacity
+    println!("\n--- s2.shrink_to_fit() ---");
+    let before = after;
+    s2.shrink_to_fit();
+    let after = show_string("s2", &s2);
+    compare("s2 before", before, "s2 after", after);
+
+    // VEC: same idea, but the heap holds elements instead of bytes
+    println!("\n--- Vec growth: push one at a time ---");
+    let mut numbers: Vec<i32> = Vec::new();
+    let mut last_capacity = numbers.capacity();
+    for n in 1..=9 {
+        numbers.push(n * 10);
+        if numbers.capacity() != last_capacity {
+            println!(
+                "push({:>2}): len {} > old capacity {}, so a bigger block was allocated (capacity {})",
+                n * 10,
+                numbers.len(),
+                last_capacity,
+                numbers.capacity()
+            );
+            last_capacity = numbers.capacity();
+        }
+    }
+    let before = show_vec("numbers", &numbers);
+
+    println!("\n--- let moved = numbers; (move) ---");
+    let mut moved = numbers; // mut only so we can shrink_to_fit() below
+    let after = show_vec("moved", &moved);
+    compare("numbers (moved away)", before, "moved", after);
+
+    println!("\n--- let copy = moved.clone(); ---");
+    let copy = moved.clone();
+    let cloned = show_vec("copy", &copy);
+    compare("moved", after, "copy", cloned);
+
+    println!("\n--- moved.shrink_to_fit() ---");
+    moved.shrink_to_fit();
+    let shrunk = show_vec("moved", &moved);
+    compare("moved before", after, "moved after", shrunk);
+
+    println!("\n--- End of memory mode ---");
+}
+
+fn layout_of_string(s: &String) -> Layout {
+    Layout {
+        stack: s as *const String as usize,
+        heap: s.as_ptr() as usize,
+        len: s.len(),
+        capacity: s.capacity(),
+    }
+}
+
+// Draw a String: stack header on the left, heap bytes on the right
+fn show_string(name: &str, s: &String) -> Layout {
+    let layout = layout_of_string(s);
+    let cells: Vec<String> = s.chars().map(|c| c.to_string()).collect();
+    draw(name, "String", layout, &cells);
+    layout
+}
+
+// Draw a Vec the same way, one cell per element
+fn show_vec<T: Debug>(name: &str, v: &Vec<T>) -> Layout {
+    let layout = Layout {
+        stack: v as *const Vec<T> as usize,
+        heap: v.as_ptr() as usize,
+        len: v.len(),
+        capacity: v.capacity(),
+    };
+    let cells: Vec<String> = v.iter().map(|item| format!("{:?}", item)).collect();
+    draw(name, &format!("Vec<{}>", std::any::type_name::<T>()), layout, &cells);
+    layout
+}
+
+//  s1: String
+//  STACK @ 0x7ffe6ac86058               HEAP @ 0x55e4c30feda0
+//  ┌──────────┬────────────────┐        ┌───┬───┬───┬───┬───┬───┬───┬───┐
+//  │ ptr      │ 0x55e4c30feda0 │──────> │ H │ e │ l │ l │ o │ · │ · │ · │
+//  │ len      │ 5              │        └───┴───┴───┴───┴───┴───┴───┴───┘
+//  │ capacity │ 8              │        5 used, 3 spare (·)
+//  └──────────┴────────────────┘
+fn draw(name: &str, type_name: &str, layout: Layout, used: &[String]) {
+    let mut cells: Vec<String> = used.to_vec();
+    // Show spare capacity as empty cells (capacity counts bytes for String,
+    // so a multi-byte char like 🦀 makes this an approximation)
+    let spare = layout.capacity.saturating_sub(layout.len);
+    cells.extend(std::iter::repeat_n(String::from("·"), spare));
+    let hidden = cells.len().saturating_sub(MAX_CELLS);
+    cells.truncate(MAX_CELLS);
+
+    let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(1);
+    let border = |left: &str, mid: &str, right: &str| -> String {
+        let pieces: Vec<String> = cells.iter().map(|_| "─".repeat(width + 2)).collect();
+        format!("{}{}{}", left, pieces.join(mid), right)
+    };
+    let row: String = cells
+        .iter()
+        .map(|c| format!("│ {:<w$} ", c, w = width))
+        .collect::<String>()
+        + "│";
+    let more = if hidden > 0 { format!(" ... {} more", hidden) } else { String::new() };
+
+    let heap_address = format!("{:#x}", layout.heap);
+    println!("{}: {}", name, type_name);
+    if layout.capacity == 0 {
+        // Empty String/Vec don't allocate: the pointer is a placeholder
+        println!("STACK @ {:#x}", layout.stack);
+        println!("┌──────────┬────────────────┐");
+        println!("│ ptr      │ {:<14} │──────> (nothing allocated yet)", "(dangling)");
+        println!("│ len      │ {:<14} │", layout.len);
+        println!("│ capacity │ {:<14} │", layout.capacity);
+        println!("└──────────┴────────────────┘");
+        return;
+    }
+
+    println!("STACK @ {:<#29x}HEAP @ {}", layout.stack, heap_address);
+    println!("┌──────────┬────────────────┐        {}", border("┌", "┬", "┐"));
+    println!("│ ptr      │ {:<14} │──────> {}{}", heap_address, row, more);
+    println!("│ len      │ {:<14} │        {}", layout.len, border("└", "┴", "┘"));
+    println!(
+        "│ capacity │ {:<14} │        {} used, {} spare (·)",
+        layout.capacity, layout.len, spare
+    );
+    println!("└──────────┴────────────────┘");
+}
+
+// Spell out what changed between two layouts
+fn compare(a_name: &str, a: Layout, b_name: &str, b: Layout) {
+    let same = |x: usize, y: usize| if x == y { "same" } else { "DIFFERENT" };
+    println!(
+        "{} -> {}: stack header {}, heap pointer {}, len {} -> {}, capacity {} -> {}",
+        a_name,
+        b_name,
+        same(a.stack, b.stack),
+        same(a.heap, b.heap),
+        a.len,
+        b.len,
+        a.capacity,
+        b.capacity
+    );
+}
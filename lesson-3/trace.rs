@@ -0,0 +1,176 @@
+// Lesson 3 trace mode: watch ownership happen
+// Run with: rustc lesson3.rs && ./lesson3 trace
+//
+// Traced<T> wraps a value and prints a line whenever the value is created,
+// cloned, moved (where we mark it with .moved_to()) and dropped.
+// Each line is indented by the current scope depth, so you can see exactly
+// which scope a value dies in.
+
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
+
+// thread_local! gives us a "global" counter without needing unsafe code
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static STEP: Cell<usize> = const { Cell::new(0) };
+}
+
+// Print one numbered timeline line, indented by scope depth
+fn log(event: &str) {
+    let step = STEP.with(|s| {
+        s.set(s.get() + 1);
+        s.get()
+    });
+    let depth = DEPTH.with(|d| d.get());
+    println!("{:>3} │ {}{}", step, "│   ".repeat(depth), event);
+}
+
+// Run `body` one scope deeper. Values created inside (and values moved in)
+// are dropped before we leave, so their drop lines are indented too.
+fn scope<R>(label: &str, body: impl FnOnce() -> R) -> R {
+    log(&format!("{{ enter {} (depth {})", label, DEPTH.with(|d| d.get()) + 1));
+    DEPTH.with(|d| d.set(d.get() + 1));
+    let result = body();
+    DEPTH.with(|d| d.set(d.get() - 1));
+    log(&format!("}} leave {}", label));
+    result
+}
+
+// A value with a name that reports what happens to it
+pub struct Traced<T: Debug> {
+    name: String,
+    value: T,
+}
+
+impl<T: Debug> Traced<T> {
+    pub fn new(name: &str, value: T) -> Traced<T> {
+        log(&format!("+ create {} = {:?}", name, value));
+        Traced {
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    // Rust can't tell us when a move happens (it's just a memcpy), so we
+    // mark moves ourselves: `let s2 = s1.moved_to("s2");`
+    // Taking `self` by value means the old name really is unusable afterwards.
+    pub fn moved_to(mut self, new_owner: &str) -> Traced<T> {
+        log(&format!("> move   {} -> {}", self.name, new_owner));
+        self.name = new_owner.to_string();
+        self
+    }
+}
+
+impl<T: Debug + Clone> Clone for Traced<T> {
+    fn clone(&self) -> Traced<T> {
+        let name = format!("{}.clone()", self.name);
+        log(&format!("* clone  {} -> {} (new, independent value)", self.name, name));
+        Traced {
+            name,
+            value: self.value.clone(),
+        }
+    }
+}
+
+// Drop runs automatically when the owner goes out of scope
+impl<T: Debug> Drop for Traced<T> {
+    fn drop(&mut self) {
+        log(&format!("- drop   {} = {:?}", self.name, self.value));
+    }
+}
+
+// Deref lets us use a Traced<String> like a String (e.g. s.len(), println!("{}", *s))
+impl<T: Debug> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Debug> DerefMut for Traced<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// THE LESSON 3 EXAMPLES, TRACED
+
+pub fn run() {
+    println!("=== Lesson 3 (trace mode): Ownership, step by step ===");
+    println!("Legend: + create   * clone   > move   - drop   {{ }} scopes");
+
+    println!("\n--- Move: let s2 = s1 ---");
+    scope("main", || {
+        let s1 = Traced::new("s1", String::from("Hello"));
+        let s2 = s1.moved_to("s2"); // no copy of the text, just a new owner
+        log(&format!("  s2: {}", *s2));
+    }); // s2 is dropped here; s1 has nothing left to drop
+
+    println!("\n--- Function ownership: take_ownership(message) ---");
+    scope("main", || {
+        let message = Traced::new("message", String::from("Hello, Functions!"));
+        take_ownership(message.moved_to("take_ownership(s)"));
+        log("  (message can't be used here any more)");
+    });
+
+    println!("\n--- Returning ownership: add_word_and_return ---");
+    scope("main", || {
+        let words = Traced::new("words", vec!["apple", "banana", "cherry"]);
+        let words = add_word_and_return(words.moved_to("add_word_and_return(words)"), "date")
+            .moved_to("words (returned)");
+        log(&format!("  Words after adding: {:?}", *words));
+    });
+
+    println!("\n--- Clone: independent copies ---");
+    scope("main", || {
+        let original_list = Traced::new("original_list", vec!["red", "green", "blue"]);
+        let copied_list = original_list.clone().moved_to("copied_list");
+        log(&format!("  Original: {:?}, Copy: {:?}", *original_list, *copied_list));
+    }); // two values, so two drops (in reverse order of creation)
+
+    println!("\n--- Consuming loop: for num in numbers ---");
+    scope("main", || {
+        let numbers = vec![
+            Traced::new("numbers[0]", 2),
+            Traced::new("numbers[1]", 4),
+            Traced::new("numbers[2]", 6),
+        ];
+
+        // Borrowing loop: nothing moves, nothing is dropped
+        for num in &numbers {
+            log(&format!("  Borrowing: {}", **num));
+        }
+
+        // Consuming loop: each element is moved into `num`
+        // and dropped at the end of its own iteration
+        log("> move   numbers -> for loop");
+        for num in numbers {
+            scope("loop body", || {
+                let num = num.moved_to("num");
+                log(&format!("  Consuming: {}", *num));
+            });
+        }
+        log("  (numbers is no longer valid here)");
+    });
+
+    println!("\n--- End of trace ---");
+}
+
+// Same as lesson 3's take_ownership, but with a traced String
+fn take_ownership(s: Traced<String>) {
+    scope("take_ownership", move || {
+        log(&format!("  I now own: {}", *s));
+    }) // s goes out of scope and is dropped - you can see it happen above this line
+}
+
+// Same as lesson 3's add_word_and_return: ownership goes in, and comes back out
+fn add_word_and_return<'a>(words: Traced<Vec<&'a str>>, new_word: &'a str) -> Traced<Vec<&'a str>> {
+    scope("add_word_and_return", move || {
+        let mut words = words;
+        words.push(new_word);
+        log(&format!("  pushed {:?}", new_word));
+        words // moved out to the caller, so NOT dropped here
+    })
+}
@@ -61,8 +61,15 @@ fn main() {
     }
     println!("Not found: {:?}", not_found); // prints None
 
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Add a perimeter() method to Rectangle and a can_hold(&other) method that checks if another rectangle fits inside");
+    println!("2. Give User a constructor function new(name, age) that sets active to true");
+    println!("3. Add a ChangeColor(u8, u8, u8) variant to Message and handle it in print_message");
+    println!("4. Write a function that returns the first negative number in a slice as an Option<i32>");
+
     println!("\n--- End of Lesson 4 ---");
-    println!("Next: Error handling with Result<T, E>");
+    println!("Next lesson will cover: Error Handling with Result<T, E>");
 }
 
 // STRUCT DEFINITIONS
@@ -121,10 +128,10 @@ fn find_first_even(numbers: &[i32]) -> Option<i32> {
 }
 
 // LESSON 4 SUMMARY:
-// - struct groups named fields into one type
-// - impl adds methods; &self = read, &mut self = modify, no self = constructor
-// - enum defines a type that is exactly one of its variants
-// - enum variants can carry different data per variant
-// - Option<T> replaces null: Some(value) or None
-// - match must cover every variant (exhaustive)
-// - if let is shorthand when you only care about one variant
+// - struct groups named fields into one type (see Structs)
+// - impl adds methods; &self = read, &mut self = modify, no self = constructor (see Struct Methods)
+// - enum defines a type that is exactly one of its variants (see Enums)
+// - enum variants can carry different data per variant (see Enums)
+// - Option<T> replaces null: Some(value) or None (see Option<T>)
+// - match must cover every variant (exhaustive) (see Enums)
+// - if let is shorthand when you only care about one variant (see Option<T>)
@@ -2,7 +2,7 @@
 // To compile and run: rustc lesson5.rs && ./lesson5.exe
 
 fn main() {
-    println!("=== Lesson 5: Error Handling with Result ===");
+    println!("=== Lesson 5: Error Handling with Result<T, E> ===");
 
     // RESULT - Like Option, but the "failure" case carries info
     // Result<T, E> is either Ok(value) or Err(error)
@@ -46,8 +46,15 @@ fn main() {
     let safe = divide(8, 0).unwrap_or(-1);
     println!("With fallback: {}", safe); // -1
 
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Write a function that parses a string into an i32 and returns Result<i32, String>");
+    println!("2. Write a function that takes a list of strings and adds them up, using ? to stop at the first bad one");
+    println!("3. Change halve() to return a custom error enum instead of a String");
+    println!("4. Replace each unwrap() you find in earlier lessons with match or unwrap_or");
+
     println!("\n--- End of Lesson 5 ---");
-    println!("Next: Generics and Traits");
+    println!("Next lesson will cover: Generics and Traits");
 }
 
 // Returns Ok with the result, or Err with a message
@@ -77,10 +84,10 @@ fn halve_then_halve(n: i32) -> Result<i32, String> {
 }
 
 // LESSON 5 SUMMARY:
-// - Result<T, E> is Ok(value) for success or Err(error) for failure
-// - Like Option, but Err carries information about what went wrong
-// - match handles both Ok and Err explicitly
-// - ? propagates errors: returns Err early, or unwraps Ok
-// - ? only works in functions that return Result (or Option)
-// - unwrap()/expect() extract the value but PANIC on Err
-// - unwrap_or(default) gives a fallback instead of panicking
+// - Result<T, E> is Ok(value) for success or Err(error) for failure (see Result Basics)
+// - Like Option, but Err carries information about what went wrong (see Result Basics)
+// - match handles both Ok and Err explicitly (see Matching on Result)
+// - ? propagates errors: returns Err early, or unwraps Ok (see The ? Operator)
+// - ? only works in functions that return Result (or Option) (see The ? Operator)
+// - unwrap()/expect() extract the value but PANIC on Err (see unwrap / expect)
+// - unwrap_or(default) gives a fallback instead of panicking (see unwrap / expect)
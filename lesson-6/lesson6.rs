@@ -46,8 +46,15 @@ fn main() {
     println!("{}", dog.introduce());
     println!("{}", cat.introduce());
 
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Write a generic function smallest<T: PartialOrd + Copy>(list: &[T]) -> T");
+    println!("2. Add a swap() method to Pair<T> that returns a new Pair with first and second swapped");
+    println!("3. Create a Cow struct that implements Animal and overrides introduce()");
+    println!("4. Write a trait Area with an area() method and implement it for two shapes");
+
     println!("\n--- End of Lesson 6 ---");
-    println!("Next: Collections (Vec, HashMap) and iterators");
+    println!("Next lesson will cover: Explicit Lifetimes");
 }
 
 // GENERIC FUNCTION
@@ -115,10 +122,10 @@ fn describe(animal: &impl Animal) {
 }
 
 // LESSON 6 SUMMARY:
-// - Generics (<T>) let one piece of code work for many types
-// - Trait bounds (T: PartialOrd) constrain what a generic type must support
-// - A trait defines shared behavior (methods) types can implement
-// - impl Trait for Type provides that behavior for a specific type
-// - Traits can have default methods, overridable per type
-// - &impl Trait accepts any type implementing the trait (polymorphism)
-// - #[derive(Debug)] auto-generates {:?} formatting
+// - Generics (<T>) let one piece of code work for many types (see Generic Functions)
+// - Trait bounds (T: PartialOrd) constrain what a generic type must support (see Trait Bounds)
+// - A trait defines shared behavior (methods) types can implement (see Traits)
+// - impl Trait for Type provides that behavior for a specific type (see Traits)
+// - Traits can have default methods, overridable per type (see Default Trait Methods)
+// - &impl Trait accepts any type implementing the trait (polymorphism) (see Trait Bounds)
+// - #[derive(Debug)] auto-generates {:?} formatting (see Generic Structs)
@@ -0,0 +1,258 @@
+// Lesson 7: Explicit Lifetimes
+// To compile and run: rustc lesson7.rs && ./lesson7 (or lesson7.exe on Windows)
+
+// Every commented-out mistake below is also in mistakes.md next to this file,
+// where `./md-test` (tools/md_test.rs) checks it still fails with that error.
+
+use std::fmt::Debug;
+
+fn main() {
+    println!("=== Lesson 7: Explicit Lifetimes ===");
+
+    // WHY LIFETIMES? - A reference must never outlive the value it points to
+    // A lifetime is the stretch of code where a reference is valid.
+    // The borrow checker compares lifetimes to reject dangling references.
+    println!("\n--- Why Lifetimes? ---");
+
+    let outer = String::from("I live for the whole of main");
+    let r = &outer; // fine: outer lives longer than r
+    println!("r points to: {}", r);
+
+    // let r;
+    // {
+    //     let x = 5;
+    //     r = &x;
+    // } // x is dropped here...
+    // println!("{}", r); // ...so r would dangle. ERROR E0597: `x` does not live long enough
+    println!("A reference to a value in an inner scope can't be used after that scope ends");
+
+    // LIFETIME ELISION - Why get_first_word (lesson 3) needs no annotations
+    // Rust fills in lifetimes for us with three rules:
+    // 1. Each reference parameter gets its own lifetime
+    // 2. If there is exactly one input lifetime, the output gets it too
+    // 3. If a method takes &self, the output gets the lifetime of self
+    println!("\n--- Lifetime Elision ---");
+
+    let sentence = String::from("Hello wonderful world");
+    // These two functions are the same; the second spells out what rule 2 fills in
+    println!("first_word: {}", first_word(&sentence));
+    println!("first_word_explicit: {}", first_word_explicit(&sentence));
+
+    // ANNOTATING LONGEST - When the rules aren't enough
+    // longest() takes two references, so rule 2 doesn't apply and Rust can't
+    // guess which input the result borrows from. We have to say so with 'a.
+    println!("\n--- Annotating longest ---");
+
+    let first = String::from("borrow");
+    let result;
+    {
+        let second = String::from("lifetime");
+        result = longest(&first, &second);
+        // The result may point at second, so it can only be used in here
+        println!("Longest: {}", result);
+    }
+    // println!("{}", result); // ERROR E0597: `second` does not live long enough
+
+    // STRUCTS HOLDING REFERENCES - The struct can't outlive what it borrows
+    // Highlight<'a> marks one word of a sentence without copying the text.
+    println!("\n--- Structs Holding References ---");
+
+    match Highlight::find(&sentence, "wonderful") {
+        Some(highlight) => {
+            println!("Found '{}' at byte {}", highlight.text(), highlight.start);
+            println!("{}", highlight.sentence);
+            println!("{}", highlight.underline());
+        }
+        None => println!("Word not found"),
+    }
+
+    // The highlighted text outlives the Highlight itself, because text()
+    // returns &'a str: a borrow of the sentence, not of the Highlight
+    let word = {
+        let highlight = Highlight::find(&sentence, "world");
+        highlight.map_or("", |h| h.text())
+    };
+    println!("Still usable after the Highlight is gone: {}", word);
+
+    // let highlight;
+    // {
+    //     let short_lived = String::from("gone soon");
+    //     highlight = Highlight::find(&short_lived, "soon");
+    // }
+    // println!("{:?}", highlight.map(|h| h.text())); // ERROR E0597: `short_lived` does not live long enough
+
+    // MULTIPLE LIFETIME PARAMETERS - Tie the result only to the input it comes from
+    println!("\n--- Multiple Lifetime Parameters ---");
+
+    let csv = String::from("apple,banana,cherry");
+    let fruit;
+    {
+        let separator = String::from(",");
+        // fruit borrows from csv only ('a), so separator ('b) may be dropped first
+        fruit = before(&csv, &separator);
+    }
+    println!("Before the first comma: {}", fruit);
+
+    // With one lifetime for both (fn before<'a>(text: &'a str, separator: &'a str) -> &'a str)
+    // the result would be tied to separator too: ERROR E0597 at the same code.
+
+    // THE 'STATIC LIFETIME - Valid for the whole program
+    // String literals are stored in the program itself, so they are &'static str.
+    println!("\n--- The 'static Lifetime ---");
+
+    let greeting: &'static str = "Hello from the program's binary";
+    println!("{}", greeting);
+    println!("Grade for 85: {}", grade_label(85));
+    println!("Grade for 40: {}", grade_label(40));
+
+    // fn dangle() -> &'static str {
+    //     let text = String::from("temporary");
+    //     &text // ERROR E0515: cannot return reference to local variable `text`
+    // }
+    // 'static doesn't fix a dangling reference: return the String itself instead
+
+    // LIFETIME BOUNDS ON GENERICS - T: 'a and T: 'static
+    println!("\n--- Lifetime Bounds ---");
+
+    // largest_ref is lesson 6's largest without Copy: it returns a reference
+    // into the list, so it works for Strings too
+    let names = vec![String::from("Ana"), String::from("Zed"), String::from("Mia")];
+    println!("Largest name: {}", largest_ref(&names));
+
+    // Labeled<'a, T> holds a &'a T, which needs T: 'a (T lives at least as long)
+    let scores = vec![90, 72, 85];
+    let labeled = Labeled { label: "scores", item: &scores };
+    println!("{}", labeled.describe());
+
+    // T: 'static means "owns its data or only borrows 'static data",
+    // so the value can be kept for as long as we like
+    let saved: Vec<Box<dyn Debug>> = vec![keep(String::from("an owned String")), keep("a string literal"), keep(42)];
+    println!("Kept: {:?}", saved);
+
+    // let local = String::from("local");
+    // let kept = keep(&local); // ERROR E0597: `local` does not live long enough (&local isn't 'static)
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Write shortest<'a>(x: &'a str, y: &'a str) -> &'a str and call it from an inner scope");
+    println!("2. Add a method word_count(&self) -> usize to Highlight and explain why it needs no 'a");
+    println!("3. Write a struct Quote<'a> with author and text fields borrowed from one String");
+    println!("4. Give before() a single lifetime and read the compiler error it causes in main");
+
+    println!("\n--- End of Lesson 7 ---");
+    println!("Next lesson will cover: Concurrency with Threads, Channels, Arc and Mutex");
+}
+
+// ELISION
+// Written like lesson 3's get_first_word, but taking &str so it also works
+// with string literals. Rule 2 gives the output the input's lifetime.
+fn first_word(s: &str) -> &str {
+    s.split(' ').next().unwrap_or("")
+}
+
+// The same function with the elided lifetime written out. Clippy would elide
+// it again (needless_lifetimes), so this lesson's spelled-out lifetimes are
+// allowed on purpose.
+#[allow(clippy::needless_lifetimes)]
+fn first_word_explicit<'a>(s: &'a str) -> &'a str {
+    s.split(' ').next().unwrap_or("")
+}
+
+// EXPLICIT LIFETIMES
+// From questions.md Question 5: 'a is the shorter of the two input
+// lifetimes, and the result is only valid that long
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// A STRUCT HOLDING A REFERENCE
+// Highlight<'a> can't outlive the sentence it borrows.
+struct Highlight<'a> {
+    sentence: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Highlight<'a> {
+    // Finds the first place `word` appears; the word itself is only looked
+    // at here, so it doesn't need the 'a lifetime
+    fn find(sentence: &'a str, word: &str) -> Option<Highlight<'a>> {
+        let start = sentence.find(word)?;
+        Some(Highlight { sentence, start, end: start + word.len() })
+    }
+
+    // Returns &'a str, not &str: with elision (rule 3) the result would
+    // borrow from self, and couldn't outlive the Highlight
+    fn text(&self) -> &'a str {
+        &self.sentence[self.start..self.end]
+    }
+
+    // "      ^^^^^^^^^" lined up under the highlighted word
+    fn underline(&self) -> String {
+        format!("{}{}", " ".repeat(self.start), "^".repeat(self.end - self.start))
+    }
+}
+
+// MULTIPLE LIFETIMES
+// The result comes from text only, so only text shares its lifetime
+#[allow(clippy::needless_lifetimes)]
+fn before<'a, 'b>(text: &'a str, separator: &'b str) -> &'a str {
+    match text.find(separator) {
+        Some(i) => &text[..i],
+        None => text,
+    }
+}
+
+// 'STATIC
+// Every possible result is a literal, so the caller can keep it forever
+fn grade_label(score: u32) -> &'static str {
+    if score >= 50 {
+        "pass"
+    } else {
+        "fail"
+    }
+}
+
+// LIFETIME BOUNDS
+// The result borrows from the list, so it shares the list's lifetime
+#[allow(clippy::needless_lifetimes)]
+fn largest_ref<'a, T: PartialOrd>(list: &'a [T]) -> &'a T {
+    let mut biggest = &list[0];
+    for item in list {
+        if item > biggest {
+            biggest = item;
+        }
+    }
+    biggest
+}
+
+// T: 'a says every reference inside T lives at least as long as 'a.
+// (Rust infers this bound for struct fields, but it can be written out.)
+struct Labeled<'a, T: 'a> {
+    label: &'a str,
+    item: &'a T,
+}
+
+impl<'a, T: Debug + 'a> Labeled<'a, T> {
+    fn describe(&self) -> String {
+        format!("{}: {:?}", self.label, self.item)
+    }
+}
+
+// Box<dyn Debug> on its own means Box<dyn Debug + 'static>, so T must be 'static
+fn keep<T: Debug + 'static>(value: T) -> Box<dyn Debug> {
+    Box::new(value)
+}
+
+// LESSON 7 SUMMARY:
+// - A lifetime is the part of the program where a reference is valid (see Why Lifetimes?)
+// - Elision rules fill in lifetimes when there is one input reference or &self (see Lifetime Elision)
+// - longest<'a> needs 'a because the result could borrow from either input (see Annotating longest)
+// - A struct holding a reference (Highlight<'a>) can't outlive the data it borrows (see Structs Holding References)
+// - Use separate lifetimes ('a, 'b) when the result borrows from only one input (see Multiple Lifetime Parameters)
+// - 'static references (like string literals) are valid for the whole program (see The 'static Lifetime)
+// - T: 'a and T: 'static limit how long the references inside a generic type must live (see Lifetime Bounds)
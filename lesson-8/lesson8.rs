@@ -0,0 +1,215 @@
+// Lesson 8: Concurrency with Threads, Channels, Arc and Mutex
+// To compile and run: rustc lesson8.rs && ./lesson8 (or lesson8.exe on Windows)
+
+// Threads run at the same time, so the order they finish in changes from run
+// to run. To keep this lesson's output the same every time, the threads only
+// compute; main prints their results afterwards, in a fixed order.
+// The commented-out mistakes are also in mistakes.md, checked by `./md-test`.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog. The dog was sleeping. \
+                    The fox ran into the woods and the dog woke up. \
+                    A lazy afternoon for the dog, a busy one for the fox.";
+
+fn main() {
+    println!("=== Lesson 8: Concurrency with Threads, Channels, Arc and Mutex ===");
+
+    // SPAWNING THREADS - Run a closure on another thread
+    // thread::spawn returns a JoinHandle; join() waits for the thread and
+    // hands back the closure's return value.
+    println!("\n--- Spawning Threads ---");
+
+    let numbers = [1, 2, 3, 4, 5];
+    // move: the thread takes ownership of numbers, because it might outlive main's variables
+    // (an array of i32 is Copy, so the thread gets its own copy; a Vec would be moved)
+    let handle = thread::spawn(move || numbers.iter().sum::<i32>());
+    let sum = handle.join().unwrap(); // join() is Err if the thread panicked
+    println!("Sum computed on another thread: {}", sum);
+
+    // SCOPED THREADS - Borrow local data from threads
+    // thread::scope waits for every thread spawned inside it before it returns,
+    // so those threads may borrow TEXT's chunks without moving or copying them.
+    println!("\n--- Scoped Threads ---");
+
+    let chunks = split_into_chunks(TEXT, 3);
+    for (i, chunk) in chunks.iter().enumerate() {
+        println!("Chunk {}: {} bytes", i, chunk.len());
+    }
+
+    let total: usize = thread::scope(|s| {
+        let handles: Vec<_> = chunks.iter().map(|chunk| s.spawn(move || count_words(chunk))).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    println!("Words counted in parallel: {}", total);
+    println!("Words counted on one thread: {}", count_words(TEXT));
+
+    // CHANNELS - Send values from threads back to main
+    // mpsc = "multiple producer, single consumer": clone the Sender for each
+    // thread, keep the one Receiver. The loop over rx ends when every Sender is dropped.
+    println!("\n--- Channels (mpsc) ---");
+
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| {
+        for (i, chunk) in chunks.iter().enumerate() {
+            let tx = tx.clone();
+            s.spawn(move || {
+                tx.send((i, count_words(chunk))).unwrap();
+            });
+        }
+    });
+    drop(tx); // main's own Sender; without this the loop below would wait forever
+
+    let mut results: Vec<(usize, usize)> = rx.iter().collect();
+    results.sort(); // arrival order depends on which thread finished first
+    for (i, count) in &results {
+        println!("Chunk {} sent back {} words", i, count);
+    }
+
+    // SHARED STATE - Arc<Mutex<T>>
+    // Arc = atomically reference counted: shared ownership across threads.
+    // Mutex = one thread at a time: lock() waits its turn, and the lock is
+    // released when the guard it returns is dropped.
+    println!("\n--- Shared State: Arc<Mutex<T>> ---");
+
+    let frequencies: Arc<Mutex<HashMap<String, usize>>> = Arc::new(Mutex::new(HashMap::new()));
+    let mut handles = Vec::new();
+    for chunk in &chunks {
+        let frequencies = Arc::clone(&frequencies); // a new owner for the thread
+        let chunk = chunk.to_string(); // thread::spawn needs owned ('static) data
+        handles.push(thread::spawn(move || {
+            for word in chunk.split_whitespace() {
+                let word = word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+                let mut map = frequencies.lock().unwrap();
+                *map.entry(word).or_insert(0) += 1;
+            } // the lock is released at the end of each loop iteration
+        }));
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let map = frequencies.lock().unwrap();
+    println!("Different words: {}", map.len());
+    for (word, count) in top_words(&map, 3) {
+        println!("  {:<5} {}", word, count);
+    }
+    drop(map);
+
+    // READ-MOSTLY DATA - RwLock
+    // Many readers at once OR one writer, like the borrowing rules from
+    // lesson 3 (many & or one &mut), but checked while the program runs.
+    println!("\n--- Read-Mostly Data: RwLock ---");
+
+    let counts: HashMap<String, usize> = frequencies.lock().unwrap().clone();
+    let shared = Arc::new(RwLock::new(counts));
+
+    // One writer: write() waits until nobody else holds the lock
+    {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || {
+            shared.write().unwrap().insert(String::from("rust"), 1);
+        })
+        .join()
+        .unwrap();
+    }
+
+    // Several readers: read() locks can be held at the same time
+    let lookups = ["dog", "fox", "rust", "cat"];
+    let handles: Vec<_> = lookups
+        .iter()
+        .map(|&word| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || (word, shared.read().unwrap().get(word).copied()))
+        })
+        .collect();
+    for handle in handles {
+        match handle.join().unwrap() {
+            (word, Some(count)) => println!("{} appears {} time(s)", word, count),
+            (word, None) => println!("{} does not appear", word),
+        }
+    }
+
+    // WHAT THE COMPILER STOPS - Data races are compile errors
+    // questions.md Question 3 says the borrow checker prevents data races in
+    // multithreaded code. These are the errors it gives:
+    println!("\n--- What the Compiler Stops ---");
+
+    // Rc's count isn't atomic, so Rc is not Send (can't move to another thread):
+    // let shared = std::rc::Rc::new(5);
+    // thread::spawn(move || println!("{}", shared)); // ERROR E0277: `Rc<i32>` cannot be sent between threads safely
+
+    // Two threads writing through &mut at the same time:
+    // let mut total = 0;
+    // thread::scope(|s| {
+    //     s.spawn(|| total += 1);
+    //     s.spawn(|| total += 1); // ERROR E0499: cannot borrow `total` as mutable more than once at a time
+    // });
+
+    // A spawned thread borrowing a local it might outlive:
+    // let name = String::from("main");
+    // thread::spawn(|| println!("{}", name)); // ERROR E0373: closure may outlive the current function
+    println!("Rc across threads: E0277, use Arc instead");
+    println!("&mut in two threads: E0499, use a Mutex (or a channel) instead");
+    println!("Borrowing from thread::spawn: E0373, use move or thread::scope instead");
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Change split_into_chunks to make 5 chunks and check the total is still the same");
+    println!("2. Send each chunk's longest word over a channel instead of its word count");
+    println!("3. Count the letters of TEXT with Arc<Mutex<[usize; 26]>> shared by 4 threads");
+    println!("4. Replace the Mutex in the word frequency example with per-thread maps merged at the end");
+
+    println!("\n--- End of Lesson 8 ---");
+    println!("Next lesson will cover: Smart Pointers: Box, Rc, RefCell and Weak");
+}
+
+// Word counting example from lesson 3
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+// Split text into about `n` pieces of similar size, only at spaces,
+// so no word is cut in half. The pieces borrow from text (lesson 7).
+// Asking for 0 pieces gives 1, so no text is ever lost.
+fn split_into_chunks(text: &str, n: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    for left in (1..=n.max(1)).rev() {
+        if left == 1 || rest.is_empty() {
+            chunks.push(rest);
+            break;
+        }
+        // len() counts bytes, and a character like 'é' takes more than one,
+        // so move forward to where a character starts before slicing there
+        let mut target = rest.len() / left;
+        while !rest.is_char_boundary(target) {
+            target += 1;
+        }
+        // The first space at or after the target size
+        let cut = rest[target..].find(' ').map_or(rest.len(), |i| target + i);
+        chunks.push(&rest[..cut]);
+        rest = rest[cut..].trim_start();
+    }
+    chunks
+}
+
+// Most frequent words first; ties in alphabetical order so the output is stable
+fn top_words(map: &HashMap<String, usize>, n: usize) -> Vec<(&str, usize)> {
+    let mut words: Vec<(&str, usize)> = map.iter().map(|(w, c)| (w.as_str(), *c)).collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    words.truncate(n);
+    words
+}
+
+// LESSON 8 SUMMARY:
+// - thread::spawn runs a closure on a new thread; join() waits and returns its result (see Spawning Threads)
+// - move closures give a thread ownership of what it uses (see Spawning Threads)
+// - thread::scope lets threads borrow local data, because they finish before it returns (see Scoped Threads)
+// - mpsc channels send values from many threads to one receiver (see Channels (mpsc))
+// - Arc shares ownership across threads; Rc can't, because its count isn't atomic (see Shared State: Arc<Mutex<T>>)
+// - Mutex allows one thread at a time; RwLock allows many readers or one writer (see Read-Mostly Data: RwLock)
+// - Data races (Rc or &mut shared between threads) are compile errors, not bugs (see What the Compiler Stops)
@@ -0,0 +1,216 @@
+// Lesson 9: Smart Pointers: Box, Rc, RefCell and Weak
+// To compile and run: rustc lesson9.rs && ./lesson9 (or lesson9.exe on Windows)
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+fn main() {
+    println!("=== Lesson 9: Smart Pointers: Box, Rc, RefCell and Weak ===");
+
+    // BOX - One owner, value on the heap
+    // A Box is a pointer with a fixed size, whatever it points to. That lets
+    // an enum contain itself: a List holds a Box<List>, not a List.
+    println!("\n--- Box<T> and Recursive Types ---");
+
+    let boxed = Box::new(5); // the 5 lives on the heap, the pointer on the stack
+    println!("boxed = {}, doubled = {}", boxed, *boxed * 2); // * follows the pointer
+
+    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    println!("list: {}", list.describe());
+    println!("sum: {}", list.sum());
+
+    // enum List { Cons(i32, List), Nil } // ERROR E0072: recursive type `List` has infinite size
+
+    // RC - Shared ownership, counted
+    // Rc::clone doesn't copy the data, it adds one to the "strong count".
+    // The data is dropped when the last Rc pointing to it is dropped.
+    println!("\n--- Rc<T>: Shared Ownership ---");
+
+    let shared = Rc::new(String::from("shared text"));
+    println!("after creating: strong count = {}", Rc::strong_count(&shared));
+    let second = Rc::clone(&shared);
+    {
+        let third = Rc::clone(&shared);
+        println!("after two clones: strong count = {}", Rc::strong_count(&shared));
+        println!("all three point to the same text: {}", Rc::ptr_eq(&second, &third));
+    } // third is dropped here
+    println!("after the inner scope: strong count = {}", Rc::strong_count(&shared));
+
+    // let number = Rc::new(5);
+    // *number += 1; // ERROR E0594: cannot assign to data in an `Rc` (Rc only gives shared & access)
+
+    // REFCELL - Change data behind a shared reference
+    // RefCell checks the borrowing rules (many borrow() OR one borrow_mut())
+    // while the program runs instead of at compile time. Breaking them panics.
+    println!("\n--- RefCell<T>: Interior Mutability ---");
+
+    let cell = RefCell::new(vec![1, 2, 3]);
+    cell.borrow_mut().push(4); // cell itself isn't mut, yet we can change what's inside
+    println!("cell: {:?}", cell.borrow());
+
+    {
+        let reading = cell.borrow();
+        // While `reading` is alive, a mutable borrow would panic; try_borrow_mut asks safely
+        println!("try_borrow_mut while reading: {}", if cell.try_borrow_mut().is_ok() { "ok" } else { "refused" });
+        println!("still reading: {:?}", reading);
+    }
+    println!("try_borrow_mut after reading: {}", if cell.try_borrow_mut().is_ok() { "ok" } else { "refused" });
+
+    // Rc<RefCell<T>>: several owners that can all change the shared value.
+    // The same task is on two lists; finishing it through one shows in both.
+    let task = Rc::new(RefCell::new(Task { name: String::from("Write lesson 9"), done: false }));
+    let today = [Rc::clone(&task)];
+    let project = vec![Rc::clone(&task), Rc::new(RefCell::new(Task { name: String::from("Review lesson 9"), done: false }))];
+    println!("task strong count = {} (task, today, project)", Rc::strong_count(&task));
+
+    today[0].borrow_mut().done = true;
+    for item in &project {
+        let item = item.borrow();
+        println!("project: {} [{}]", item.name, if item.done { "done" } else { "todo" });
+    }
+
+    // WEAK - A pointer that doesn't keep its target alive
+    // A child points to its parent with Weak, so parent and child don't own
+    // each other. upgrade() turns a Weak into Some(Rc) if the target still exists.
+    println!("\n--- Weak<T>: Trees Without Cycles ---");
+
+    let leaf = Rc::new(Node::new(3));
+    println!("leaf parent = {:?}", leaf.parent_value());
+    println!("leaf strong = {}, weak = {}", Rc::strong_count(&leaf), Rc::weak_count(&leaf));
+
+    {
+        let branch = Rc::new(Node::new(5));
+        branch.children.borrow_mut().push(Rc::clone(&leaf));
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+
+        println!("leaf parent = {:?}", leaf.parent_value());
+        println!("branch strong = {}, weak = {}", Rc::strong_count(&branch), Rc::weak_count(&branch));
+        println!("leaf strong = {}, weak = {}", Rc::strong_count(&leaf), Rc::weak_count(&leaf));
+        println!("tree sum from branch = {}", branch.sum());
+    } // branch's strong count drops to 0: branch is freed, even though leaf still points to it
+
+    println!("leaf parent = {:?}", leaf.parent_value());
+    println!("leaf strong = {}, weak = {}", Rc::strong_count(&leaf), Rc::weak_count(&leaf));
+
+    // LEAKING MEMORY - Two Rc pointing at each other are never freed
+    // Each keeps the other's strong count above 0, so neither is ever dropped.
+    // Rust prevents dangling pointers, but a leak like this is still "safe".
+    println!("\n--- Leaking Memory with an Rc Cycle ---");
+
+    {
+        let a = Rc::new(Link { name: "a", next: RefCell::new(None) });
+        let b = Rc::new(Link { name: "b", next: RefCell::new(None) });
+        *a.next.borrow_mut() = Some(Rc::clone(&b));
+        *b.next.borrow_mut() = Some(Rc::clone(&a)); // a -> b -> a: a cycle
+        println!("a strong = {}, b strong = {}", Rc::strong_count(&a), Rc::strong_count(&b));
+        println!("leaving the scope; a and b should be dropped...");
+    }
+    println!("...but no \"dropping\" message was printed: both were leaked");
+
+    // The same two links with b pointing back through a Weak: no cycle of strong counts
+    {
+        let a = Rc::new(Link { name: "a", next: RefCell::new(None) });
+        let b = Rc::new(BackLink { name: "b", back: Rc::downgrade(&a) });
+        println!("b points back to {:?}", b.back.upgrade().map(|link| link.name));
+        println!("a strong = {}, weak = {}", Rc::strong_count(&a), Rc::weak_count(&a));
+        println!("leaving the scope with a Weak link instead...");
+    }
+
+    // EXERCISES FOR YOU TO TRY:
+    println!("\n--- Exercises ---");
+    println!("1. Add a len() method to List that counts the Cons cells");
+    println!("2. Give Node a depth() method that walks up the parents with upgrade()");
+    println!("3. Add a second leaf to the branch and print every count again");
+    println!("4. Break the a -> b -> a cycle by making b.next a Weak and watch both drop");
+
+    println!("\n--- End of Lesson 9 ---");
+    println!("Next lesson will cover: Closures and Iterator Adaptors");
+}
+
+// RECURSIVE ENUM WITH BOX
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    fn sum(&self) -> i32 {
+        match self {
+            Cons(value, rest) => value + rest.sum(),
+            Nil => 0,
+        }
+    }
+
+    // "1 -> 2 -> 3 -> Nil"
+    fn describe(&self) -> String {
+        match self {
+            Cons(value, rest) => format!("{} -> {}", value, rest.describe()),
+            Nil => String::from("Nil"),
+        }
+    }
+}
+
+// SHARED AND MUTABLE
+struct Task {
+    name: String,
+    done: bool,
+}
+
+// A TREE WITH WEAK PARENT POINTERS
+// Parents own their children (Rc); children only refer to their parent (Weak).
+// RefCell lets us fill in parent and children after the nodes exist.
+struct Node {
+    value: i32,
+    parent: RefCell<Weak<Node>>,
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    fn new(value: i32) -> Node {
+        Node { value, parent: RefCell::new(Weak::new()), children: RefCell::new(Vec::new()) }
+    }
+
+    // None if there is no parent, or it has been dropped
+    fn parent_value(&self) -> Option<i32> {
+        self.parent.borrow().upgrade().map(|parent| parent.value)
+    }
+
+    fn sum(&self) -> i32 {
+        self.value + self.children.borrow().iter().map(|child| child.sum()).sum::<i32>()
+    }
+}
+
+// A CYCLE
+// Drop prints a message, so we can see whether a Link is ever freed
+struct Link {
+    name: &'static str,
+    next: RefCell<Option<Rc<Link>>>,
+}
+
+impl Drop for Link {
+    fn drop(&mut self) {
+        println!("dropping link {}", self.name);
+    }
+}
+
+struct BackLink {
+    name: &'static str,
+    back: Weak<Link>,
+}
+
+impl Drop for BackLink {
+    fn drop(&mut self) {
+        println!("dropping back link {}", self.name);
+    }
+}
+
+// LESSON 9 SUMMARY:
+// - Box<T> puts a value on the heap with one owner; it makes recursive types possible (see Box<T> and Recursive Types)
+// - Rc<T> gives shared ownership; Rc::clone adds to the strong count (see Rc<T>: Shared Ownership)
+// - The value behind an Rc is dropped when its strong count reaches 0 (see Rc<T>: Shared Ownership)
+// - RefCell<T> checks borrowing at run time: borrow() and borrow_mut(), panic on misuse (see RefCell<T>: Interior Mutability)
+// - Rc<RefCell<T>> = several owners who can all change the value (see RefCell<T>: Interior Mutability)
+// - Weak<T> doesn't keep a value alive; upgrade() returns None once it is gone (see Weak<T>: Trees Without Cycles)
+// - Rc cycles leak memory; point back up a tree with Weak to avoid them (see Leaking Memory with an Rc Cycle)
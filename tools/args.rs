@@ -0,0 +1,53 @@
+// Shared module: Command-Line Options
+// The tools take their options as `--name VALUE` pairs, in any order.
+//
+// To use it from a program in this folder, add `mod args;` at the top, then:
+//     let mut args: Vec<String> = env::args().skip(1).collect();
+//     let path = args::take_option(&mut args, "--progress")?;
+// Whatever is left in `args` afterwards is the program's other arguments.
+//
+// Tests: rustc --test args.rs -o args-tests && ./args-tests
+
+#![allow(dead_code)] // not every program uses every helper
+
+// Remove `--name VALUE` from the arguments and return VALUE
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|a| a == name) else {
+        return Ok(None);
+    };
+    if i + 1 >= args.len() {
+        return Err(format!("{} needs a value", name));
+    }
+    let value = args.remove(i + 1);
+    args.remove(i);
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn takes_the_option_and_its_value_out() {
+        let mut list = args(&["3.4", "--progress", "my.progress", "--reset"]);
+        assert_eq!(take_option(&mut list, "--progress"), Ok(Some(String::from("my.progress"))));
+        assert_eq!(list, args(&["3.4", "--reset"]));
+    }
+
+    #[test]
+    fn a_missing_option_leaves_the_arguments_alone() {
+        let mut list = args(&["3.4"]);
+        assert_eq!(take_option(&mut list, "--progress"), Ok(None));
+        assert_eq!(list, args(&["3.4"]));
+    }
+
+    #[test]
+    fn an_option_without_a_value_is_an_error() {
+        let mut list = args(&["3.4", "--progress"]);
+        assert_eq!(take_option(&mut list, "--progress"), Err(String::from("--progress needs a value")));
+    }
+}
@@ -0,0 +1,2175 @@
+// Borrow Checker Simulator
+// Checks programs written in a tiny Rust-like language against the ownership
+// and borrowing rules from Lesson 3 - no rustc needed, and every error comes
+// with the lesson rule it breaks.
+// To compile and run: rustc borrowsim.rs && ./borrowsim program.txt
+//   ./borrowsim                  read the program from stdin
+//   ./borrowsim --example q2     check a built-in example (--list shows them all)
+//   ./borrowsim -v program.txt   also print every move, borrow and drop
+//
+// The language: let / let mut, assignment (= += -= *= /=), &x, &mut x, *r,
+// reborrows (&*r, &mut *r), { blocks }, fn definitions and calls, drop(x),
+// method calls, println!, String::from, vec![..] and integer/string/char
+// literals. There are no ifs or loops, so every program runs top to bottom
+// and each statement gets a position number.
+//
+// Like real Rust, borrows use non-lexical lifetimes (NLL): a borrow lasts
+// until the last time the reference is used, not until the end of the block.
+// Method calls use two-phase borrows: in v.push(v.len()) the &mut v only
+// starts after v.len() has been evaluated. A &mut reference is moved, not
+// copied, unless it is passed where a reference is expected (then it is
+// reborrowed), and &mut *r borrows r itself for as long as it is used.
+//
+// Tests: rustc --test borrowsim.rs -o borrowsim-tests && ./borrowsim-tests
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+// The rules, worded the way the lessons word them
+const OWNERSHIP_RULES: &str = "Ownership: each value has a single owner; when it is moved, the old variable is no longer valid (Lesson 3, Ownership Rules)";
+const BORROW_RULE_1: &str = "Borrowing rule 1: You can have many immutable references OR one mutable reference (Lesson 3, Borrowing Rules)";
+const BORROW_RULE_2: &str = "Borrowing rule 2: References must always be valid (no dangling references) (Lesson 3, Borrowing Rules)";
+const MUT_RULE: &str = "By default, variables in Rust are immutable (cannot be changed); to make a variable mutable, use the 'mut' keyword (Lesson 1)";
+const REF_RULE: &str = "&T for immutable references, &mut T for mutable references (Lesson 3 summary)";
+const LIFETIME_RULE: &str = "Rust needs help when it can't figure out how long a returned reference will live (questions.md, Question 5)";
+
+fn main() {
+    let mut verbose = false;
+    let mut example: Option<String> = None;
+    let mut path: Option<String> = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbose = true,
+            "--list" => {
+                for (name, description, _) in EXAMPLES {
+                    println!("{:<10} {}", name, description);
+                }
+                return;
+            }
+            "--example" => example = args.next(),
+            _ => path = Some(arg),
+        }
+    }
+
+    let source = match (example, path) {
+        (Some(name), _) => match EXAMPLES.iter().find(|(n, _, _)| *n == name) {
+            Some((_, _, code)) => code.to_string(),
+            None => fail(&format!("no example called '{}' (try --list)", name)),
+        },
+        (None, Some(path)) => fs::read_to_string(&path)
+            .unwrap_or_else(|e| fail(&format!("could not read {}: {}", path, e))),
+        (None, None) => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .unwrap_or_else(|e| fail(&format!("could not read stdin: {}", e)));
+            text
+        }
+    };
+
+    let lines: Vec<&str> = source.lines().collect();
+    let report = check_program(&source);
+
+    if verbose {
+        println!("--- Timeline ---");
+        for (line, event) in &report.events {
+            println!("line {:>3}: {}", line, event);
+        }
+        println!();
+    }
+
+    for diagnostic in &report.diagnostics {
+        print_diagnostic(diagnostic, &lines);
+    }
+
+    if report.diagnostics.is_empty() {
+        println!("✅ No errors: this program follows the ownership and borrowing rules.");
+    } else {
+        println!("❌ {} error(s) found.", report.diagnostics.len());
+        process::exit(1);
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
+
+// Runs every phase; a syntax error stops early since there is nothing to check
+fn check_program(source: &str) -> Report {
+    let mut report = Report {
+        diagnostics: Vec::new(),
+        events: Vec::new(),
+    };
+
+    let parsed = tokenize(source).and_then(|tokens| Parser::new(tokens).parse_program());
+    let mut functions = match parsed {
+        Ok(functions) => functions,
+        Err(diagnostic) => {
+            report.diagnostics.push(diagnostic);
+            return report;
+        }
+    };
+
+    let mut resolver = Resolver {
+        scopes: Vec::new(),
+        visible: HashMap::new(),
+        bindings: Bindings::default(),
+        diagnostics: Vec::new(),
+    };
+    for function in &mut functions {
+        resolver.function(function);
+    }
+    report.diagnostics.append(&mut resolver.diagnostics);
+
+    for function in &functions {
+        let mut checker = Checker {
+            functions: &functions,
+            bindings: &resolver.bindings,
+            vars: HashMap::new(),
+            loans: Vec::new(),
+            live: Vec::new(),
+            scopes: Vec::new(),
+            report: &mut report,
+            pos: 0,
+        };
+        checker.function(function);
+    }
+
+    report
+        .diagnostics
+        .sort_by_key(|d| d.labels.iter().find(|l| l.primary).map(|l| l.span.line));
+    report
+}
+
+// DIAGNOSTICS
+// Shaped like rustc's: a code, a message, labelled source spans and notes.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Span {
+    line: usize,  // 1-based
+    start: usize, // 0-based column
+    end: usize,   // exclusive
+}
+
+struct Label {
+    span: Span,
+    primary: bool,
+    text: String,
+}
+
+struct Diagnostic {
+    code: &'static str,
+    message: String,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+}
+
+impl Diagnostic {
+    fn new(code: &'static str, message: String) -> Diagnostic {
+        Diagnostic {
+            code,
+            message,
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    // The ^^^ label: where the error happens
+    fn primary(mut self, span: Span, text: &str) -> Diagnostic {
+        self.labels.push(Label { span, primary: true, text: text.to_string() });
+        self
+    }
+
+    // The --- labels: related places
+    fn secondary(mut self, span: Span, text: &str) -> Diagnostic {
+        self.labels.push(Label { span, primary: false, text: text.to_string() });
+        self
+    }
+
+    fn note(mut self, text: &str) -> Diagnostic {
+        self.notes.push(text.to_string());
+        self
+    }
+}
+
+struct Report {
+    diagnostics: Vec<Diagnostic>,
+    events: Vec<(usize, String)>, // (line, what happened) for --verbose
+}
+
+fn print_diagnostic(diagnostic: &Diagnostic, lines: &[&str]) {
+    let primary_line = diagnostic
+        .labels
+        .iter()
+        .find(|l| l.primary)
+        .map(|l| l.span.line)
+        .unwrap_or(0);
+    println!("error[{}]: {}", diagnostic.code, diagnostic.message);
+    println!(" --> line {}", primary_line);
+
+    let mut labels: Vec<&Label> = diagnostic.labels.iter().collect();
+    labels.sort_by_key(|l| (l.span.line, l.span.start));
+    let width = labels.iter().map(|l| l.span.line.to_string().len()).max().unwrap_or(1);
+    let gutter = " ".repeat(width);
+
+    println!("{} |", gutter);
+    let mut last_line = 0;
+    for label in labels {
+        let text = lines.get(label.span.line - 1).copied().unwrap_or("");
+        if label.span.line != last_line {
+            println!("{:>w$} | {}", label.span.line, text, w = width);
+            last_line = label.span.line;
+        }
+        let end = label.span.end.min(text.chars().count()).max(label.span.start + 1);
+        let marker = if label.primary { "^" } else { "-" };
+        println!(
+            "{} | {}{} {}",
+            gutter,
+            " ".repeat(label.span.start),
+            marker.repeat(end - label.span.start),
+            label.text
+        );
+    }
+    println!("{} |", gutter);
+    for note in &diagnostic.notes {
+        println!("{} = {}", gutter, note);
+    }
+    println!();
+}
+
+// LEXER
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Ident(String),
+    Number,
+    Str,
+    Lifetime,
+    Sym(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+// Longest symbols first so "->" isn't read as "-" then ">"
+const SYMBOLS: [&str; 29] = [
+    "::", "->", "+=", "-=", "*=", "/=", "==", "!=", "<=", ">=", "&&", "||", "..", "(", ")", "{",
+    "}", "[", "]", ";", ",", "&", "*", "=", "+", "-", "/", ":", "!",
+];
+const MORE_SYMBOLS: [&str; 4] = [".", "<", ">", "%"];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, Diagnostic> {
+    let mut tokens = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let span = |start: usize, end: usize| Span { line: index + 1, start, end };
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+
+            if c.is_whitespace() {
+                i += 1;
+                continue;
+            }
+            if c == '/' && chars.get(i + 1) == Some(&'/') {
+                break; // comment runs to the end of the line
+            }
+
+            let tok = if c.is_alphabetic() || c == '_' {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                Tok::Ident(chars[start..i].iter().collect())
+            } else if c.is_ascii_digit() {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                // 3.14 but not 1..5
+                if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+                Tok::Number
+            } else if c == '"' {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                if i >= chars.len() {
+                    return Err(Diagnostic::new("syntax", String::from("unterminated string literal"))
+                        .primary(span(start, chars.len()), "string starts here"));
+                }
+                i += 1;
+                Tok::Str
+            } else if c == '\'' {
+                // 'a is a lifetime, 'a' is a char
+                i += 1;
+                if chars.get(i + 1) == Some(&'\'') || chars.get(i) == Some(&'\\') {
+                    // The escape's backslash is skipped with the character after
+                    // it, so the quote in '\'' doesn't end the literal early
+                    i += if chars.get(i) == Some(&'\\') { 2 } else { 1 };
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                    Tok::Number // chars are Copy values, just like numbers
+                } else {
+                    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                        i += 1;
+                    }
+                    Tok::Lifetime
+                }
+            } else {
+                // Compared in place: copying the rest of the line for every
+                // symbol would make a long line take quadratic time
+                let at_symbol = |symbol: &str| symbol.chars().enumerate().all(|(k, c)| chars.get(i + k) == Some(&c));
+                match SYMBOLS.iter().chain(MORE_SYMBOLS.iter()).find(|s| at_symbol(s)) {
+                    Some(symbol) => {
+                        i += symbol.len();
+                        Tok::Sym(symbol)
+                    }
+                    None => {
+                        return Err(Diagnostic::new("syntax", format!("unexpected character '{}'", c))
+                            .primary(span(start, start + 1), "not part of the language"));
+                    }
+                }
+            };
+            tokens.push(Token { tok, span: span(start, i) });
+        }
+    }
+
+    let last_line = source.lines().count().max(1);
+    tokens.push(Token {
+        tok: Tok::Eof,
+        span: Span { line: last_line, start: 0, end: 1 },
+    });
+    Ok(tokens)
+}
+
+// SYNTAX TREE
+
+// A variable name as written in the source; `id` is filled in by the Resolver
+#[derive(Clone, Debug)]
+struct Name {
+    text: String,
+    span: Span,
+    id: usize,
+}
+
+const UNRESOLVED: usize = usize::MAX;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Ty {
+    Copy,  // i32, bool, char, f64...
+    Owned, // String, Vec<T>... (moved, not copied)
+    Ref { mutable: bool, lifetime: bool, copy: bool }, // copy: what it points at is Copy
+}
+
+#[derive(Debug)]
+enum Expr {
+    Literal(Span),
+    New { args: Vec<Expr>, span: Span }, // String::from(..), vec![..]: a fresh owned value
+    Var(Name),
+    Borrow { mutable: bool, deref: bool, name: Name, span: Span }, // deref: a reborrow, &*r or &mut *r
+    Deref(Name),
+    Call { name: Name, args: Vec<Expr>, span: Span },
+    Method { receiver: Name, method: String, args: Vec<Expr>, span: Span },
+    Binary(Vec<Expr>), // a + b - c: the operands, in order (kept flat, so a long chain doesn't nest deeply)
+}
+
+impl Expr {
+    fn span(&self) -> Span {
+        match self {
+            Expr::Literal(span) => *span,
+            Expr::New { span, .. } | Expr::Borrow { span, .. } => *span,
+            Expr::Call { span, .. } | Expr::Method { span, .. } => *span,
+            Expr::Var(name) | Expr::Deref(name) => name.span,
+            Expr::Binary(operands) => join(operands[0].span(), operands[operands.len() - 1].span()),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum StmtKind {
+    Let { name: Name, mutable: bool, init: Option<Expr> },
+    Assign { target: Name, deref: bool, compound: bool, value: Expr },
+    Expr { expr: Expr, tail: bool }, // tail = last expression of a block, no semicolon
+    Print { args: Vec<Expr> },
+    Block { body: Vec<Stmt>, end_pos: usize, end: Span },
+}
+
+#[derive(Debug)]
+struct Stmt {
+    kind: StmtKind,
+    pos: usize, // execution order, used to decide whether a borrow is still alive
+    line: usize,
+}
+
+#[derive(Debug)]
+struct Param {
+    name: Name,
+    ty: Ty,
+}
+
+#[derive(Debug)]
+struct Function {
+    name: String,
+    params: Vec<Param>,
+    ret: Option<(Ty, Span)>,
+    body: Vec<Stmt>,
+    end: Option<(usize, Span)>, // closing brace; None for loose top-level statements
+}
+
+fn join(a: Span, b: Span) -> Span {
+    if a.line == b.line {
+        Span { line: a.line, start: a.start, end: b.end.max(a.end) }
+    } else {
+        Span { line: a.line, start: a.start, end: usize::MAX } // underline to end of line
+    }
+}
+
+// PARSER
+
+// Blocks, parentheses and & types nest by calling the parser again; past
+// this many levels it gives up with an error instead of running out of stack
+const MAX_NESTING: usize = 100;
+
+struct Parser {
+    tokens: Vec<Token>,
+    at: usize,
+    next_pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Parser {
+        Parser { tokens, at: 0, next_pos: 0, depth: 0 }
+    }
+
+    fn nested<T>(&mut self, parse: fn(&mut Parser) -> Result<T, Diagnostic>) -> Result<T, Diagnostic> {
+        if self.depth == MAX_NESTING {
+            return Err(Diagnostic::new("syntax", format!("nested more than {} levels deep", MAX_NESTING))
+                .primary(self.span(), "too deeply nested"));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn peek(&self) -> &Tok {
+        &self.tokens[self.at].tok
+    }
+
+    fn peek_at(&self, offset: usize) -> &Tok {
+        let index = (self.at + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].tok
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.at].span
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.at].clone();
+        if self.at < self.tokens.len() - 1 {
+            self.at += 1;
+        }
+        token
+    }
+
+    fn is_sym(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Tok::Sym(s) if *s == symbol)
+    }
+
+    fn is_ident(&self, word: &str) -> bool {
+        matches!(self.peek(), Tok::Ident(w) if w == word)
+    }
+
+    fn error(&self, expected: &str) -> Diagnostic {
+        let found = match self.peek() {
+            Tok::Eof => String::from("end of input"),
+            Tok::Ident(word) => format!("'{}'", word),
+            Tok::Sym(symbol) => format!("'{}'", symbol),
+            Tok::Number | Tok::Str | Tok::Lifetime => String::from("a literal"),
+        };
+        Diagnostic::new("syntax", format!("expected {}, found {}", expected, found))
+            .primary(self.span(), &format!("expected {} here", expected))
+    }
+
+    fn expect_sym(&mut self, symbol: &str) -> Result<Span, Diagnostic> {
+        if self.is_sym(symbol) {
+            return Ok(self.advance().span);
+        }
+        let mut diagnostic = self.error(&format!("'{}'", symbol));
+        if symbol == ";" && self.at > 0 {
+            // A missing ; belongs at the end of the previous token, not on the next line
+            let previous = self.tokens[self.at - 1].span;
+            diagnostic.labels[0].span = Span { line: previous.line, start: previous.end, end: previous.end + 1 };
+            diagnostic.labels[0].text = String::from("add ';' here");
+        }
+        Err(diagnostic)
+    }
+
+    fn expect_ident(&mut self) -> Result<Name, Diagnostic> {
+        match self.peek().clone() {
+            Tok::Ident(text) => {
+                let span = self.advance().span;
+                Ok(Name { text, span, id: UNRESOLVED })
+            }
+            _ => Err(self.error("a name")),
+        }
+    }
+
+    fn take_pos(&mut self) -> usize {
+        self.next_pos += 1;
+        self.next_pos
+    }
+
+    // Top-level statements outside any fn are treated as the body of main
+    fn parse_program(mut self) -> Result<Vec<Function>, Diagnostic> {
+        let mut functions = Vec::new();
+        let mut loose = Vec::new();
+        while *self.peek() != Tok::Eof {
+            if self.is_ident("fn") {
+                functions.push(self.parse_function()?);
+            } else {
+                loose.push(self.parse_stmt()?);
+            }
+        }
+        if !loose.is_empty() {
+            if functions.iter().any(|f| f.name == "main") {
+                let span = Span { line: loose[0].line, start: 0, end: 1 };
+                return Err(Diagnostic::new("syntax", String::from("statements outside of fn main"))
+                    .primary(span, "move this into fn main, or remove fn main"));
+            }
+            functions.push(Function {
+                name: String::from("main"),
+                params: Vec::new(),
+                ret: None,
+                body: loose,
+                end: None,
+            });
+        }
+        Ok(functions)
+    }
+
+    fn parse_function(&mut self) -> Result<Function, Diagnostic> {
+        self.advance(); // fn
+        let name = self.expect_ident()?;
+        if self.is_sym("<") {
+            // generic parameters like <'a, 'b> or <T>: nothing to check
+            while !self.is_sym(">") && *self.peek() != Tok::Eof {
+                self.advance();
+            }
+            self.expect_sym(">")?;
+        }
+
+        self.expect_sym("(")?;
+        let mut params = Vec::new();
+        while !self.is_sym(")") {
+            let name = self.expect_ident()?;
+            self.expect_sym(":")?;
+            let (ty, _) = self.parse_type()?;
+            params.push(Param { name, ty });
+            if !self.is_sym(")") {
+                self.expect_sym(",")?;
+            }
+        }
+        self.expect_sym(")")?;
+
+        let ret = if self.is_sym("->") {
+            self.advance();
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let (body, end_pos, end) = self.parse_block()?;
+        Ok(Function { name: name.text, params, ret, body, end: Some((end_pos, end)) })
+    }
+
+    fn parse_type(&mut self) -> Result<(Ty, Span), Diagnostic> {
+        self.nested(Parser::type_inside)
+    }
+
+    fn type_inside(&mut self) -> Result<(Ty, Span), Diagnostic> {
+        let start = self.span();
+        if self.is_sym("&") {
+            self.advance();
+            let lifetime = *self.peek() == Tok::Lifetime;
+            if lifetime {
+                self.advance();
+            }
+            let mutable = self.is_ident("mut");
+            if mutable {
+                self.advance();
+            }
+            let (to, end) = self.parse_type()?;
+            // Shared references are Copy too, so *r copies them out
+            let copy = matches!(to, Ty::Copy | Ty::Ref { mutable: false, .. });
+            return Ok((Ty::Ref { mutable, lifetime, copy }, join(start, end)));
+        }
+
+        let name = self.expect_ident()?;
+        let mut end = name.span;
+        if self.is_sym("<") {
+            while !self.is_sym(">") && *self.peek() != Tok::Eof {
+                self.advance();
+            }
+            end = self.expect_sym(">")?;
+        }
+        let copy_types = [
+            "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+            "usize", "f32", "f64", "bool", "char", "str",
+        ];
+        let ty = if copy_types.contains(&name.text.as_str()) { Ty::Copy } else { Ty::Owned };
+        Ok((ty, join(start, end)))
+    }
+
+    // Returns the statements, the position of the closing brace, and its span
+    fn parse_block(&mut self) -> Result<(Vec<Stmt>, usize, Span), Diagnostic> {
+        self.nested(Parser::block_inside)
+    }
+
+    fn block_inside(&mut self) -> Result<(Vec<Stmt>, usize, Span), Diagnostic> {
+        self.expect_sym("{")?;
+        let mut body = Vec::new();
+        while !self.is_sym("}") {
+            if *self.peek() == Tok::Eof {
+                return Err(self.error("'}'"));
+            }
+            body.push(self.parse_stmt()?);
+        }
+        let end_pos = self.take_pos();
+        let end = self.expect_sym("}")?;
+        Ok((body, end_pos, end))
+    }
+
+    fn parse_stmt(&mut self) -> Result<Stmt, Diagnostic> {
+        let line = self.span().line;
+
+        if self.is_sym("{") {
+            let pos = self.take_pos();
+            let (body, end_pos, end) = self.parse_block()?;
+            return Ok(Stmt { kind: StmtKind::Block { body, end_pos, end }, pos, line });
+        }
+
+        let pos = self.take_pos();
+        let kind = if self.is_ident("let") {
+            self.advance();
+            let mutable = self.is_ident("mut");
+            if mutable {
+                self.advance();
+            }
+            let name = self.expect_ident()?;
+            if self.is_sym(":") {
+                self.advance();
+                self.parse_type()?; // annotations are allowed but not needed
+            }
+            let init = if self.is_sym("=") {
+                self.advance();
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            self.expect_sym(";")?;
+            StmtKind::Let { name, mutable, init }
+        } else if (self.is_ident("println") || self.is_ident("print"))
+            && *self.peek_at(1) == Tok::Sym("!")
+        {
+            self.advance();
+            self.advance();
+            self.expect_sym("(")?;
+            let args = self.parse_args(")")?;
+            self.expect_sym(";")?;
+            StmtKind::Print { args }
+        } else if let Some(kind) = self.try_parse_assign()? {
+            kind
+        } else {
+            if self.is_ident("return") {
+                self.advance();
+            }
+            let expr = self.parse_expr()?;
+            // A block's last expression may leave out the semicolon
+            let tail = self.is_sym("}");
+            if !tail {
+                self.expect_sym(";")?;
+            }
+            StmtKind::Expr { expr, tail }
+        };
+
+        Ok(Stmt { kind, pos, line })
+    }
+
+    // x = .., x += .., *r = .., *r += ..
+    fn try_parse_assign(&mut self) -> Result<Option<StmtKind>, Diagnostic> {
+        let deref = self.is_sym("*");
+        let offset = if deref { 1 } else { 0 };
+        let is_name = matches!(self.peek_at(offset), Tok::Ident(_));
+        let operator = match self.peek_at(offset + 1) {
+            Tok::Sym(op) if ["=", "+=", "-=", "*=", "/="].contains(op) => *op,
+            _ => return Ok(None),
+        };
+        if !is_name {
+            return Ok(None);
+        }
+
+        if deref {
+            self.advance();
+        }
+        let target = self.expect_ident()?;
+        self.advance(); // the operator
+        let value = self.parse_expr()?;
+        self.expect_sym(";")?;
+        Ok(Some(StmtKind::Assign { target, deref, compound: operator != "=", value }))
+    }
+
+    fn parse_args(&mut self, close: &str) -> Result<Vec<Expr>, Diagnostic> {
+        let mut args = Vec::new();
+        while !self.is_sym(close) {
+            args.push(self.parse_expr()?);
+            if !self.is_sym(close) {
+                self.expect_sym(",")?;
+            }
+        }
+        self.expect_sym(close)?;
+        Ok(args)
+    }
+
+    // No precedence needed: we only care which values an expression touches
+    fn parse_expr(&mut self) -> Result<Expr, Diagnostic> {
+        let mut operands = vec![self.parse_unary()?];
+        let operators = ["+", "-", "*", "/", "%", "==", "!=", "<", ">", "<=", ">=", "&&", "||"];
+        while let Tok::Sym(op) = self.peek() {
+            if !operators.contains(op) {
+                break;
+            }
+            self.advance();
+            operands.push(self.parse_unary()?);
+        }
+        if operands.len() == 1 {
+            return Ok(operands.remove(0));
+        }
+        Ok(Expr::Binary(operands))
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, Diagnostic> {
+        self.nested(Parser::unary_inside)
+    }
+
+    fn unary_inside(&mut self) -> Result<Expr, Diagnostic> {
+        let start = self.span();
+
+        if self.is_sym("&") {
+            self.advance();
+            let mutable = self.is_ident("mut");
+            if mutable {
+                self.advance();
+            }
+            if matches!(self.peek(), Tok::Str) {
+                return Ok(Expr::Literal(join(start, self.advance().span)));
+            }
+            let deref = self.is_sym("*");
+            if deref {
+                self.advance();
+            }
+            let name = self.expect_ident()?;
+            let mut end = name.span;
+            if self.is_sym("[") {
+                // &s[0..5] borrows part of s, which still borrows s
+                while !self.is_sym("]") && *self.peek() != Tok::Eof {
+                    self.advance();
+                }
+                end = self.expect_sym("]")?;
+            }
+            return Ok(Expr::Borrow { mutable, deref, name, span: join(start, end) });
+        }
+        if self.is_sym("*") {
+            self.advance();
+            return Ok(Expr::Deref(self.expect_ident()?));
+        }
+        if self.is_sym("-") || self.is_sym("!") {
+            self.advance();
+            return self.parse_unary();
+        }
+        if self.is_sym("(") {
+            self.advance();
+            let expr = self.parse_expr()?;
+            self.expect_sym(")")?;
+            return Ok(expr);
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, Diagnostic> {
+        let start = self.span();
+        match self.peek().clone() {
+            Tok::Number | Tok::Str => return Ok(Expr::Literal(self.advance().span)),
+            Tok::Ident(word) if word == "true" || word == "false" => {
+                return Ok(Expr::Literal(self.advance().span));
+            }
+            Tok::Ident(_) => {}
+            _ => return Err(self.error("an expression")),
+        }
+
+        let name = self.expect_ident()?;
+
+        // vec![..] and format!(..) build new owned values
+        if self.is_sym("!") {
+            self.advance();
+            let close = if self.is_sym("[") { "]" } else { ")" };
+            self.advance();
+            let args = self.parse_args(close)?;
+            return Ok(Expr::New { args, span: join(start, self.tokens[self.at - 1].span) });
+        }
+
+        // String::from(..), Vec::new(): also new owned values
+        if self.is_sym("::") {
+            self.advance();
+            self.expect_ident()?;
+            self.expect_sym("(")?;
+            let args = self.parse_args(")")?;
+            return Ok(Expr::New { args, span: join(start, self.tokens[self.at - 1].span) });
+        }
+
+        if self.is_sym("(") {
+            self.advance();
+            let args = self.parse_args(")")?;
+            let span = join(start, self.tokens[self.at - 1].span);
+            return Ok(Expr::Call { name, args, span });
+        }
+
+        if self.is_sym(".") {
+            self.advance();
+            let method = match self.peek().clone() {
+                Tok::Ident(method) => {
+                    self.advance();
+                    method
+                }
+                Tok::Number => {
+                    self.advance();
+                    String::new() // tuple field like x.0: just a read
+                }
+                _ => return Err(self.error("a method name")),
+            };
+            let args = if self.is_sym("(") {
+                self.advance();
+                self.parse_args(")")?
+            } else {
+                Vec::new()
+            };
+            // Chained calls (s.trim().len()) act on a temporary, so only the first one matters
+            while self.is_sym(".") {
+                self.advance();
+                self.expect_ident()?;
+                if self.is_sym("(") {
+                    self.advance();
+                    self.parse_args(")")?;
+                }
+            }
+            let span = join(start, self.tokens[self.at - 1].span);
+            return Ok(Expr::Method { receiver: name, method, args, span });
+        }
+
+        Ok(Expr::Var(name))
+    }
+}
+
+// RESOLVER
+// Gives every variable a unique id (so shadowed names don't get mixed up)
+// and records where each one is used. Those use positions are what make
+// the borrow checking non-lexical: a borrow is alive while its reference
+// still has a use coming up.
+
+#[derive(Default)]
+struct Bindings {
+    uses: Vec<Vec<(usize, Span)>>, // (statement position, where) for each id
+    defs: Vec<Vec<usize>>,         // positions where the variable is reassigned
+}
+
+struct Resolver {
+    scopes: Vec<Vec<String>>,              // the names declared in each open block
+    visible: HashMap<String, Vec<usize>>, // name -> ids in scope, innermost (or latest shadow) last
+    bindings: Bindings,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Resolver {
+    fn declare(&mut self, name: &mut Name) {
+        name.id = self.bindings.uses.len();
+        self.bindings.uses.push(Vec::new());
+        self.bindings.defs.push(Vec::new());
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(name.text.clone());
+            self.visible.entry(name.text.clone()).or_default().push(name.id);
+        }
+    }
+
+    // A block's names go out of view when it ends
+    fn close_scope(&mut self) {
+        for text in self.scopes.pop().unwrap_or_default() {
+            if let Some(ids) = self.visible.get_mut(&text) {
+                ids.pop();
+            }
+        }
+    }
+
+    fn lookup(&mut self, name: &mut Name) {
+        let found = self.visible.get(&name.text).and_then(|ids| ids.last());
+        match found {
+            Some(&id) => name.id = id,
+            None => self.diagnostics.push(
+                Diagnostic::new("E0425", format!("cannot find value `{}` in this scope", name.text))
+                    .primary(name.span, "not found in this scope")
+                    .note("variables must be declared with `let` before they are used"),
+            ),
+        }
+    }
+
+    fn use_name(&mut self, name: &mut Name, pos: usize) {
+        self.lookup(name);
+        if name.id != UNRESOLVED {
+            self.bindings.uses[name.id].push((pos, name.span));
+        }
+    }
+
+    fn function(&mut self, function: &mut Function) {
+        self.scopes.push(Vec::new());
+        for param in &mut function.params {
+            self.declare(&mut param.name);
+        }
+        for stmt in &mut function.body {
+            self.stmt(stmt);
+        }
+        self.close_scope();
+    }
+
+    fn stmt(&mut self, stmt: &mut Stmt) {
+        let pos = stmt.pos;
+        match &mut stmt.kind {
+            StmtKind::Let { name, init, .. } => {
+                // Resolve the value first: in `let x = x + 1` the right side is the OLD x
+                if let Some(init) = init {
+                    self.expr(init, pos);
+                }
+                self.declare(name);
+            }
+            StmtKind::Assign { target, deref, value, .. } => {
+                self.expr(value, pos);
+                if *deref {
+                    self.use_name(target, pos); // *r = .. uses r
+                } else {
+                    self.lookup(target);
+                    if target.id != UNRESOLVED {
+                        self.bindings.defs[target.id].push(pos);
+                    }
+                }
+            }
+            StmtKind::Expr { expr, .. } => self.expr(expr, pos),
+            StmtKind::Print { args } => {
+                for arg in args {
+                    self.expr(arg, pos);
+                }
+            }
+            StmtKind::Block { body, .. } => {
+                self.scopes.push(Vec::new());
+                for stmt in body {
+                    self.stmt(stmt);
+                }
+                self.close_scope();
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &mut Expr, pos: usize) {
+        match expr {
+            Expr::Literal(_) => {}
+            Expr::Var(name) | Expr::Deref(name) | Expr::Borrow { name, .. } => self.use_name(name, pos),
+            Expr::New { args, .. } | Expr::Call { args, .. } => {
+                for arg in args {
+                    self.expr(arg, pos);
+                }
+            }
+            Expr::Method { receiver, args, .. } => {
+                self.use_name(receiver, pos);
+                for arg in args {
+                    self.expr(arg, pos);
+                }
+            }
+            Expr::Binary(operands) => {
+                for operand in operands {
+                    self.expr(operand, pos);
+                }
+            }
+        }
+    }
+}
+
+// CHECKER
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Copy,
+    Owned,
+    Ref { mutable: bool, copy: bool }, // copy: *r can be copied out (an i32, not a String)
+}
+
+#[derive(Clone, PartialEq)]
+enum State {
+    Uninit,
+    Valid,
+    Moved { span: Span, to: String },
+}
+
+struct Var {
+    name: String,
+    mutable: bool,
+    kind: Kind,
+    state: State,
+    decl: Span,
+}
+
+// Who keeps a borrow alive: a variable, or a temporary that only lives
+// during one statement (like the &s in calculate_length(&s))
+#[derive(Clone, Copy, PartialEq)]
+enum Holder {
+    Var(usize),
+    Temp(usize),
+}
+
+struct Loan {
+    target: usize,
+    mutable: bool,
+    span: Span,
+    holders: Vec<Holder>,
+    ended: bool,    // already reported as ended in the timeline
+    reborrow: bool, // &*r or &mut *r: a borrow of what r points at, through r
+}
+
+// What evaluating an expression produces
+enum Value {
+    Copy,
+    Owned,
+    Ref { mutable: bool, copy: bool, loans: Vec<usize> },
+}
+
+struct Checker<'a> {
+    functions: &'a [Function],
+    bindings: &'a Bindings,
+    vars: HashMap<usize, Var>,
+    loans: Vec<Loan>,
+    live: Vec<usize>, // loans that may still be used; see end_finished_loans
+    scopes: Vec<Vec<usize>>,
+    report: &'a mut Report,
+    pos: usize,
+}
+
+// Methods that need &mut self / take self; everything else only reads
+const MUTATING_METHODS: [&str; 12] = [
+    "push", "push_str", "insert", "remove", "clear", "pop", "truncate", "sort", "reverse",
+    "extend", "retain", "shrink_to_fit",
+];
+const CONSUMING_METHODS: [&str; 3] = ["into_iter", "into_bytes", "unwrap"];
+const OWNED_RESULT_METHODS: [&str; 5] = ["clone", "to_string", "to_uppercase", "to_lowercase", "to_owned"];
+const REF_RESULT_METHODS: [&str; 6] = ["as_str", "trim", "iter", "first", "last", "get"];
+
+impl<'a> Checker<'a> {
+    fn error(&mut self, diagnostic: Diagnostic) {
+        self.report.diagnostics.push(diagnostic);
+    }
+
+    fn event(&mut self, line: usize, text: String) {
+        self.report.events.push((line, text));
+    }
+
+    fn var(&self, name: &Name) -> Option<&Var> {
+        self.vars.get(&name.id)
+    }
+
+    // Is this holder going to be used at or after position `at`?
+    fn holder_live(&self, holder: Holder, at: usize) -> bool {
+        self.holder_next_use(holder, at).is_some() || holder == Holder::Temp(at)
+    }
+
+    // The next use of a reference variable (before it gets reassigned).
+    // Uses and reassignments are recorded in position order, so both can be
+    // binary searched, and only the first use from `at` on matters.
+    fn holder_next_use(&self, holder: Holder, at: usize) -> Option<Span> {
+        let id = match holder {
+            Holder::Var(id) => id,
+            Holder::Temp(_) => return None,
+        };
+        let uses = &self.bindings.uses[id];
+        let &(use_pos, span) = uses.get(uses.partition_point(|&(p, _)| p < at))?;
+        let defs = &self.bindings.defs[id];
+        let redefined = defs.get(defs.partition_point(|&d| d <= at)).is_some_and(|&d| d <= use_pos);
+        if redefined { None } else { Some(span) }
+    }
+
+    fn loan_live(&self, loan: &Loan, at: usize) -> bool {
+        loan.holders.iter().any(|&h| self.holder_live(h, at))
+    }
+
+    fn later_use(&self, loan: &Loan, at: usize) -> Option<Span> {
+        loan.holders.iter().find_map(|&h| self.holder_next_use(h, at))
+    }
+
+    fn live_loans_on(&self, target: usize) -> Vec<usize> {
+        self.live
+            .iter()
+            .copied()
+            .filter(|&i| self.loans[i].target == target && self.loan_live(&self.loans[i], self.pos))
+            .collect()
+    }
+
+    // Add the "later used here" label, unless the use is in this same statement
+    fn with_later_use(&self, diagnostic: Diagnostic, loan: usize, text: &str) -> Diagnostic {
+        match self.later_use(&self.loans[loan], self.pos + 1) {
+            Some(span) => diagnostic.secondary(span, text),
+            None => diagnostic,
+        }
+    }
+
+    // Returns false (after reporting) if the variable can't be touched at all
+    fn check_usable(&mut self, name: &Name, verb: &str) -> bool {
+        let var = match self.var(name) {
+            Some(var) => var,
+            None => return false, // unknown name, already reported by the resolver
+        };
+        match var.state.clone() {
+            State::Valid => true,
+            State::Uninit => {
+                let diagnostic = Diagnostic::new("E0381", format!("used binding `{}` isn't initialized", name.text))
+                    .secondary(var.decl, "binding declared here but left uninitialized")
+                    .primary(name.span, &format!("`{}` used here but it isn't initialized", name.text))
+                    .note("a variable declared with `let x;` must be assigned before it is used");
+                self.error(diagnostic);
+                false
+            }
+            State::Moved { span, to } => {
+                let help = match var.kind {
+                    Kind::Ref { .. } => format!(
+                        "`{}` is a `&mut` reference, and there can only be one, so it was moved into {}, not copied; reborrow it with &mut *{} to keep using `{}`",
+                        name.text, to, name.text, name.text
+                    ),
+                    _ => format!(
+                        "`{}` was moved into {}, so `{}` is no longer valid; clone() it for an independent copy, or borrow it with &{} instead of moving it",
+                        name.text, to, name.text, name.text
+                    ),
+                };
+                let diagnostic = Diagnostic::new("E0382", format!("{} of moved value: `{}`", verb, name.text))
+                    .secondary(span, &format!("value moved into {} here", to))
+                    .primary(name.span, &format!("value {} here after move", past_tense(verb)))
+                    .note(OWNERSHIP_RULES)
+                    .note(&help);
+                self.error(diagnostic);
+                false
+            }
+        }
+    }
+
+    // Creates a borrow of `name`, reporting any conflict with live borrows
+    fn borrow(&mut self, name: &Name, mutable: bool, span: Span) -> Option<usize> {
+        if !self.check_usable(name, "borrow") {
+            return None;
+        }
+        let var = &self.vars[&name.id];
+
+        if mutable && !var.mutable {
+            let diagnostic = Diagnostic::new(
+                "E0596",
+                format!("cannot borrow `{}` as mutable, as it is not declared as mutable", name.text),
+            )
+            .secondary(var.decl, &format!("help: consider changing this to be mutable: `mut {}`", name.text))
+            .primary(span, "cannot borrow as mutable")
+            .note(MUT_RULE);
+            self.error(diagnostic);
+            return None;
+        }
+
+        if self.conflicts(name.id, &name.text, mutable, span) {
+            return None;
+        }
+        Some(self.new_loan(name.id, mutable, span, false))
+    }
+
+    // Reports the first live borrow of `target` that a new (mutable) borrow
+    // would break the rules with. `shown` is how to name it: "s", or "*r"
+    // for what a reference points at.
+    fn conflicts(&mut self, target: usize, shown: &str, mutable: bool, span: Span) -> bool {
+        for loan in self.live_loans_on(target) {
+            let old = &self.loans[loan];
+            if !mutable && !old.mutable {
+                continue; // many immutable references: fine
+            }
+            let (code, message, old_text, new_text, later_text) = match (old.mutable, mutable) {
+                (true, true) => (
+                    "E0499",
+                    format!("cannot borrow `{}` as mutable more than once at a time", shown),
+                    "first mutable borrow occurs here",
+                    "second mutable borrow occurs here",
+                    "first borrow later used here",
+                ),
+                (false, _) => (
+                    "E0502",
+                    format!("cannot borrow `{}` as mutable because it is also borrowed as immutable", shown),
+                    "immutable borrow occurs here",
+                    "mutable borrow occurs here",
+                    "immutable borrow later used here",
+                ),
+                (true, false) => (
+                    "E0502",
+                    format!("cannot borrow `{}` as immutable because it is also borrowed as mutable", shown),
+                    "mutable borrow occurs here",
+                    "immutable borrow occurs here",
+                    "mutable borrow later used here",
+                ),
+            };
+            let diagnostic = Diagnostic::new(code, message)
+                .secondary(old.span, old_text)
+                .primary(span, new_text);
+            let diagnostic = self
+                .with_later_use(diagnostic, loan, later_text)
+                .note(BORROW_RULE_1)
+                .note("a borrow lasts until the reference's last use; use the first reference before creating the second");
+            self.error(diagnostic);
+            return true;
+        }
+        false
+    }
+
+    // A borrow held by the current statement until something binds it
+    fn new_loan(&mut self, target: usize, mutable: bool, span: Span, reborrow: bool) -> usize {
+        self.loans.push(Loan {
+            target,
+            mutable,
+            span,
+            holders: vec![Holder::Temp(self.pos)],
+            ended: false,
+            reborrow,
+        });
+        self.live.push(self.loans.len() - 1);
+        self.loans.len() - 1
+    }
+
+    // Using a reference goes through it to what it points at, so it
+    // conflicts with a live reborrow of it: r.push_str(..) while r2 = &mut *r
+    fn use_through(&mut self, name: &Name, mutable: bool, span: Span) -> bool {
+        !self.conflicts(name.id, &format!("*{}", name.text), mutable, span)
+    }
+
+    // Moves the value out of `name` (into `to`), or copies it for Copy types
+    fn take(&mut self, name: &Name, to: &str) -> Option<Value> {
+        if !self.check_usable(name, "use") {
+            return None;
+        }
+        let kind = self.vars[&name.id].kind;
+        match kind {
+            // A &mut reference must be the only way to its value, so it is
+            // moved like a String rather than copied
+            Kind::Owned | Kind::Ref { mutable: true, .. } => {
+                if let Some(&loan) = self.live_loans_on(name.id).first() {
+                    let diagnostic = Diagnostic::new("E0505", format!("cannot move out of `{}` because it is borrowed", name.text))
+                        .secondary(self.loans[loan].span, &format!("borrow of `{}` occurs here", name.text))
+                        .primary(name.span, &format!("move out of `{}` occurs here", name.text));
+                    let diagnostic = self
+                        .with_later_use(diagnostic, loan, "borrow later used here")
+                        .note(BORROW_RULE_2)
+                        .note("after the move the reference would point at a value that has a new owner");
+                    self.error(diagnostic);
+                    return None;
+                }
+                let loans = self.loans_held_by(name.id);
+                self.vars.get_mut(&name.id).unwrap().state = State::Moved { span: name.span, to: to.to_string() };
+                self.event(name.span.line, format!("`{}` moved into {} - `{}` is no longer valid", name.text, to, name.text));
+                match kind {
+                    Kind::Ref { copy, .. } => Some(Value::Ref { mutable: true, copy, loans }),
+                    _ => Some(Value::Owned),
+                }
+            }
+            Kind::Copy => {
+                // Reading a Copy value still conflicts with a live mutable borrow
+                let live = self.live_loans_on(name.id);
+                if let Some(&loan) = live.iter().find(|&&l| self.loans[l].mutable) {
+                    let diagnostic = Diagnostic::new("E0503", format!("cannot use `{}` because it was mutably borrowed", name.text))
+                        .secondary(self.loans[loan].span, &format!("`{}` is borrowed here", name.text))
+                        .primary(name.span, &format!("use of borrowed `{}`", name.text));
+                    let diagnostic = self.with_later_use(diagnostic, loan, "borrow later used here").note(BORROW_RULE_1);
+                    self.error(diagnostic);
+                    return None;
+                }
+                Some(Value::Copy)
+            }
+            // Copying a shared reference shares its borrows with the new holder
+            Kind::Ref { mutable: false, copy } => {
+                Some(Value::Ref { mutable: false, copy, loans: self.loans_held_by(name.id) })
+            }
+        }
+    }
+
+    // Only live loans can be held: a reference being used keeps its loans live
+    fn loans_held_by(&self, id: usize) -> Vec<usize> {
+        self.live
+            .iter()
+            .copied()
+            .filter(|&i| self.loans[i].holders.contains(&Holder::Var(id)))
+            .collect()
+    }
+
+    fn expr(&mut self, expr: &Expr, to: &str) -> Option<Value> {
+        match expr {
+            Expr::Literal(_) => Some(Value::Copy),
+            Expr::New { args, .. } => {
+                for arg in args {
+                    self.read(arg);
+                }
+                Some(Value::Owned)
+            }
+            Expr::Var(name) => self.take(name, to),
+            Expr::Borrow { mutable, deref: false, name, span } => {
+                let loan = self.borrow(name, *mutable, *span)?;
+                Some(Value::Ref { mutable: *mutable, copy: self.copy_kind(name), loans: vec![loan] })
+            }
+            Expr::Borrow { mutable, deref: true, name, span } => self.reborrow(name, *mutable, *span),
+            Expr::Deref(name) => self.deref(name, true),
+            Expr::Call { name, args, span } => self.call(name, args, *span),
+            Expr::Method { receiver, method, args, span } => self.method(receiver, method, args, *span),
+            Expr::Binary(operands) => {
+                // String + &str consumes the left String, so evaluate normally;
+                // but *a == *b only compares, it doesn't move out of a or b
+                let values: Vec<Option<Value>> = operands
+                    .iter()
+                    .map(|o| match o {
+                        Expr::Deref(name) => self.deref(name, false),
+                        _ => self.expr(o, "the `+` expression"),
+                    })
+                    .collect();
+                let mut values = values.into_iter().collect::<Option<Vec<Value>>>()?.into_iter();
+                match values.next() {
+                    Some(Value::Owned) => Some(Value::Owned),
+                    _ => Some(Value::Copy),
+                }
+            }
+        }
+    }
+
+    // println!("{}", x) and format! only borrow their arguments
+    fn read(&mut self, expr: &Expr) -> Option<Value> {
+        match expr {
+            Expr::Var(name) => match self.var(name).map(|v| v.kind) {
+                Some(Kind::Ref { mutable, copy }) => {
+                    if !self.check_usable(name, "use") || !self.use_through(name, false, name.span) {
+                        return None;
+                    }
+                    Some(Value::Ref { mutable, copy, loans: self.loans_held_by(name.id) })
+                }
+                _ => {
+                    let loan = self.borrow(name, false, name.span)?;
+                    Some(Value::Ref { mutable: false, copy: self.copy_kind(name), loans: vec![loan] })
+                }
+            },
+            Expr::Deref(name) => self.deref(name, false),
+            _ => self.expr(expr, "the macro"),
+        }
+    }
+
+    // Is a reference to this variable's value Copy-able through *r?
+    fn copy_kind(&self, name: &Name) -> bool {
+        matches!(self.var(name).map(|v| v.kind), Some(Kind::Copy | Kind::Ref { mutable: false, .. }))
+    }
+
+    // *r and &*r need r to be a reference; returns whether it is &mut
+    fn ref_mutability(&mut self, name: &Name) -> Option<bool> {
+        match self.var(name)?.kind {
+            Kind::Ref { mutable, .. } => Some(mutable),
+            _ => {
+                self.error(
+                    Diagnostic::new("E0614", format!("`{}` is not a reference, so it cannot be dereferenced", name.text))
+                        .primary(name.span, "can't use * here")
+                        .note("dereference with * to access/modify values through references (Lesson 3)"),
+                );
+                None
+            }
+        }
+    }
+
+    // *r reads what r points at. Used as a value (let t = *r;) that is a
+    // move out of the borrowed value, which only Copy values allow.
+    fn deref(&mut self, name: &Name, moves: bool) -> Option<Value> {
+        let ref_mutable = self.ref_mutability(name)?;
+        if !self.check_usable(name, "use") || !self.use_through(name, false, name.span) {
+            return None;
+        }
+        if moves && !matches!(self.vars[&name.id].kind, Kind::Ref { copy: true, .. }) {
+            let behind = if ref_mutable { "a mutable" } else { "a shared" };
+            self.error(
+                Diagnostic::new("E0507", format!("cannot move out of `*{}` which is behind {} reference", name.text, behind))
+                    .primary(name.span, &format!("move occurs because `*{}` does not implement the `Copy` trait", name.text))
+                    .note(REF_RULE)
+                    .note(&format!("a reference only borrows the value; use {}.clone() for a copy of your own", name.text)),
+            );
+            return None;
+        }
+        Some(Value::Copy)
+    }
+
+    // &*r and &mut *r borrow again what r points at, so the new reference
+    // holds r's borrows, plus a borrow of r itself: while the new reference
+    // is used, r can't be used to change (or, for &mut *r, read) the value.
+    // &mut *r needs r itself to be a &mut reference.
+    fn reborrow(&mut self, name: &Name, mutable: bool, span: Span) -> Option<Value> {
+        let ref_mutable = self.ref_mutability(name)?;
+        if !self.check_usable(name, "use") {
+            return None;
+        }
+        if mutable && !ref_mutable {
+            self.error(
+                Diagnostic::new(
+                    "E0596",
+                    format!("cannot borrow `*{}` as mutable, as it is behind a `&` reference", name.text),
+                )
+                .primary(span, &format!("`{}` is a `&` reference, so the data it refers to cannot be borrowed as mutable", name.text))
+                .note(REF_RULE),
+            );
+            return None;
+        }
+        if !self.use_through(name, mutable, span) {
+            return None;
+        }
+        let copy = matches!(self.vars[&name.id].kind, Kind::Ref { copy: true, .. });
+        let mut loans = self.loans_held_by(name.id);
+        loans.push(self.new_loan(name.id, mutable, span, true));
+        Some(Value::Ref { mutable, copy, loans })
+    }
+
+    fn call(&mut self, name: &Name, args: &[Expr], span: Span) -> Option<Value> {
+        let function = match self.functions.iter().find(|f| f.name == name.text) {
+            Some(function) => function,
+            // The standard library's drop(x) takes ownership of x: x is
+            // moved into it and dropped there, so it can't be used afterwards
+            None if name.text == "drop" => {
+                if args.len() != 1 {
+                    self.error(wrong_arg_count(1, args.len(), span));
+                    return None;
+                }
+                self.expr(&args[0], "`drop()`")?;
+                return Some(Value::Copy);
+            }
+            None => {
+                self.error(
+                    Diagnostic::new("E0425", format!("cannot find function `{}` in this scope", name.text))
+                        .primary(name.span, "not found in this scope"),
+                );
+                return None;
+            }
+        };
+        if function.params.len() != args.len() {
+            self.error(wrong_arg_count(function.params.len(), args.len(), span));
+            return None;
+        }
+
+        let to = format!("function `{}`", name.text);
+        let mut returned_loans = Vec::new();
+        let mut ok = true;
+        for (param, arg) in function.params.iter().zip(args) {
+            if let Some(problem) = mismatch(param.ty, arg) {
+                self.error(
+                    Diagnostic::new("E0308", String::from("mismatched types"))
+                        .primary(arg.span(), &problem)
+                        .note(REF_RULE),
+                );
+                ok = false;
+                continue;
+            }
+            // A &mut reference passed where a reference is expected is
+            // reborrowed (as if written &mut *r), so r can be used again after
+            let value = match (param.ty, arg) {
+                (Ty::Ref { mutable, .. }, Expr::Var(name))
+                    if matches!(self.var(name).map(|v| v.kind), Some(Kind::Ref { mutable: true, .. })) =>
+                {
+                    self.reborrow(name, mutable, name.span)
+                }
+                _ => self.expr(arg, &to),
+            };
+            match value {
+                Some(Value::Ref { loans, .. }) => {
+                    // Which arguments can the returned reference borrow from?
+                    // With 'a annotations we assume all of them; otherwise elision
+                    // only allows a single reference parameter (checked in function())
+                    if matches!(param.ty, Ty::Ref { .. }) {
+                        returned_loans.extend(loans);
+                    }
+                }
+                Some(_) => {}
+                None => ok = false,
+            }
+        }
+        if !ok {
+            return None;
+        }
+
+        match function.ret {
+            None | Some((Ty::Copy, _)) => Some(Value::Copy),
+            Some((Ty::Owned, _)) => Some(Value::Owned),
+            Some((Ty::Ref { mutable, copy, .. }, _)) => Some(Value::Ref { mutable, copy, loans: returned_loans }),
+        }
+    }
+
+    fn method(&mut self, receiver: &Name, method: &str, args: &[Expr], span: Span) -> Option<Value> {
+        let kind = self.var(receiver)?.kind;
+        let mutating = MUTATING_METHODS.contains(&method);
+
+        // Two-phase borrow: v.push(v.len()) first reserves &mut v, then runs
+        // the arguments (which may still read v), and only then activates
+        // the &mut borrow. So only what an argument leaves borrowed, like
+        // the &s in s.push_str(&s), conflicts with it.
+        if mutating && !matches!(kind, Kind::Ref { .. }) {
+            let reservation = self.borrow(receiver, true, span)?;
+            self.loans[reservation].mutable = false; // reserved: shared until activated
+            self.method_args(args);
+            self.loans[reservation].holders.clear();
+            let loan = self.borrow(receiver, true, span)?;
+            return Some(method_result(method, vec![loan]));
+        }
+
+        self.method_args(args);
+        if CONSUMING_METHODS.contains(&method) {
+            self.take(receiver, &format!("`{}()`", method))?;
+            return Some(Value::Owned);
+        }
+
+        let loans = match kind {
+            Kind::Ref { mutable, .. } => {
+                if !self.check_usable(receiver, "use") {
+                    return None;
+                }
+                if mutating && !mutable {
+                    self.error(
+                        Diagnostic::new(
+                            "E0596",
+                            format!("cannot borrow `*{}` as mutable, as it is behind a `&` reference", receiver.text),
+                        )
+                        .primary(span, &format!("`{}` is a `&` reference, so the data it refers to cannot be changed", receiver.text))
+                        .note(REF_RULE),
+                    );
+                    return None;
+                }
+                if !self.use_through(receiver, mutating, span) {
+                    return None;
+                }
+                self.loans_held_by(receiver.id)
+            }
+            _ => vec![self.borrow(receiver, false, span)?],
+        };
+        Some(method_result(method, loans))
+    }
+
+    // A method's arguments, evaluated before the call. The temporary borrows
+    // they make (the &v inside v.len()) end once each value is computed; a
+    // reference an argument passes on keeps its borrow.
+    fn method_args(&mut self, args: &[Expr]) {
+        let first_new = self.loans.len();
+        let mut passed = Vec::new();
+        for arg in args {
+            if let Some(Value::Ref { loans, .. }) = self.read(arg) {
+                passed.extend(loans);
+            }
+        }
+        for i in first_new..self.loans.len() {
+            if !passed.contains(&i) {
+                self.loans[i].holders.retain(|h| !matches!(h, Holder::Temp(_)));
+            }
+        }
+    }
+
+    fn declare(&mut self, name: &Name, mutable: bool, kind: Kind, state: State) {
+        self.vars.insert(
+            name.id,
+            Var { name: name.text.clone(), mutable, kind, state, decl: name.span },
+        );
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(name.id);
+        }
+    }
+
+    // Bind a value to a variable: references start holding their borrows
+    fn bind(&mut self, id: usize, value: &Value) -> Kind {
+        match value {
+            Value::Copy => Kind::Copy,
+            Value::Owned => Kind::Owned,
+            Value::Ref { mutable, copy, loans } => {
+                for &loan in loans {
+                    self.loans[loan].holders.push(Holder::Var(id));
+                }
+                Kind::Ref { mutable: *mutable, copy: *copy }
+            }
+        }
+    }
+
+    fn describe(&self, value: &Value) -> String {
+        match value {
+            Value::Ref { mutable, loans, .. } => {
+                let targets: Vec<String> = loans
+                    .iter()
+                    .filter(|&&l| !self.loans[l].reborrow) // name what is borrowed, not the reference to it
+                    .map(|&l| format!("`{}`", self.vars[&self.loans[l].target].name))
+                    .collect();
+                let kind = if *mutable { "mutably (&mut)" } else { "immutably (&)" };
+                if targets.is_empty() {
+                    format!("a reference {}", kind)
+                } else {
+                    format!("borrows {} {}", targets.join(" and "), kind)
+                }
+            }
+            Value::Copy => String::from("gets a Copy value"),
+            Value::Owned => String::from("owns a value"),
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        if let Some((Ty::Ref { lifetime: false, .. }, span)) = function.ret {
+            let ref_params = function.params.iter().filter(|p| matches!(p.ty, Ty::Ref { .. })).count();
+            if ref_params != 1 {
+                let diagnostic = Diagnostic::new("E0106", String::from("missing lifetime specifier"))
+                    .primary(span, "expected named lifetime parameter")
+                    .note(LIFETIME_RULE)
+                    .note(if ref_params == 0 {
+                        "this function returns a reference, but there is no reference parameter it could be borrowed from"
+                    } else {
+                        "is the output tied to one parameter or another? annotate it: fn longest<'a>(x: &'a str, y: &'a str) -> &'a str"
+                    });
+                self.error(diagnostic);
+            }
+        }
+
+        self.scopes.push(Vec::new());
+        for param in &function.params {
+            let kind = match param.ty {
+                Ty::Copy => Kind::Copy,
+                Ty::Owned => Kind::Owned,
+                Ty::Ref { mutable, copy, .. } => Kind::Ref { mutable, copy },
+            };
+            self.declare(&param.name, false, kind, State::Valid);
+        }
+        self.block(&function.body, function.end, function);
+    }
+
+    // Runs a block's statements, then drops its variables
+    fn block(&mut self, body: &[Stmt], end: Option<(usize, Span)>, function: &Function) {
+        for stmt in body {
+            self.pos = stmt.pos;
+            self.stmt(stmt, function);
+            self.end_finished_loans(stmt.line);
+        }
+
+        let scope = self.scopes.pop().unwrap_or_default();
+        if let Some((end_pos, end_span)) = end {
+            self.pos = end_pos;
+            for &id in scope.iter().rev() {
+                self.drop_var(id, end_span);
+            }
+        }
+    }
+
+    // When a variable goes out of scope while a reference to it is still
+    // going to be used, that reference would dangle
+    fn drop_var(&mut self, id: usize, end: Span) {
+        let name = self.vars[&id].name.clone();
+        // What a reborrow points at outlives the reference it went through
+        let live: Vec<usize> = self.live_loans_on(id).into_iter().filter(|&l| !self.loans[l].reborrow).collect();
+        if let Some(&loan) = live.first() {
+            let diagnostic = Diagnostic::new("E0597", format!("`{}` does not live long enough", name))
+                .primary(self.loans[loan].span, "borrowed value does not live long enough")
+                .secondary(end, &format!("`{}` dropped here while still borrowed", name));
+            let diagnostic = self
+                .with_later_use(diagnostic, loan, "borrow later used here")
+                .note(BORROW_RULE_2)
+                .note(&format!("`{}` dies at the end of its block, but the reference to it is used after that", name));
+            self.error(diagnostic);
+        }
+        if self.vars[&id].kind == Kind::Owned && self.vars[&id].state == State::Valid {
+            self.event(end.line, format!("`{}` goes out of scope and is dropped", name));
+        }
+    }
+
+    // Timeline: a borrow ends right after the last use of its reference (NLL).
+    // Positions only go forward, so a borrow that has ended stays ended and
+    // is taken out of `live`: long programs don't keep checking old borrows.
+    fn end_finished_loans(&mut self, line: usize) {
+        let live = std::mem::take(&mut self.live);
+        for i in live {
+            if self.loan_live(&self.loans[i], self.pos + 1) {
+                self.live.push(i);
+                continue;
+            }
+            let only_temps = self.loans[i].holders.iter().all(|h| matches!(h, Holder::Temp(_)));
+            if self.loans[i].ended || only_temps || self.loans[i].reborrow {
+                continue;
+            }
+            self.loans[i].ended = true;
+            let loan = &self.loans[i];
+            let text = format!(
+                "borrow of `{}` from line {} ends (its reference is not used after this)",
+                self.vars[&loan.target].name, loan.span.line
+            );
+            self.event(line, text);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt, function: &Function) {
+        match &stmt.kind {
+            StmtKind::Let { name, mutable, init } => {
+                let to = format!("`{}`", name.text);
+                match init {
+                    None => self.declare(name, *mutable, Kind::Copy, State::Uninit),
+                    Some(init) => {
+                        let value = self.expr(init, &to);
+                        let kind = value.as_ref().map(|v| self.bind(name.id, v)).unwrap_or(Kind::Copy);
+                        if let Some(value) = &value {
+                            let text = format!("`{}` {}", name.text, self.describe(value));
+                            self.event(stmt.line, text);
+                        }
+                        self.declare(name, *mutable, kind, State::Valid);
+                    }
+                }
+            }
+            StmtKind::Assign { target, deref: true, value, .. } => {
+                self.expr(value, &format!("`*{}`", target.text));
+                let kind = match self.var(target) {
+                    Some(var) => var.kind,
+                    None => return,
+                };
+                match kind {
+                    Kind::Ref { mutable: true, .. } => {
+                        self.use_through(target, true, target.span);
+                    }
+                    Kind::Ref { mutable: false, .. } => self.error(
+                        Diagnostic::new("E0594", format!("cannot assign to `*{}`, which is behind a `&` reference", target.text))
+                            .primary(target.span, &format!("`{}` is a `&` reference, so the data it refers to cannot be written", target.text))
+                            .note(REF_RULE),
+                    ),
+                    _ => self.error(
+                        Diagnostic::new("E0614", format!("`{}` is not a reference, so it cannot be dereferenced", target.text))
+                            .primary(target.span, "can't use * here"),
+                    ),
+                }
+            }
+            StmtKind::Assign { target, compound, value, .. } => {
+                let value = self.expr(value, &format!("`{}`", target.text));
+                self.assign(target, *compound, value, stmt.line);
+            }
+            StmtKind::Expr { expr, tail } => {
+                let value = self.expr(expr, "the caller");
+                if *tail {
+                    self.check_return(function, expr, value);
+                }
+            }
+            StmtKind::Print { args } => {
+                for arg in args {
+                    self.read(arg);
+                }
+            }
+            StmtKind::Block { body, end_pos, end } => {
+                self.scopes.push(Vec::new());
+                self.block(body, Some((*end_pos, *end)), function);
+            }
+        }
+    }
+
+    fn assign(&mut self, target: &Name, compound: bool, value: Option<Value>, line: usize) {
+        let var = match self.var(target) {
+            Some(var) => var,
+            None => return,
+        };
+        let first_assignment = var.state == State::Uninit;
+
+        if compound && !self.check_usable(target, "use") {
+            return;
+        }
+        let var = &self.vars[&target.id];
+        if !var.mutable && !first_assignment {
+            let diagnostic = Diagnostic::new("E0384", format!("cannot assign twice to immutable variable `{}`", target.text))
+                .secondary(var.decl, &format!("first assignment; help: consider making this binding mutable: `mut {}`", target.text))
+                .primary(target.span, "cannot assign twice to immutable variable")
+                .note(MUT_RULE);
+            self.error(diagnostic);
+            return;
+        }
+        let live = self.live_loans_on(target.id);
+        if let Some(&loan) = live.iter().find(|&&l| !self.loans[l].reborrow) {
+            let diagnostic = Diagnostic::new("E0506", format!("cannot assign to `{}` because it is borrowed", target.text))
+                .secondary(self.loans[loan].span, &format!("`{}` is borrowed here", target.text))
+                .primary(target.span, &format!("`{}` is assigned to here but it was already borrowed", target.text));
+            let diagnostic = self.with_later_use(diagnostic, loan, "borrow later used here").note(BORROW_RULE_1);
+            self.error(diagnostic);
+            return;
+        }
+
+        let value = match value {
+            Some(value) => value,
+            None => return,
+        };
+        if compound {
+            return; // x += 1 changes the value, not what kind of value it is
+        }
+        // The old borrows this variable held are released
+        for &i in &self.live {
+            self.loans[i].holders.retain(|&h| h != Holder::Var(target.id));
+        }
+        let kind = self.bind(target.id, &value);
+        let text = format!("`{}` {} (assigned)", target.text, self.describe(&value));
+        let var = self.vars.get_mut(&target.id).unwrap();
+        var.kind = kind;
+        var.state = State::Valid;
+        self.event(line, text);
+    }
+
+    // A function can't return a reference to something that dies when it returns
+    fn check_return(&mut self, function: &Function, expr: &Expr, value: Option<Value>) {
+        let (Some(Value::Ref { loans, .. }), Some((Ty::Ref { .. }, _))) = (value, function.ret) else {
+            return;
+        };
+        // A reborrow of a reference parameter (&mut *r) may be returned
+        if let Some(&loan) = loans.iter().find(|&&l| !self.loans[l].reborrow) {
+            let name = self.vars[&self.loans[loan].target].name.clone();
+            self.error(
+                Diagnostic::new("E0515", format!("cannot return reference to local variable `{}`", name))
+                    .primary(expr.span(), "returns a reference to data owned by the current function")
+                    .note(BORROW_RULE_2)
+                    .note(&format!("`{}` is dropped when `{}` returns; return the owned value instead", name, function.name)),
+            );
+        }
+    }
+}
+
+// What a method call gives back; `loans` are the receiver's borrows
+fn method_result(method: &str, loans: Vec<usize>) -> Value {
+    if OWNED_RESULT_METHODS.contains(&method) {
+        Value::Owned
+    } else if REF_RESULT_METHODS.contains(&method) {
+        Value::Ref { mutable: false, copy: false, loans }
+    } else {
+        Value::Copy
+    }
+}
+
+fn wrong_arg_count(expected: usize, found: usize, span: Span) -> Diagnostic {
+    Diagnostic::new("E0061", format!("this function takes {} argument(s) but {} were supplied", expected, found))
+        .primary(span, "wrong number of arguments")
+}
+
+fn past_tense(verb: &str) -> &'static str {
+    if verb == "borrow" { "borrowed" } else { "used" }
+}
+
+// Passing a String where &String is expected (or the other way round)
+fn mismatch(param: Ty, arg: &Expr) -> Option<String> {
+    match (param, arg) {
+        (Ty::Ref { mutable: true, .. }, Expr::Borrow { mutable: false, .. }) => {
+            Some(String::from("types differ in mutability: expected `&mut`, found `&` (use &mut here)"))
+        }
+        (Ty::Ref { .. }, Expr::New { .. }) => Some(String::from("expected a reference, found an owned value (add &)")),
+        (Ty::Copy | Ty::Owned, Expr::Borrow { .. }) => {
+            Some(String::from("expected an owned value, found a reference (remove the &)"))
+        }
+        _ => None,
+    }
+}
+
+// BUILT-IN EXAMPLES
+// (name, description, program)
+
+const EXAMPLES: [(&str, &str, &str); 8] = [
+    (
+        "q1",
+        "questions.md Q1: use after move",
+        r#"fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{}", s1);
+}
+"#,
+    ),
+    (
+        "q2",
+        "questions.md Q2: mutable + immutable at the same time",
+        r#"fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r3 = &mut s;
+    println!("{}", r1);
+}
+"#,
+    ),
+    (
+        "nll",
+        "Q2 fixed: r1's last use comes before the &mut, so it's fine",
+        r#"fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &s;
+    println!("{} and {}", r1, r2);
+    let r3 = &mut s;
+    r3.push_str(", world");
+    println!("{}", r3);
+}
+"#,
+    ),
+    (
+        "q5",
+        "questions.md Q5: dangling reference",
+        r#"fn main() {
+    let r;
+    {
+        let x = 5;
+        r = &x;
+    }
+    println!("{}", r);
+}
+"#,
+    ),
+    (
+        "longest",
+        "questions.md Q5: returned reference without a lifetime",
+        r#"fn longest(x: &str, y: &str) -> &str {
+    x
+}
+
+fn main() {
+    let a = String::from("apple");
+    let b = String::from("banana");
+    let result = longest(&a, &b);
+    println!("{}", result);
+}
+"#,
+    ),
+    (
+        "lesson3",
+        "Lesson 3's ownership and borrowing examples (no errors)",
+        r#"fn take_ownership(s: String) {
+    println!("I now own: {}", s);
+}
+
+fn calculate_length(s: &String) -> usize {
+    s.len()
+}
+
+fn change_string(s: &mut String) {
+    s.push_str(", World!");
+}
+
+fn main() {
+    let s1 = String::from("Hello");
+    let s2 = s1;
+    println!("s2: {}", s2);
+
+    let message = String::from("Hello, Functions!");
+    take_ownership(message);
+
+    let original = String::from("I'm the original!");
+    let length = calculate_length(&original);
+    println!("'{}' has {} characters", original, length);
+
+    let mut text = String::from("Hello");
+    change_string(&mut text);
+
+    let mut counter = 0;
+    {
+        let counter_ref = &mut counter;
+        *counter_ref += 10;
+    }
+    println!("Counter after increment: {}", counter);
+}
+"#,
+    ),
+    (
+        "immutable",
+        "Lesson 1: assigning twice to an immutable variable",
+        r#"fn main() {
+    let x = 5;
+    x = 6;
+}
+"#,
+    ),
+    (
+        "dangle",
+        "Returning a reference to a local variable",
+        r#"fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
+
+fn main() {
+    let r = dangle();
+}
+"#,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    const NO_ERRORS: [&str; 0] = [];
+
+    // The error codes found in a program, in order
+    fn codes(source: &str) -> Vec<&'static str> {
+        check_program(source).diagnostics.iter().map(|d| d.code).collect()
+    }
+
+    fn example(name: &str) -> &'static str {
+        EXAMPLES.iter().find(|(n, _, _)| *n == name).expect("no such example").2
+    }
+
+    #[test]
+    fn every_built_in_example_gives_its_error() {
+        let expected: [(&str, &[&str]); 8] = [
+            ("q1", &["E0382"]),
+            ("q2", &["E0502"]),
+            ("nll", &[]),
+            ("q5", &["E0597"]),
+            ("longest", &["E0106"]),
+            ("lesson3", &[]),
+            ("immutable", &["E0384"]),
+            ("dangle", &["E0106", "E0515"]),
+        ];
+        assert_eq!(expected.len(), EXAMPLES.len(), "an example has no expected result here");
+        for (name, errors) in expected {
+            assert_eq!(codes(example(name)), errors, "example {}", name);
+        }
+    }
+
+    #[test]
+    fn a_method_call_may_read_its_receiver_in_the_arguments() {
+        assert_eq!(codes("let mut v = vec![1, 2];\nv.push(v.len());\nprintln!(\"{:?}\", v);\n"), NO_ERRORS);
+        assert_eq!(codes("let mut v = vec![1, 2];\nv.insert(v.len(), v.len());\n"), NO_ERRORS);
+    }
+
+    #[test]
+    fn an_argument_still_borrowing_the_receiver_conflicts() {
+        // The &s is passed into push_str, so it is alive when &mut s starts
+        assert_eq!(codes("let mut s = String::from(\"a\");\ns.push_str(&s);\n"), ["E0502"]);
+    }
+
+    #[test]
+    fn a_reserved_borrow_still_conflicts_with_older_borrows() {
+        assert_eq!(codes("let mut v = vec![1];\nlet r = &v;\nv.push(1);\nprintln!(\"{:?}\", r);\n"), ["E0502"]);
+        assert_eq!(codes("let mut v = vec![1];\nlet r = &mut v;\nv.push(1);\nr.push(2);\n"), ["E0499"]);
+        assert_eq!(codes("let v = vec![1];\nv.push(v.len());\n"), ["E0596"]);
+    }
+
+    #[test]
+    fn reborrows_parse_and_keep_the_original_borrow() {
+        assert_eq!(codes("let x = 5;\nlet r = &x;\nlet q = &*r;\nprintln!(\"{} {}\", q, r);\n"), NO_ERRORS);
+        assert_eq!(codes("let mut x = 5;\nlet r = &mut x;\nlet q = &mut *r;\n*q += 1;\n"), NO_ERRORS);
+        // q borrows s through r, so s can't change while q is still used
+        let source = "let mut s = String::from(\"a\");\nlet r = &s;\nlet q = &*r;\ns.push_str(\"b\");\nprintln!(\"{}\", q);\n";
+        assert_eq!(codes(source), ["E0502"]);
+        assert_eq!(codes("let x = 5;\nlet r = &x;\nlet q = &mut *r;\n"), ["E0596"]);
+        assert_eq!(codes("let x = 5;\nlet q = &*x;\n"), ["E0614"]);
+    }
+
+    #[test]
+    fn a_mut_reference_is_moved_not_copied() {
+        let source = "let mut s = String::from(\"a\");\nlet r = &mut s;\nlet r2 = r;\nprintln!(\"{}\", r);\n";
+        assert_eq!(codes(source), ["E0382"]);
+        // Shared references are Copy, so both stay usable
+        assert_eq!(codes("let s = String::from(\"a\");\nlet r = &s;\nlet r2 = r;\nprintln!(\"{} {}\", r, r2);\n"), NO_ERRORS);
+        // Passing r where a &mut is expected reborrows it instead of moving it
+        let source = "fn add(t: &mut String) {\n    t.push_str(\"x\");\n}\nfn main() {\n    let mut s = String::from(\"a\");\n    let r = &mut s;\n    add(r);\n    add(r);\n}\n";
+        assert_eq!(codes(source), NO_ERRORS);
+    }
+
+    #[test]
+    fn only_copy_values_can_be_moved_out_through_a_reference() {
+        assert_eq!(codes("let s = String::from(\"a\");\nlet r = &s;\nlet t = *r;\n"), ["E0507"]);
+        assert_eq!(codes("let mut s = String::from(\"a\");\nlet r = &mut s;\nlet t = *r;\n"), ["E0507"]);
+        assert_eq!(codes("let x = 5;\nlet r = &x;\nlet t = *r;\nprintln!(\"{}\", t);\n"), NO_ERRORS);
+        assert_eq!(codes("fn first(v: &String) {\n    let t = *v;\n}\n"), ["E0507"]);
+        // Printing or comparing *r only reads it
+        assert_eq!(codes("let s = String::from(\"a\");\nlet r = &s;\nprintln!(\"{}\", *r);\nlet same = *r == *r;\n"), NO_ERRORS);
+    }
+
+    #[test]
+    fn a_mut_reborrow_borrows_the_reference_too() {
+        let source = "let mut s = String::from(\"a\");\nlet r = &mut s;\nlet r2 = &mut *r;\nr.push_str(\"y\");\nr2.push_str(\"x\");\n";
+        assert_eq!(codes(source), ["E0499"]);
+        // Once r2 is done, r can be used again
+        let source = "let mut s = String::from(\"a\");\nlet r = &mut s;\nlet r2 = &mut *r;\nr2.push_str(\"x\");\nr.push_str(\"y\");\n";
+        assert_eq!(codes(source), NO_ERRORS);
+        let source = "let mut s = String::from(\"a\");\nlet r = &mut s;\nlet r2 = &mut *r;\nprintln!(\"{}\", r);\nr2.push_str(\"x\");\n";
+        assert_eq!(codes(source), ["E0502"]);
+        // The reborrowed value outlives the reference it went through
+        let source = "fn first(t: &mut String) -> &mut String {\n    &mut *t\n}\nfn main() {\n    let mut s = String::from(\"a\");\n    let r = first(&mut s);\n    r.push_str(\"x\");\n}\n";
+        assert_eq!(codes(source), NO_ERRORS);
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error_not_a_crash() {
+        let blocks = format!("fn main() {}{}", "{".repeat(5000), "}".repeat(5000));
+        assert_eq!(codes(&blocks), ["syntax"]);
+        let parens = format!("let x = {}1{};", "(".repeat(5000), ")".repeat(5000));
+        assert_eq!(codes(&parens), ["syntax"]);
+        let types = format!("fn f(x: {}i32) {{\n}}\n", "& ".repeat(5000));
+        assert_eq!(codes(&types), ["syntax"]);
+        assert_eq!(codes(&format!("let x = {}1{};", "(".repeat(20), ")".repeat(20))), NO_ERRORS);
+    }
+
+    #[test]
+    fn drop_moves_its_argument() {
+        assert_eq!(codes("let s = String::from(\"a\");\ndrop(s);\nprintln!(\"{}\", s);\n"), ["E0382"]);
+        assert_eq!(codes("let s = String::from(\"a\");\nlet r = &s;\ndrop(s);\nprintln!(\"{}\", r);\n"), ["E0505"]);
+        assert_eq!(codes("let n = 5;\ndrop(n);\nprintln!(\"{}\", n);\n"), NO_ERRORS);
+        assert_eq!(codes("let s = String::from(\"a\");\ndrop(s, s);\n"), ["E0061"]);
+        // A program's own fn drop is called like any other function
+        assert_eq!(codes("fn drop(s: &String) {\n}\nfn main() {\n    let s = String::from(\"a\");\n    drop(&s);\n    println!(\"{}\", s);\n}\n"), NO_ERRORS);
+    }
+
+    #[test]
+    fn char_literals_with_escapes_are_one_token() {
+        for literal in ["'x'", "'\\''", "'\\\\'", "'\\n'"] {
+            let Ok(tokens) = tokenize(&format!("let c = {};", literal)) else {
+                panic!("{} did not lex", literal);
+            };
+            let kinds: Vec<&Tok> = tokens.iter().map(|t| &t.tok).collect();
+            assert_eq!(kinds[3], &Tok::Number, "{}", literal);
+            assert_eq!(kinds[4], &Tok::Sym(";"), "{}", literal);
+            assert_eq!(tokens[3].span.end - tokens[3].span.start, literal.chars().count());
+        }
+        assert_eq!(codes("let c = '\\'';\nlet s = String::from(\"it's\");\nlet t = s;\nprintln!(\"{}\", s);\n"), ["E0382"]);
+    }
+
+    #[test]
+    fn a_long_line_is_checked_quickly() {
+        // Each of these is a single line of about 200,000 characters
+        let lines = [
+            format!("let x = {}1;", "1 + ".repeat(50_000)),
+            format!("let v = vec![{}1];", "1, ".repeat(66_000)),
+            format!("let mut v = vec![1]; {}", "v.push(v.len()); ".repeat(12_000)),
+            format!("let s = String::from(\"a\"); {}", "let r = &s; ".repeat(16_000)),
+        ];
+        for line in &lines {
+            let start = Instant::now();
+            assert_eq!(codes(line), NO_ERRORS);
+            assert!(start.elapsed() < Duration::from_secs(10), "took {:?}", start.elapsed());
+        }
+    }
+}
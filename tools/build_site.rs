@@ -0,0 +1,351 @@
+// Course Website Builder
+// Turns the lessons and questions.md into a folder of static HTML pages:
+// - index.html         every lesson and its sections
+// - lesson-N.html      the lesson's source, section by section, with what that
+//                      section prints shown beside the code
+// - quiz.html          questions.md as a quiz: write your answer, then reveal
+// - cheatsheet.html    every lesson summary (see make_cheatsheet.rs)
+// Nothing is loaded from the internet, so the folder can be copied to a file
+// share or opened straight from disk.
+// To compile and run: rustc build_site.rs -o build-site && ./build-site
+//   ./build-site              write into target/site/
+//   ./build-site --out DIR    write into DIR instead
+//
+// The program output is captured by compiling and running each lesson with
+// rustc; if that fails, the lesson's snapshot (lessonN.snap) is used instead.
+// Run it from this folder or from the top of the repository.
+
+mod cheatsheet;
+mod html;
+mod lessons;
+mod markdown;
+mod sandbox;
+
+use cheatsheet::Links;
+use html::escape;
+use lessons::{Lesson, Line};
+use sandbox::{Limits, Sandbox};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+// Extra styles for the lesson and quiz pages
+const SITE_STYLE: &str = "<style>
+.pair { display: grid; grid-template-columns: minmax(0, 3fr) minmax(0, 2fr); gap: 1rem; align-items: start; }
+@media (max-width: 900px) { .pair { grid-template-columns: 1fr; } }
+.code .ln { color: #aaa; display: inline-block; width: 3ch; margin-right: 1ch; text-align: right; user-select: none; }
+.code .c { color: #5f7f5f; }
+.output { background: #1e1e1e; color: #ddd; }
+.output::before { content: \"output\"; display: block; color: #888; font-size: 0.75rem; }
+h2 a { color: inherit; text-decoration: none; }
+table { border-collapse: collapse; }
+td, th { border: 1px solid #ddd; padding: 0.25rem 0.5rem; }
+blockquote { border-left: 3px solid #ddd; margin-left: 0; padding-left: 1rem; color: #555; }
+textarea { width: 100%; min-height: 4rem; font: inherit; }
+details { margin: 0.5rem 0; }
+summary { cursor: pointer; font-weight: bold; }
+</style>
+";
+
+fn main() {
+    let Some(root) = lessons::find_root() else {
+        fail("no lesson-1 folder here or one level up; run this from the repository");
+    };
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let out = match args.as_slice() {
+        [] => root.join("target").join("site"),
+        [flag, dir] if flag == "--out" => PathBuf::from(dir),
+        _ => fail("usage: build-site [--out DIR]"),
+    };
+
+    let all = lessons::load_all(&root).unwrap_or_else(|e| fail(&format!("could not read the lessons: {}", e)));
+    if let Err(e) = fs::create_dir_all(&out) {
+        fail(&format!("could not create {}: {}", out.display(), e));
+    }
+
+    let mut pages = vec![(String::from("index.html"), index_page(&all))];
+    for (i, lesson) in all.iter().enumerate() {
+        println!("Running lesson {}...", lesson.number);
+        let output = capture_output(lesson);
+        let previous = i.checked_sub(1).and_then(|p| all.get(p));
+        let next = all.get(i + 1);
+        pages.push((format!("lesson-{}.html", lesson.number), lesson_page(lesson, &output, previous, next)));
+    }
+    match fs::read_to_string(root.join("questions.md")) {
+        Ok(questions) => pages.push((String::from("quiz.html"), quiz_page(&questions))),
+        Err(e) => eprintln!("Skipping the quiz: could not read questions.md ({})", e),
+    }
+    let cheatsheet = format!("{}{}", nav(&[]), cheatsheet::html_body(&all, &Links::Site));
+    pages.push((String::from("cheatsheet.html"), html::page("Rust Cheat Sheet", &cheatsheet)));
+
+    for (name, contents) in &pages {
+        let path = out.join(name);
+        if let Err(e) = fs::write(&path, contents) {
+            fail(&format!("could not write {}: {}", path.display(), e));
+        }
+    }
+    println!("Wrote {} pages to {}", pages.len(), out.display());
+}
+
+// What the lesson prints: a fresh run if possible, the snapshot otherwise
+fn capture_output(lesson: &Lesson) -> String {
+    match run_lesson(&lesson.path) {
+        Ok(output) => output,
+        Err(reason) => {
+            let snapshot = lesson.path.with_extension("snap");
+            eprintln!("  could not run it ({}), using {}", reason.lines().next().unwrap_or(""), snapshot.display());
+            fs::read_to_string(snapshot).unwrap_or_default()
+        }
+    }
+}
+
+fn run_lesson(path: &Path) -> Result<String, String> {
+    let sandbox = Sandbox::new().map_err(|e| e.to_string())?;
+    let limits = Limits::default();
+    // An absolute path, so rustc finds sibling modules like lesson-3/trace.rs
+    let source = fs::canonicalize(path).map_err(|e| e.to_string())?;
+    let compiled = sandbox.compile(&source.to_string_lossy(), &[], &limits).map_err(|e| e.to_string())?;
+    if !compiled.success {
+        return Err(compiled.diagnostics);
+    }
+    let run = sandbox.run(&[], "", &limits).map_err(|e| e.to_string())?;
+    if !run.success() {
+        return Err(format!("exited with {:?}", run.status));
+    }
+    Ok(run.stdout)
+}
+
+// PAGES
+
+fn nav(extra: &[(String, String)]) -> String {
+    let mut links = vec![
+        (String::from("index.html"), String::from("All lessons")),
+        (String::from("quiz.html"), String::from("Quiz")),
+        (String::from("cheatsheet.html"), String::from("Cheat sheet")),
+    ];
+    links.extend(extra.iter().cloned());
+    let links: Vec<String> = links
+        .iter()
+        .map(|(href, label)| format!("<a href=\"{}\">{}</a>", href, escape(label)))
+        .collect();
+    format!("<nav>{}</nav>\n", links.join(""))
+}
+
+fn lesson_name(lesson: &Lesson) -> String {
+    match lesson.title() {
+        Some(title) => format!("Lesson {}: {}", lesson.number, title),
+        None => format!("Lesson {}", lesson.number),
+    }
+}
+
+fn index_page(all: &[Lesson]) -> String {
+    let mut body = nav(&[]);
+    body.push_str("<h1>Learning Rust</h1>\n");
+    body.push_str("<p>Each lesson is a single Rust program: read the code, and see what every section prints.</p>\n");
+    for lesson in all {
+        body.push_str(&format!(
+            "<h2><a href=\"lesson-{}.html\">{}</a></h2>\n<ul>\n",
+            lesson.number,
+            escape(&lesson_name(lesson))
+        ));
+        for section in &lesson.sections {
+            body.push_str(&format!(
+                "<li><a href=\"lesson-{}.html#{}\">{}</a></li>\n",
+                lesson.number,
+                lessons::slug(&section.text),
+                escape(&section.text)
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+    html::page_with("Learning Rust", &body, SITE_STYLE)
+}
+
+// The lesson split at its section banners. Each part shows its code, and
+// beside it the output printed from that banner up to the next one.
+fn lesson_page(lesson: &Lesson, output: &str, previous: Option<&Lesson>, next: Option<&Lesson>) -> String {
+    let lines: Vec<&str> = lesson.source.lines().collect();
+    let outputs = split_output(output, &lesson.sections);
+
+    // Where each part starts (0-based), plus the end of main() and the end of the file
+    let mut starts: Vec<usize> = vec![0];
+    starts.extend(lesson.sections.iter().map(|s| lessons::section_start(&lines, s.number)));
+    let main_end = lesson
+        .end_banner
+        .as_ref()
+        .and_then(|end| (end.number..lines.len()).find(|&i| lines[i] == "}"))
+        .map_or(lines.len(), |i| i + 1);
+    starts.push(main_end);
+    starts.push(lines.len());
+
+    let mut extra = Vec::new();
+    if let Some(previous) = previous {
+        extra.push((format!("lesson-{}.html", previous.number), format!("← Lesson {}", previous.number)));
+    }
+    if let Some(next) = next {
+        extra.push((format!("lesson-{}.html", next.number), format!("Lesson {} →", next.number)));
+    }
+    let mut body = nav(&extra);
+    body.push_str(&format!("<h1>{}</h1>\n", escape(&lesson_name(lesson))));
+    if let Some(compile) = &lesson.compile_line {
+        body.push_str(&format!("<p class=\"muted\">To compile and run: <code>{}</code></p>\n", escape(&compile.text)));
+    }
+
+    for part in 0..starts.len() - 1 {
+        let (start, end) = (starts[part], starts[part + 1]);
+        if start >= end {
+            continue;
+        }
+        // Part 0 is the code before the first banner; the last part is
+        // everything after main(): helper functions and the summary
+        let section = part.checked_sub(1).and_then(|s| lesson.sections.get(s));
+        let is_rest = part == starts.len() - 2;
+        let id = match section {
+            Some(section) => lessons::slug(&section.text),
+            None if is_rest => String::from("summary"),
+            None => String::from("start"),
+        };
+        body.push_str(&format!("<section id=\"{}\">\n", id));
+        if let Some(section) = section {
+            body.push_str(&format!("<h2><a href=\"#{}\">{}</a></h2>\n", id, escape(&section.text)));
+        } else if is_rest {
+            body.push_str("<h2><a href=\"#summary\">Definitions and summary</a></h2>\n");
+        }
+
+        let code = code_html(&lines[start..end], start + 1);
+        let printed = if is_rest { None } else { outputs.get(part).filter(|o| !o.is_empty()) };
+        match printed {
+            Some(printed) => body.push_str(&format!(
+                "<div class=\"pair\">\n{}<pre class=\"output\">{}</pre>\n</div>\n",
+                code,
+                escape(printed)
+            )),
+            None => body.push_str(&code),
+        }
+        body.push_str("</section>\n");
+    }
+    html::page_with(&lesson_name(lesson), &body, SITE_STYLE)
+}
+
+// Output before the first banner, then one piece per section banner
+fn split_output(output: &str, sections: &[Line]) -> Vec<String> {
+    let mut pieces = vec![Vec::new()];
+    let mut next_banner = 0;
+    for line in output.lines() {
+        let is_banner = sections
+            .get(next_banner)
+            .is_some_and(|section| line == format!("--- {} ---", section.text));
+        if is_banner {
+            pieces.push(Vec::new());
+            next_banner += 1;
+        }
+        pieces.last_mut().expect("there is always a piece").push(line);
+    }
+    pieces
+        .iter()
+        .map(|piece| piece.join("\n").trim_matches('\n').to_string())
+        .collect()
+}
+
+// Source lines with line numbers (and ids like #L12), comments dimmed
+fn code_html(lines: &[&str], first_number: usize) -> String {
+    let mut out = String::from("<pre class=\"code\"><code>");
+    for (i, line) in lines.iter().enumerate() {
+        let number = first_number + i;
+        out.push_str(&format!("<span class=\"ln\" id=\"L{}\">{}</span>", number, number));
+        match lessons::comment_start(line) {
+            Some(start) => out.push_str(&format!(
+                "{}<span class=\"c\">{}</span>",
+                escape(&line[..start]),
+                escape(&line[start..])
+            )),
+            None => out.push_str(&escape(line)),
+        }
+        out.push('\n');
+    }
+    out.push_str("</code></pre>\n");
+    out
+}
+
+// QUIZ
+
+// Each "## Question N" section of questions.md becomes a card: the bold
+// question, a box for your answer, and the rest of the section hidden behind
+// "Show the answer". Ticking "I got it" keeps score (stored in the browser).
+fn quiz_page(questions: &str) -> String {
+    let mut body = nav(&[]);
+    let mut total = 0;
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for line in questions.lines() {
+        if let Some(title) = line.strip_prefix("## ") {
+            sections.push((title.trim().to_string(), Vec::new()));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line);
+        } else if let Some(title) = line.strip_prefix("# ") {
+            body.push_str(&format!("<h1>{}</h1>\n", markdown::inline(title.trim())));
+        }
+    }
+
+    body.push_str("<p>Answer each question in your own words, then open the answer to compare.</p>\n");
+    body.push_str("<p><strong id=\"score\"></strong></p>\n");
+    for (title, lines) in &sections {
+        // Drop the --- that separates sections in the Markdown
+        let mut lines: Vec<&str> = lines.clone();
+        while lines.last().is_some_and(|l| l.trim().is_empty() || l.trim() == "---") {
+            lines.pop();
+        }
+        let id = lessons::slug(title);
+        body.push_str(&format!("<section id=\"{}\">\n<h2><a href=\"#{}\">{}</a></h2>\n", id, id, markdown::inline(title)));
+
+        let question = lines.iter().position(|l| l.trim().starts_with("**"));
+        match question.filter(|_| title.starts_with("Question")) {
+            Some(q) => {
+                total += 1;
+                // A code block right after the question belongs to it ("What happens when you run this code?")
+                let mut answer = q + 1;
+                if lines.get(answer).is_some_and(|l| l.trim().starts_with("```")) {
+                    answer += 1 + lines[answer + 1..].iter().position(|l| l.trim() == "```").map_or(0, |end| end + 1);
+                }
+                body.push_str(&markdown::to_html(&lines[q..answer.min(lines.len())].join("\n")));
+                body.push_str("<textarea placeholder=\"Your answer\"></textarea>\n");
+                body.push_str("<details>\n<summary>Show the answer</summary>\n");
+                body.push_str(&markdown::to_html(&lines[answer.min(lines.len())..].join("\n")));
+                body.push_str("</details>\n");
+                body.push_str(&format!(
+                    "<label><input type=\"checkbox\" class=\"got-it\" data-id=\"{}\"> I got it</label>\n",
+                    id
+                ));
+            }
+            // Not a question (like the Summary table): show it as it is
+            None => body.push_str(&markdown::to_html(&lines.join("\n"))),
+        }
+        body.push_str("</section>\n");
+    }
+
+    let script = format!("{}<script>\n{}</script>\n", SITE_STYLE, QUIZ_SCRIPT.replace("TOTAL", &total.to_string()));
+    html::page_with("Rust Quiz", &body, &script)
+}
+
+// Keeps the "I got it" ticks across visits and shows the score
+const QUIZ_SCRIPT: &str = "document.addEventListener('DOMContentLoaded', function () {
+  var boxes = document.querySelectorAll('.got-it');
+  function load() { try { return JSON.parse(localStorage.getItem('rust-quiz') || '{}'); } catch (e) { return {}; } }
+  function update() {
+    var saved = {};
+    var right = 0;
+    boxes.forEach(function (box) { saved[box.dataset.id] = box.checked; if (box.checked) right++; });
+    try { localStorage.setItem('rust-quiz', JSON.stringify(saved)); } catch (e) {}
+    document.getElementById('score').textContent = 'Score: ' + right + ' / TOTAL';
+  }
+  var saved = load();
+  boxes.forEach(function (box) { box.checked = !!saved[box.dataset.id]; box.addEventListener('change', update); });
+  update();
+});
+";
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
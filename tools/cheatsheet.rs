@@ -0,0 +1,153 @@
+// Shared module: Cheat Sheet Rendering
+// Turns the `// LESSON N SUMMARY:` and `// KEY CONCEPTS:` blocks of every
+// lesson into one cheat sheet, as Markdown or as HTML. Each bullet links to
+// the lesson section named by its "(see Section)" tag.
+//
+// To use it from a program in this folder, add `mod lessons;`, `mod html;`
+// and `mod cheatsheet;` at the top, then:
+//     let markdown = cheatsheet::markdown(&lessons, &Links::Source(String::from("../")));
+
+#![allow(dead_code)] // not every program uses every helper
+
+use crate::html;
+use crate::lessons::{self, Block, Bullet, Lesson};
+
+// Where the bullet links point
+pub enum Links {
+    // At the section banner in the lesson source, e.g. "../../lesson-3/lesson3.rs#L53";
+    // the String is the path from the cheat sheet back to the repository root
+    Source(String),
+    // At the section on the HTML lesson page, e.g. "lesson-3.html#borrowing-with-references"
+    Site,
+}
+
+impl Links {
+    // The link for a bullet: the section it names, or the bullet itself if
+    // it names none (the lesson lint reports those)
+    fn href(&self, lesson: &Lesson, bullet: &Bullet) -> (String, String) {
+        let section = bullet.section.as_deref().and_then(|title| lesson.section_named(title));
+        let label = match section {
+            Some(section) => format!("Lesson {}: {}", lesson.number, section.text),
+            None => format!("Lesson {}", lesson.number),
+        };
+        let href = match (self, section) {
+            (Links::Source(root), _) => {
+                let line = section.map_or(bullet.number, |s| s.number);
+                format!("{}lesson-{n}/lesson{n}.rs#L{}", root, line, n = lesson.number)
+            }
+            (Links::Site, Some(section)) => format!("lesson-{}.html#{}", lesson.number, lessons::slug(&section.text)),
+            (Links::Site, None) => format!("lesson-{}.html#L{}", lesson.number, bullet.number),
+        };
+        (label, href)
+    }
+}
+
+// The blocks a lesson contributes, with the heading to show for each
+fn blocks(lesson: &Lesson) -> Vec<(&'static str, &Block)> {
+    let mut blocks = Vec::new();
+    if let Some(summary) = &lesson.summary {
+        blocks.push(("Summary", summary));
+    }
+    if let Some(key_concepts) = &lesson.key_concepts {
+        blocks.push(("Key Concepts", key_concepts));
+    }
+    blocks
+}
+
+fn lesson_heading(lesson: &Lesson) -> String {
+    match lesson.title() {
+        Some(title) => format!("Lesson {}: {}", lesson.number, title),
+        None => format!("Lesson {}", lesson.number),
+    }
+}
+
+// MARKDOWN
+
+pub fn markdown(lessons: &[Lesson], links: &Links) -> String {
+    let mut out = String::from("# Rust Cheat Sheet\n\n");
+    out.push_str("Collected from the summary at the end of each lesson. ");
+    out.push_str("Each bullet links to the lesson section it sums up.\n");
+
+    for lesson in lessons {
+        out.push_str(&format!("\n## {}\n", md_escape(&lesson_heading(lesson))));
+        for (name, block) in blocks(lesson) {
+            if name != "Summary" {
+                out.push_str(&format!("\n### {}\n", name));
+            }
+            for group in &block.groups {
+                if let Some(heading) = &group.heading {
+                    out.push_str(&format!("\n**{}**\n", md_escape(heading)));
+                }
+                out.push('\n');
+                for bullet in &group.bullets {
+                    let (label, href) = links.href(lesson, bullet);
+                    out.push_str(&format!("- {} ([{}]({}))\n", md_escape(&bullet.text), md_escape(&label), href));
+                }
+            }
+        }
+    }
+    out
+}
+
+// Backslash the characters Markdown would otherwise treat as formatting
+// (the lessons write things like Vec<T>, &mut T and "Dereference with *")
+fn md_escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        if "\\`*_[]<>#|".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+// HTML
+
+// Just the cheat sheet itself, so it can be placed inside another page
+pub fn html_body(lessons: &[Lesson], links: &Links) -> String {
+    let mut out = String::from("<h1>Rust Cheat Sheet</h1>\n");
+    out.push_str("<p class=\"muted\">Collected from the summary at the end of each lesson. ");
+    out.push_str("Each bullet links to the lesson section it sums up.</p>\n");
+
+    out.push_str("<nav>");
+    for lesson in lessons {
+        out.push_str(&format!("<a href=\"#lesson-{n}\">Lesson {n}</a>", n = lesson.number));
+    }
+    out.push_str("</nav>\n");
+
+    for lesson in lessons {
+        out.push_str(&format!(
+            "<h2 id=\"lesson-{}\">{}</h2>\n",
+            lesson.number,
+            html::escape(&lesson_heading(lesson))
+        ));
+        for (name, block) in blocks(lesson) {
+            if name != "Summary" {
+                out.push_str(&format!("<h3>{}</h3>\n", name));
+            }
+            for group in &block.groups {
+                if let Some(heading) = &group.heading {
+                    out.push_str(&format!("<h4>{}</h4>\n", html::escape(heading)));
+                }
+                out.push_str("<ul>\n");
+                for bullet in &group.bullets {
+                    let (label, href) = links.href(lesson, bullet);
+                    out.push_str(&format!(
+                        "<li>{} <a class=\"muted\" href=\"{}\">({})</a></li>\n",
+                        html::escape(&bullet.text),
+                        html::escape(&href),
+                        html::escape(&label)
+                    ));
+                }
+                out.push_str("</ul>\n");
+            }
+        }
+    }
+    out
+}
+
+// A standalone page
+pub fn html_page(lessons: &[Lesson], links: &Links) -> String {
+    html::page("Rust Cheat Sheet", &html_body(lessons, links))
+}
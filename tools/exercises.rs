@@ -0,0 +1,273 @@
+// Shared module: Exercise Catalog
+// The lesson exercises that can be graded automatically. Each one pins down
+// the function signature a solution must provide, hints to reveal one at a
+// time, plus hidden tests the grader compiles together with the learner's file.
+//
+// To use it from a program in this folder, add `mod exercises;` at the top, then:
+//     let exercise = exercises::find("2.3");
+
+#![allow(dead_code)] // not every program uses every helper
+
+pub struct Exercise {
+    pub id: &'static str, // "<lesson>.<exercise number>", e.g. "2.3"
+    pub lesson: u32,
+    pub text: &'static str,      // the exercise as worded in the lesson
+    pub signature: &'static str, // what the solution must define
+    pub hints: &'static [&'static str], // from a gentle nudge to a partial solution
+    pub tests: &'static str,     // hidden #[test] functions (they can call private fns)
+}
+
+impl Exercise {
+    // Names of the hidden tests, in order: every `fn` right after a #[test]
+    pub fn test_names(&self) -> Vec<&'static str> {
+        let lines: Vec<&'static str> = self.tests.lines().map(|l| l.trim()).collect();
+        lines
+            .windows(2)
+            .filter(|pair| pair[0] == "#[test]")
+            .filter_map(|pair| pair[1].strip_prefix("fn "))
+            .filter_map(|rest| rest.split('(').next())
+            .collect()
+    }
+}
+
+pub fn find(id: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|e| e.id == id)
+}
+
+pub const EXERCISES: [Exercise; 7] = [
+    Exercise {
+        id: "2.1",
+        lesson: 2,
+        text: "Write a function that takes a number and returns whether it's even or odd",
+        signature: "fn is_even(n: i32) -> bool",
+        hints: &[
+            "A number is even when dividing it by 2 leaves nothing over. Which operator gives you the remainder?",
+            "Lesson 1 computes `43 % 5` and lesson 2's divide_with_remainder() uses `dividend % divisor`: `%` is the remainder operator.",
+            r#"fn is_even(n: i32) -> bool {
+    n % 2 == ___ // what is the remainder of an even number?
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn even_numbers() {
+    assert!(is_even(0));
+    assert!(is_even(4));
+}
+
+#[test]
+fn odd_numbers() {
+    assert!(!is_even(7));
+}
+
+#[test]
+fn negative_numbers() {
+    assert!(is_even(-2));
+    assert!(!is_even(-3));
+}
+"#,
+    },
+    Exercise {
+        id: "2.3",
+        lesson: 2,
+        text: "Write a function that finds the maximum number in a vector",
+        signature: "fn find_max(numbers: &[i32]) -> Option<i32>",
+        hints: &[
+            "An empty vector has no maximum, which is why the answer is an Option: None for empty, Some(biggest) otherwise.",
+            "Lesson 2 uses `scores.get(2)`, which returns an Option the same way, and loops with `for score in &scores`.",
+            r#"fn find_max(numbers: &[i32]) -> Option<i32> {
+    let mut max = *numbers.first()?; // ? returns None early for an empty slice
+    for &n in numbers {
+        // keep n if it is bigger than max
+    }
+    Some(max)
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn finds_max() {
+    assert_eq!(find_max(&vec![85, 92, 78, 96, 88]), Some(96));
+}
+
+#[test]
+fn max_at_start() {
+    assert_eq!(find_max(&vec![9, 1, 2]), Some(9));
+}
+
+#[test]
+fn all_negative() {
+    assert_eq!(find_max(&vec![-5, -2, -9]), Some(-2));
+}
+
+#[test]
+fn empty_vector_has_no_max() {
+    assert_eq!(find_max(&vec![]), None);
+}
+"#,
+    },
+    Exercise {
+        id: "2.5",
+        lesson: 2,
+        text: "Practice with string manipulation: reverse a string, count vowels, etc.",
+        signature: "fn count_vowels(s: &str) -> usize",
+        hints: &[
+            "Look at the text one character at a time, and count the ones that are vowels (upper or lower case).",
+            "Lesson 2's greeting.to_uppercase() and greeting.contains(\"World\") show string methods; `\"aeiou\".contains(c)` works for a single char c too.",
+            r#"fn count_vowels(s: &str) -> usize {
+    s.chars()
+        .filter(|c| "aeiouAEIOU".contains(*c))
+        .___() // how many are left?
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn counts_vowels() {
+    assert_eq!(count_vowels("Hello, Rust!"), 3);
+}
+
+#[test]
+fn uppercase_vowels_count_too() {
+    assert_eq!(count_vowels("AEIOU"), 5);
+}
+
+#[test]
+fn no_vowels() {
+    assert_eq!(count_vowels("rhythm"), 0);
+    assert_eq!(count_vowels(""), 0);
+}
+"#,
+    },
+    Exercise {
+        id: "3.1",
+        lesson: 3,
+        text: "Write a function that takes a String and returns the longest word",
+        signature: "fn longest_word(text: String) -> String",
+        hints: &[
+            "Split the text into words, then remember the longest word seen so far. Only replace it when a word is strictly longer, so the first one wins a tie.",
+            "Lesson 3's count_words() uses `text.split_whitespace()`, which also skips extra spaces.",
+            r#"fn longest_word(text: String) -> String {
+    let mut longest = "";
+    for word in text.split_whitespace() {
+        // replace longest if word.len() is bigger
+    }
+    longest.to_string()
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn finds_longest() {
+    assert_eq!(longest_word(String::from("Hello wonderful world")), "wonderful");
+}
+
+#[test]
+fn first_wins_a_tie() {
+    assert_eq!(longest_word(String::from("cat dog")), "cat");
+}
+
+#[test]
+fn extra_spaces() {
+    assert_eq!(longest_word(String::from("  a   bb  ")), "bb");
+}
+
+#[test]
+fn empty_text() {
+    assert_eq!(longest_word(String::new()), "");
+}
+"#,
+    },
+    Exercise {
+        id: "3.2",
+        lesson: 3,
+        text: "Create a function that reverses a string slice and returns a new String",
+        signature: "fn reverse(s: &str) -> String",
+        hints: &[
+            "A &str is UTF-8 bytes, so reversing bytes would break characters like 🦀. Reverse characters instead.",
+            "Lesson 2 iterates backwards with `(1..=5).rev()`; `.chars()` gives you the characters of a string in the same kind of iterator.",
+            r#"fn reverse(s: &str) -> String {
+    s.chars().rev().___() // gather the characters into a new String
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn reverses() {
+    assert_eq!(reverse("Hello"), "olleH");
+}
+
+#[test]
+fn empty() {
+    assert_eq!(reverse(""), "");
+}
+
+#[test]
+fn keeps_unicode_characters_whole() {
+    assert_eq!(reverse("crab 🦀"), "🦀 barc");
+}
+"#,
+    },
+    Exercise {
+        id: "3.4",
+        lesson: 3,
+        text: "Implement a function that safely accesses vector elements by index",
+        signature: "fn safe_get(items: &[i32], index: usize) -> Option<i32>",
+        hints: &[
+            "Indexing with items[index] panics when the index is too big. Return None instead of crashing.",
+            "Lesson 2 accesses vector elements safely with `match scores.get(2)`: get() returns Some(&value) or None.",
+            r#"fn safe_get(items: &[i32], index: usize) -> Option<i32> {
+    match items.get(index) {
+        Some(value) => ___, // value is a &i32
+        None => None,
+    }
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn valid_index() {
+    assert_eq!(safe_get(&vec![10, 20, 30], 2), Some(30));
+}
+
+#[test]
+fn index_past_the_end() {
+    assert_eq!(safe_get(&vec![10, 20, 30], 3), None);
+}
+
+#[test]
+fn empty_vector() {
+    assert_eq!(safe_get(&vec![], 0), None);
+}
+"#,
+    },
+    Exercise {
+        id: "3.5",
+        lesson: 3,
+        text: "Create a text analyzer that counts vowels, consonants, and spaces",
+        signature: "fn analyze(text: &str) -> (usize, usize, usize) // (vowels, consonants, spaces)",
+        hints: &[
+            "Walk through the characters once with three counters. A letter is a vowel or a consonant; punctuation is neither.",
+            "Lesson 3's count_words() works on a &str; here use `text.chars()`, and char methods like c.is_alphabetic() and c == ' '.",
+            r#"fn analyze(text: &str) -> (usize, usize, usize) {
+    let (mut vowels, mut consonants, mut spaces) = (0, 0, 0);
+    for c in text.chars() {
+        if "aeiouAEIOU".contains(c) {
+            vowels += 1;
+        } // ...consonants and spaces
+    }
+    (vowels, consonants, spaces)
+}"#,
+        ],
+        tests: r#"
+#[test]
+fn hello_world() {
+    assert_eq!(analyze("Hello World"), (3, 7, 1));
+}
+
+#[test]
+fn punctuation_is_not_a_consonant() {
+    assert_eq!(analyze("a, b!"), (1, 1, 1));
+}
+
+#[test]
+fn empty_text() {
+    assert_eq!(analyze(""), (0, 0, 0));
+}
+"#,
+    },
+];
@@ -0,0 +1,514 @@
+// Flashcards
+// Turns the lesson summaries and the questions.md summary table into
+// flashcards and quizzes you on the ones that are due. How well you remember
+// a card decides when you see it again (the SM-2 algorithm): cards you know
+// come back after days, then weeks; cards you forgot come back tomorrow.
+// To compile and run: rustc flashcards.rs && ./flashcards
+//   ./flashcards                 review the cards due today
+//   ./flashcards --lesson 3      review only lesson 3's cards
+//   ./flashcards --stats         how many cards are new, due and learned
+//   ./flashcards --list          every card and when it is due
+//   ./flashcards --export FILE   write every card to FILE as CSV for Anki
+//   --schedule FILE              where due dates are kept (default: .flashcards)
+//
+// Run it from this folder or from the top of the repository.
+// Tests: rustc --test flashcards.rs -o flashcards-tests && ./flashcards-tests
+
+mod args;
+mod lessons;
+mod prompt;
+
+use args::take_option;
+use lessons::Lesson;
+use prompt::{ask_line, Prompt};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_SCHEDULE: &str = ".flashcards";
+const NEW_CARDS_PER_DAY: usize = 10;
+
+struct Card {
+    id: String,     // what the schedule is keyed on: where the card came from and its text
+    source: String, // "Lesson 3" or "questions.md", also the Anki tag
+    front: String,
+    back: String,
+}
+
+// SM-2 state for one card. Days are counted from 1970-01-01.
+#[derive(Clone)]
+struct Review {
+    due: i64,
+    interval: i64,    // days until the next review
+    repetitions: u32, // correct answers in a row
+    ease: f64,        // how fast the interval grows, never below 1.3
+}
+
+impl Review {
+    fn new(today: i64) -> Review {
+        Review { due: today, interval: 0, repetitions: 0, ease: 2.5 }
+    }
+
+    // SM-2: quality is 0 (blank) to 5 (perfect); below 3 counts as forgotten.
+    // A forgotten card starts its repetitions over but keeps its ease: only a
+    // successful recall says how hard the card is.
+    fn answer(&mut self, quality: u32, today: i64) {
+        if quality >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as i64,
+            };
+            self.repetitions += 1;
+            let miss = (5 - quality.min(5)) as f64;
+            self.ease = (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(1.3);
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        self.due = today + self.interval;
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let schedule_path = match take_option(&mut args, "--schedule") {
+        Ok(path) => path.unwrap_or_else(|| DEFAULT_SCHEDULE.to_string()),
+        Err(e) => usage(&e),
+    };
+    let lesson = match take_option(&mut args, "--lesson") {
+        Ok(None) => None,
+        Ok(Some(n)) => Some(n.parse::<u32>().unwrap_or_else(|_| usage("--lesson needs a lesson number"))),
+        Err(e) => usage(&e),
+    };
+    let export = take_option(&mut args, "--export").unwrap_or_else(|e| usage(&e));
+
+    let Some(root) = lessons::find_root() else {
+        fail("no lesson-1 folder here or one level up; run this from the repository");
+    };
+    let all = lessons::load_all(&root).unwrap_or_else(|e| fail(&format!("could not read the lessons: {}", e)));
+    let questions = fs::read_to_string(root.join("questions.md")).unwrap_or_default();
+    let mut cards = build_cards(&all, &questions);
+    if let Some(n) = lesson {
+        cards.retain(|card| card.source == format!("Lesson {}", n));
+        if cards.is_empty() {
+            usage(&format!("lesson {} has no summary to make cards from", n));
+        }
+    }
+
+    let today = today();
+    let mut schedule = Schedule::load(&schedule_path);
+
+    if let Some(path) = export {
+        if let Err(e) = fs::write(&path, anki_csv(&cards)) {
+            fail(&format!("could not write {}: {}", path, e));
+        }
+        println!("Wrote {} cards to {}", cards.len(), path);
+        println!("In Anki: File > Import, pick the file; the fields are Front, Back and Tags.");
+        return;
+    }
+    match args.as_slice() {
+        [] => review(&cards, &mut schedule, &schedule_path, today),
+        [flag] if flag == "--stats" => stats(&cards, &schedule, today),
+        [flag] if flag == "--list" => list(&cards, &schedule, today),
+        _ => usage("unexpected arguments"),
+    }
+}
+
+fn usage(problem: &str) -> ! {
+    eprintln!("Error: {}", problem);
+    eprintln!("Usage: flashcards [--lesson N] [--stats | --list | --export FILE] [--schedule FILE]");
+    process::exit(2);
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
+
+// CARDS
+
+fn build_cards(lessons: &[Lesson], questions: &str) -> Vec<Card> {
+    let mut cards = Vec::new();
+    for lesson in lessons {
+        let source = format!("Lesson {}", lesson.number);
+        let blocks = lesson.summary.iter().chain(lesson.key_concepts.iter());
+        for group in blocks.flat_map(|block| block.groups.iter()) {
+            // An empty "// -" line has nothing to ask about
+            for bullet in group.bullets.iter().filter(|b| !b.text.trim().is_empty()) {
+                let (front, back) = card_sides(lesson, group.heading.as_deref(), &bullet.text);
+                cards.push(Card {
+                    id: format!("{}: {}", source, bullet.text),
+                    source: source.clone(),
+                    front,
+                    back,
+                });
+            }
+        }
+    }
+
+    // | **Ownership** | Only one owner at a time; assigning moves ownership |
+    let table = questions.lines().skip_while(|l| l.trim() != "## Summary");
+    for row in table.filter(|l| l.trim().starts_with('|')) {
+        let cells: Vec<&str> = row.trim().trim_matches('|').split('|').map(str::trim).collect();
+        if let [concept, takeaway] = cells.as_slice() {
+            let concept = plain(concept);
+            if concept.is_empty() || concept == "Concept" || concept.chars().all(|c| "-: ".contains(c)) {
+                continue; // the header row and the |---|---| line under it
+            }
+            cards.push(Card {
+                id: format!("questions.md: {}", concept),
+                source: String::from("questions.md"),
+                front: format!("{}: what's the key takeaway?", concept),
+                back: plain(takeaway),
+            });
+        }
+    }
+    cards
+}
+
+// Three kinds of card, depending on the bullet:
+//   "Borrowing: Use & to create references..."  -> "Borrowing?" / the rest
+//   "Variables are immutable, use 'mut' to..."  -> fill in the blank: 'mut'
+//   anything else                               -> its first words to finish / the bullet
+fn card_sides(lesson: &Lesson, heading: Option<&str>, bullet: &str) -> (String, String) {
+    let context = match heading {
+        Some(heading) => format!("Lesson {}, {}", lesson.number, heading.trim_end_matches(':')),
+        None => format!("Lesson {}", lesson.number),
+    };
+
+    if let Some((term, rest)) = bullet.split_once(": ") {
+        if term.split_whitespace().count() <= 4 && !term.contains('(') {
+            return (format!("[{}] {}?", context, term), rest.to_string());
+        }
+    }
+
+    // "Result<T, E>" is one word here, even though it has a space in it
+    let mut words: Vec<String> = Vec::new();
+    for word in bullet.split_whitespace() {
+        match words.last_mut() {
+            Some(last) if last.matches('<').count() > last.matches('>').count() => {
+                last.push(' ');
+                last.push_str(word);
+            }
+            _ => words.push(word.to_string()),
+        }
+    }
+    if let Some(i) = words.iter().position(|w| is_code(w)) {
+        let answer = code_part(&words[i]).to_string();
+        let mut blanked = words.clone();
+        blanked[i] = words[i].replacen(&answer, "____", 1);
+        return (format!("[{}] Fill in the blank: {}", context, blanked.join(" ")), answer);
+    }
+
+    // The start of the sentence, to be finished from memory
+    let start = words[..(words.len() / 2).clamp(1, 3)].join(" ");
+    (format!("[{}] Finish the sentence: {} ...", context, start), bullet.to_string())
+}
+
+// Words that are Rust rather than English: &mut, main(), 'mut', {:?}, #[derive(Debug)]
+fn is_code(word: &str) -> bool {
+    let quoted = word.trim_end_matches([',', ';', '.']).starts_with('\'');
+    let word = code_part(word);
+    !word.is_empty() && (quoted || word.contains(['&', '<', '!', '?', '#', '{', '*']) || word.ends_with("()"))
+}
+
+// The word without the punctuation around it: "'mut'," -> "mut", "(<T>)" -> "<T>", "&)" -> "&"
+fn code_part(word: &str) -> &str {
+    let word = word.trim_end_matches([',', ';', '.']);
+    let word = match word.strip_prefix('(').and_then(|w| w.strip_suffix(')')) {
+        Some(inner) if !inner.is_empty() => inner,
+        _ if !word.contains('(') => word.trim_end_matches(')'),
+        _ if !word.contains(')') => word.trim_start_matches('('),
+        _ => word,
+    };
+    word.trim_matches('\'')
+}
+
+// Markdown cell text without ** and backslash escapes: "**Option\<T\>**" -> "Option<T>"
+fn plain(cell: &str) -> String {
+    cell.replace("**", "").replace(['\\', '`'], "")
+}
+
+// SCHEDULE
+// One line per card that has been reviewed, the id last because it can
+// contain spaces:  2026-10-25 6 2 2.50 Lesson 3: Borrowing: Use & to ...
+
+struct Schedule {
+    reviews: Vec<(String, Review)>,
+}
+
+impl Schedule {
+    // A missing or unreadable file just means nothing has been reviewed yet
+    fn load(path: &str) -> Schedule {
+        let text = fs::read_to_string(path).unwrap_or_default();
+        Schedule { reviews: text.lines().filter_map(parse_line).collect() }
+    }
+
+    fn save(&self, path: &str) -> std::io::Result<()> {
+        let mut text = String::new();
+        for (id, r) in &self.reviews {
+            text.push_str(&format!("{} {} {} {:.2} {}\n", date(r.due), r.interval, r.repetitions, r.ease, id));
+        }
+        fs::write(path, text)
+    }
+
+    fn get(&self, id: &str) -> Option<&Review> {
+        self.reviews.iter().find(|(i, _)| i == id).map(|(_, review)| review)
+    }
+
+    fn set(&mut self, id: &str, review: Review) {
+        match self.reviews.iter_mut().find(|(i, _)| i == id) {
+            Some((_, old)) => *old = review,
+            None => self.reviews.push((id.to_string(), review)),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<(String, Review)> {
+    let mut parts = line.splitn(5, ' ');
+    let due = parse_date(parts.next()?)?;
+    let interval = parts.next()?.parse().ok()?;
+    let repetitions = parts.next()?.parse().ok()?;
+    let ease = parts.next()?.parse().ok()?;
+    let id = parts.next()?.to_string();
+    Some((id, Review { due, interval, repetitions, ease }))
+}
+
+// DATES
+// Due dates are whole days, counted in UTC so a schedule file means the same
+// thing on every machine. Conversions from days-from-civil by Howard Hinnant.
+
+fn today() -> i64 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    (seconds / 86_400) as i64
+}
+
+// 20_386 -> "2025-10-26"
+fn date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// "2025-10-26" -> 20_386
+fn parse_date(text: &str) -> Option<i64> {
+    let mut parts = text.split('-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * mp + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+// COMMANDS
+
+fn review(cards: &[Card], schedule: &mut Schedule, path: &str, today: i64) {
+    // Overdue cards first (oldest first), then up to NEW_CARDS_PER_DAY new ones
+    let mut due: Vec<(&Card, Review)> = cards
+        .iter()
+        .filter_map(|card| schedule.get(&card.id).filter(|r| r.due <= today).map(|r| (card, r.clone())))
+        .collect();
+    due.sort_by_key(|(_, review)| review.due);
+    let new = cards.iter().filter(|card| schedule.get(&card.id).is_none());
+    due.extend(new.take(NEW_CARDS_PER_DAY).map(|card| (card, Review::new(today))));
+
+    if due.is_empty() {
+        println!("Nothing to review today. Come back tomorrow (see --stats).");
+        return;
+    }
+    println!("{} card(s) to review. Press Ctrl+D to stop; progress is saved after every card.", due.len());
+
+    for (n, (card, mut review)) in due.into_iter().enumerate() {
+        println!("\nCard {} ({})", n + 1, card.source);
+        println!("  {}", card.front);
+        if ask_line("Press Enter to see the answer").is_none() {
+            break;
+        }
+        println!("  {}", card.back);
+        let quality = Prompt::<u32>::new("How well did you know it? 0 = not at all, 3 = with effort, 5 = easily")
+            .check(|q| *q <= 5, "answer with a number from 0 to 5")
+            .ask();
+        let Some(quality) = quality else { break };
+
+        review.answer(quality, today);
+        println!("  Next review: {} (in {} day(s))", date(review.due), review.interval);
+        schedule.set(&card.id, review);
+        if let Err(e) = schedule.save(path) {
+            fail(&format!("could not save {}: {}", path, e));
+        }
+    }
+    println!("\nDone for now.");
+}
+
+fn stats(cards: &[Card], schedule: &Schedule, today: i64) {
+    let reviews: Vec<&Review> = cards.iter().filter_map(|card| schedule.get(&card.id)).collect();
+    let due = reviews.iter().filter(|r| r.due <= today).count();
+    let learned = reviews.iter().filter(|r| r.interval >= 21).count();
+    let new = cards.len() - reviews.len();
+    println!("{} cards: {} new, {} due today, {} learned (next review 3+ weeks away)", cards.len(), new, due, learned);
+    if let Some(next) = reviews.iter().map(|r| r.due).filter(|&d| d > today).min() {
+        println!("After today, the next card is due on {}.", date(next));
+    }
+}
+
+// Written with writeln! rather than println!, so `--list | head` stops
+// quietly when head closes the pipe instead of panicking
+fn list(cards: &[Card], schedule: &Schedule, today: i64) {
+    let mut out = io::stdout().lock();
+    for card in cards {
+        let when = match schedule.get(&card.id) {
+            None => String::from("new"),
+            Some(r) if r.due <= today => String::from("due"),
+            Some(r) => date(r.due),
+        };
+        if writeln!(out, "{:<10}  {:<12}  {}", when, card.source, card.front).is_err() {
+            return;
+        }
+    }
+}
+
+// Anki reads these header lines (Anki 2.1.54 and newer) so no import
+// settings have to be changed by hand; older versions treat them as comments.
+fn anki_csv(cards: &[Card]) -> String {
+    let mut out = String::from("#separator:comma\n#html:false\n#columns:Front,Back,Tags\n#tags column:3\n");
+    for card in cards {
+        let tag = format!("rust-learning::{}", card.source.replace(' ', "-").replace(".md", ""));
+        out.push_str(&format!("{},{},{}\n", csv_field(&card.front), csv_field(&card.back), csv_field(&tag)));
+    }
+    out
+}
+
+// Quote every field and double any quotes inside it
+fn csv_field(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lessons::Bullet;
+    use std::path::PathBuf;
+
+    const SOURCE: &str = "// Lesson 3: Ownership\n\
+        fn main() {\n\
+            println!(\"\\n--- Borrowing ---\");\n\
+        }\n\
+        // LESSON 3 SUMMARY:\n\
+        // - Borrowing: Use & to create references\n\
+        // - Variables are immutable, use 'mut' to change them\n\
+        // - Each value has exactly one owner at a time\n";
+
+    fn lesson() -> Lesson {
+        lessons::parse(3, PathBuf::from("lesson-3/lesson3.rs"), SOURCE.to_string())
+    }
+
+    #[test]
+    fn a_correct_answer_grows_the_interval() {
+        let mut review = Review::new(100);
+        review.answer(4, 100);
+        assert_eq!((review.interval, review.repetitions, review.due), (1, 1, 101));
+        review.answer(4, 101);
+        assert_eq!((review.interval, review.repetitions, review.due), (6, 2, 107));
+        // Ease is still 2.5 here: a 4 neither raises nor lowers it
+        review.answer(5, 107);
+        assert_eq!((review.interval, review.repetitions, review.due), (15, 3, 122));
+    }
+
+    #[test]
+    fn ease_follows_the_sm2_formula_on_a_recall() {
+        let mut review = Review::new(0);
+        review.answer(5, 0);
+        assert!((review.ease - 2.6).abs() < 1e-9, "{}", review.ease);
+        review.answer(3, 1);
+        assert!((review.ease - 2.46).abs() < 1e-9, "{}", review.ease);
+    }
+
+    #[test]
+    fn forgetting_resets_repetitions_but_keeps_the_ease() {
+        let mut review = Review::new(0);
+        review.answer(5, 0);
+        review.answer(5, 1);
+        let ease = review.ease;
+        review.answer(1, 7);
+        assert_eq!((review.interval, review.repetitions, review.due), (1, 0, 8));
+        assert_eq!(review.ease, ease);
+        review.answer(0, 8);
+        assert_eq!(review.ease, ease);
+    }
+
+    #[test]
+    fn ease_never_drops_below_1_3() {
+        let mut review = Review::new(0);
+        for day in 0..20 {
+            review.answer(3, day);
+        }
+        assert!((review.ease - 1.3).abs() < 1e-9, "{}", review.ease);
+    }
+
+    #[test]
+    fn each_kind_of_bullet_makes_its_kind_of_card() {
+        let cards = build_cards(&[lesson()], "");
+        let sides: Vec<(&str, &str)> = cards.iter().map(|c| (c.front.as_str(), c.back.as_str())).collect();
+        assert_eq!(sides[0], ("[Lesson 3] Borrowing?", "Use & to create references"));
+        assert_eq!(sides[1], ("[Lesson 3] Fill in the blank: Variables are immutable, use '____' to change them", "mut"));
+        assert_eq!(sides[2].1, "Each value has exactly one owner at a time");
+        assert!(sides[2].0.contains("Finish the sentence: Each value has ..."), "{}", sides[2].0);
+    }
+
+    #[test]
+    fn empty_bullets_are_skipped() {
+        let mut lesson = lesson();
+        let summary = lesson.summary.as_mut().expect("the test lesson has a summary");
+        summary.groups[0].bullets.push(Bullet { number: 9, text: String::new(), section: None });
+        summary.groups[0].bullets.push(Bullet { number: 10, text: String::from("  "), section: None });
+        assert_eq!(build_cards(&[lesson], "").len(), 3);
+    }
+
+    #[test]
+    fn questions_table_rows_become_cards() {
+        let questions = "## Summary\n\n| Concept | Key takeaway |\n|---|---|\n| **Option\\<T\\>** | `Some` or `None` |\n";
+        let cards = build_cards(&[], questions);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].front, "Option<T>: what's the key takeaway?");
+        assert_eq!(cards[0].back, "Some or None");
+    }
+
+    #[test]
+    fn dates_round_trip() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(20_387), "2025-10-26");
+        for days in [-1, 0, 59, 60, 11_016, 20_387, 30_000] {
+            assert_eq!(parse_date(&date(days)), Some(days));
+        }
+        assert_eq!(parse_date("2025-13-01"), None);
+    }
+
+    #[test]
+    fn schedule_lines_round_trip() {
+        let line = "2025-10-26 6 2 2.50 Lesson 3: Borrowing: Use & to create references";
+        let (id, review) = parse_line(line).expect("a valid line");
+        assert_eq!(id, "Lesson 3: Borrowing: Use & to create references");
+        assert_eq!((review.due, review.interval, review.repetitions), (20_387, 6, 2));
+    }
+
+    #[test]
+    fn csv_fields_double_their_quotes() {
+        assert_eq!(csv_field("say \"hi\", then go"), "\"say \"\"hi\"\", then go\"");
+    }
+}
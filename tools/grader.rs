@@ -0,0 +1,418 @@
+// Exercise Auto-Grader
+// Checks your solution to a lesson exercise against hidden tests.
+// Your file is compiled with rustc in a temporary directory together with the
+// exercise's tests, run under CPU-time and memory limits, and the result is
+// printed as a JSON report: each test's pass/fail, compiler warnings, and
+// clippy-style hints about the code itself.
+// To compile and run: rustc grader.rs && ./grader 2.3 my_max.rs
+//   ./grader --list            show the exercises that can be graded
+//   ./grader 2.3 my_max.rs     grade a solution (exit code 0 means all tests passed)
+//   --progress FILE            where progress is kept (default: .progress)
+//
+// The report also says how many hints (see hint.rs) you used, and a passing
+// solution is marked as passed in the progress file.
+//
+// A solution is a normal .rs file that defines the function in the exercise's
+// signature; it may have its own main() for trying things out.
+//
+// Tests: rustc --test grader.rs -o grader-tests && ./grader-tests
+
+mod args;
+mod exercises;
+mod progress;
+mod sandbox;
+
+use args::take_option;
+use exercises::{Exercise, EXERCISES};
+use progress::Progress;
+use sandbox::{Limits, Sandbox, Status};
+use std::env;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+const CPU_SECONDS: u64 = 5;
+const MEMORY_BYTES: u64 = 256 * 1024 * 1024;
+
+struct TestResult {
+    name: String,
+    passed: bool,
+    message: String, // why it failed (empty when it passed)
+}
+
+struct Hint {
+    lint: &'static str,
+    line: usize,
+    message: String,
+}
+
+struct Report {
+    status: &'static str, // passed, failed, compile_error, time_limit, memory_limit, output_limit, crashed
+    compile_errors: Vec<String>,
+    warnings: Vec<String>,
+    hints: Vec<Hint>,
+    tests: Vec<TestResult>,
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let progress_path = match take_option(&mut args, "--progress") {
+        Ok(path) => path.unwrap_or_else(|| progress::DEFAULT_PATH.to_string()),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(2);
+        }
+    };
+
+    if args.iter().any(|a| a == "--list") {
+        for exercise in EXERCISES.iter() {
+            println!("{:<5} {}", exercise.id, exercise.text);
+            println!("      {}", exercise.signature);
+        }
+        return;
+    }
+
+    let (exercise, path) = match args.as_slice() {
+        [id, path] => match exercises::find(id) {
+            Some(exercise) => (exercise, path),
+            None => {
+                eprintln!("Error: there is no exercise {} (see --list)", id);
+                process::exit(2);
+            }
+        },
+        _ => {
+            eprintln!("Usage: grader <exercise> <solution.rs> [--progress FILE]   (or grader --list)");
+            process::exit(2);
+        }
+    };
+
+    let solution = match fs::read_to_string(path) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("Error: could not read {}: {}", path, e);
+            process::exit(2);
+        }
+    };
+
+    let report = match grade(exercise, &solution) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Error: could not run rustc ({}). Is Rust installed and on your PATH?", e);
+            process::exit(2);
+        }
+    };
+
+    let mut progress = Progress::load(&progress_path);
+    let entry = progress.get(exercise.id);
+    println!("{}", to_json(exercise, path, &report, entry.hints_used));
+    if report.status != "passed" {
+        // On stderr, so the JSON on stdout stays machine-readable
+        if entry.hints_shown < exercise.hints.len() {
+            eprintln!("Stuck? Run ./hint {} for a hint.", exercise.id);
+        }
+        process::exit(1);
+    }
+
+    progress.entry(exercise.id).passed = true;
+    if let Err(e) = progress.save(&progress_path) {
+        eprintln!("Could not save progress: {}", e);
+    }
+}
+
+fn grade(exercise: &Exercise, solution: &str) -> std::io::Result<Report> {
+    let sandbox = Sandbox::new()?;
+    let limits = Limits {
+        run_time: Duration::from_secs(CPU_SECONDS * 2),
+        cpu_seconds: Some(CPU_SECONDS),
+        memory_bytes: Some(MEMORY_BYTES),
+        ..Limits::default()
+    };
+
+    // The solution is pasted into the harness with include!, so the hidden
+    // tests can see its functions (even private ones) through `use super::*`
+    sandbox.write("solution.rs", solution)?;
+    sandbox.write("harness.rs", &harness(exercise))?;
+
+    let mut hints = style_hints(solution);
+    let compiled = sandbox.compile("harness.rs", &["--test"], &limits)?;
+    if !compiled.success {
+        let name = function_name(exercise.signature);
+        if !defines_function(solution, name) {
+            hints.insert(0, Hint {
+                lint: "signature",
+                line: 0,
+                message: format!("the solution must define `{}`", exercise.signature),
+            });
+        }
+        return Ok(Report {
+            status: "compile_error",
+            compile_errors: compiled.errors,
+            warnings: from_solution(compiled.warnings),
+            hints,
+            tests: Vec::new(),
+        });
+    }
+
+    // One test at a time, so a test that hangs or crashes can be pinned down
+    let run = sandbox.run(&["--test-threads=1"], "", &limits)?;
+    let tests = test_results(exercise, &run.stdout, &run.status, &run.stderr);
+    let warnings = from_solution(compiled.warnings);
+
+    let status = match run.status {
+        Status::Exited(0) | Status::Exited(101) if tests.iter().all(|t| t.passed) => "passed",
+        Status::Exited(_) => "failed",
+        _ if ran_out_of_memory(&run.status, &run.stderr) => "memory_limit",
+        Status::TimedOut | Status::Crashed(Some(sandbox::SIGXCPU)) | Status::Crashed(Some(sandbox::SIGKILL)) => {
+            "time_limit"
+        }
+        Status::TooMuchOutput => "output_limit",
+        Status::Crashed(_) => "crashed",
+    };
+
+    Ok(Report {
+        status,
+        compile_errors: Vec::new(),
+        warnings,
+        hints,
+        tests,
+    })
+} // sandbox is dropped here and its temporary directory is deleted
+
+// Only warnings about the learner's own code are worth reporting
+fn from_solution(warnings: Vec<String>) -> Vec<String> {
+    warnings.into_iter().filter(|w| w.contains("(solution.rs:")).collect()
+}
+
+fn harness(exercise: &Exercise) -> String {
+    format!(
+        "#![allow(dead_code)]\n\ninclude!(\"solution.rs\");\n\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n{}}}\n",
+        exercise.tests
+    )
+}
+
+// "fn find_max(numbers: &[i32]) -> Option<i32>" -> "find_max"
+fn function_name(signature: &str) -> &str {
+    let rest = signature.trim_start_matches("fn ");
+    rest.split(['(', '<']).next().unwrap_or(rest).trim()
+}
+
+// Does the solution have `fn name(` or `fn name<`? A plain substring search
+// would also accept `fn find_maxx(` or `fn my_find_max(` for find_max.
+fn defines_function(solution: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    solution.match_indices("fn").any(|(i, _)| {
+        if solution[..i].chars().next_back().is_some_and(is_ident) {
+            return false; // the end of a longer word, like `my_fn`
+        }
+        let after_fn = &solution[i + 2..];
+        let rest = after_fn.trim_start();
+        if rest.len() == after_fn.len() {
+            return false; // no space after fn, like `fnord`
+        }
+        match rest.strip_prefix(name) {
+            Some(after) => matches!(after.trim_start().chars().next(), Some('(' | '<')),
+            None => false,
+        }
+    })
+}
+
+// Match the test runner's output back to the hidden tests:
+//   test hidden_tests::finds_max ... ok
+//   test hidden_tests::empty_vector_has_no_max ... FAILED
+// plus a "---- hidden_tests::<name> stdout ----" section for each failure.
+fn test_results(exercise: &Exercise, stdout: &str, status: &Status, stderr: &str) -> Vec<TestResult> {
+    exercise
+        .test_names()
+        .into_iter()
+        .map(|name| {
+            let line = format!("test hidden_tests::{} ... ", name);
+            let outcome = stdout
+                .lines()
+                .find_map(|l| l.strip_prefix(line.as_str()))
+                .map(|rest| rest.trim());
+            let (passed, message) = match outcome {
+                Some("ok") => (true, String::new()),
+                // The runner announced the test but never finished it:
+                // this is the test that was running when the program was stopped
+                Some("") => (false, stopped_message(status, stderr)),
+                Some(_) => (false, failure_message(stdout, name)),
+                // Tests run in alphabetical order, so later ones never started
+                None => (false, String::from("not run: the tests were stopped before reaching it")),
+            };
+            TestResult { name: name.to_string(), passed, message }
+        })
+        .collect()
+}
+
+// The panic message from a failed test's section. The "thread ... panicked at
+// harness.rs:21:5" line points into the hidden tests, so it is left out,
+// along with the "note:" lines.
+fn failure_message(stdout: &str, name: &str) -> String {
+    let header = format!("---- hidden_tests::{} stdout ----", name);
+    let mut lines = stdout.lines().skip_while(|l| *l != header).skip(1);
+    let mut message = Vec::new();
+    for line in lines.by_ref() {
+        if line.starts_with("---- ") || line == "failures:" {
+            break;
+        }
+        if !line.is_empty() && !line.starts_with("note: ") && !line.starts_with("thread '") {
+            message.push(line.trim());
+        }
+    }
+    message.join("\n")
+}
+
+fn stopped_message(status: &Status, stderr: &str) -> String {
+    if ran_out_of_memory(status, stderr) {
+        return format!("stopped: used more than {} MB of memory", MEMORY_BYTES / (1024 * 1024));
+    }
+    match status {
+        Status::TimedOut | Status::Crashed(Some(sandbox::SIGXCPU)) | Status::Crashed(Some(sandbox::SIGKILL)) => {
+            format!("stopped: ran longer than {} seconds (an infinite loop?)", CPU_SECONDS)
+        }
+        Status::TooMuchOutput => String::from("stopped: printed too much output (printing in a loop?)"),
+        Status::Crashed(Some(sandbox::SIGSEGV)) => String::from("crashed: stack overflow (endless recursion?)"),
+        Status::Crashed(_) => String::from("crashed"),
+        Status::Exited(_) => String::from("did not run"),
+    }
+}
+
+// Running out of memory makes Rust abort with "memory allocation of N bytes failed"
+fn ran_out_of_memory(status: &Status, stderr: &str) -> bool {
+    *status == Status::Crashed(Some(sandbox::SIGABRT)) && stderr.contains("memory allocation")
+}
+
+// CLIPPY-STYLE HINTS
+// A few of the lints clippy would show, found by looking at the source text.
+// They are suggestions only: they never change the grade.
+
+fn style_hints(solution: &str) -> Vec<Hint> {
+    let lines: Vec<&str> = solution.lines().collect();
+    let mut hints = Vec::new();
+    let mut add = |lint, line: usize, message: &str| {
+        hints.push(Hint { lint, line: line + 1, message: message.to_string() });
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        let code = line.split("//").next().unwrap_or(line);
+        let trimmed = code.trim();
+        let is_fn = trimmed.starts_with("fn ") || trimmed.starts_with("pub fn ");
+
+        if is_fn && code.contains("&Vec<") {
+            add("clippy::ptr_arg", i, "take `&[T]` instead of `&Vec<T>`: it accepts more callers and does the same job");
+        }
+        if is_fn && code.contains("&String") {
+            add("clippy::ptr_arg", i, "take `&str` instead of `&String`: it accepts both String and string literals");
+        }
+        if code.contains(".len() == 0") || code.contains(".len() > 0") || code.contains(".len() != 0") {
+            add("clippy::len_zero", i, "use `.is_empty()` to check whether something is empty");
+        }
+        if code.contains("== true") || code.contains("== false") {
+            add("clippy::bool_comparison", i, "no need to compare a bool with true/false: use it (or `!it`) directly");
+        }
+        if trimmed.starts_with("for ") && code.contains(" in 0..") && code.contains(".len()") {
+            add("clippy::needless_range_loop", i, "loop over the items with `for item in items.iter()` instead of indexing");
+        }
+        if code.contains(".unwrap()") {
+            add("unwrap_used", i, "`.unwrap()` panics on None/Err; prefer `match`, `if let` or `?` (see lesson 5)");
+        }
+        // `return x;` as the last line of a function: the last expression is returned anyway
+        let closes_fn = lines.get(i + 1).is_some_and(|next| next.trim_end() == "}");
+        if trimmed.starts_with("return ") && trimmed.ends_with(';') && closes_fn {
+            add("clippy::needless_return", i, "drop `return` and the `;`: the last expression of a function is its result");
+        }
+    }
+    hints
+}
+
+// JSON OUTPUT
+// Written by hand so the grader needs nothing beyond the standard library
+
+fn to_json(exercise: &Exercise, path: &str, report: &Report, hints_used: usize) -> String {
+    let strings = |items: &[String]| -> String {
+        if items.is_empty() {
+            return String::from("[]");
+        }
+        let items: Vec<String> = items.iter().map(|s| format!("    {}", quote(s))).collect();
+        format!("[\n{}\n  ]", items.join(",\n"))
+    };
+    let objects = |items: Vec<String>| -> String {
+        if items.is_empty() {
+            return String::from("[]");
+        }
+        format!("[\n    {}\n  ]", items.join(",\n    "))
+    };
+
+    let tests = report
+        .tests
+        .iter()
+        .map(|t| format!("{{ \"name\": {}, \"passed\": {}, \"message\": {} }}", quote(&t.name), t.passed, quote(&t.message)))
+        .collect();
+    let hints = report
+        .hints
+        .iter()
+        .map(|h| format!("{{ \"lint\": {}, \"line\": {}, \"message\": {} }}", quote(h.lint), h.line, quote(&h.message)))
+        .collect();
+    let passed = report.tests.iter().filter(|t| t.passed).count();
+
+    let fields = [
+        ("exercise", quote(exercise.id)),
+        ("title", quote(exercise.text)),
+        ("signature", quote(exercise.signature)),
+        ("solution", quote(path)),
+        ("status", quote(report.status)),
+        ("compiled", (report.status != "compile_error").to_string()),
+        ("compile_errors", strings(&report.compile_errors)),
+        ("warnings", strings(&report.warnings)),
+        ("hints", objects(hints)),
+        ("tests", objects(tests)),
+        ("passed", passed.to_string()),
+        ("total", report.tests.len().to_string()),
+        ("hints_used", hints_used.to_string()),
+        ("hints_left", exercise.hints.len().saturating_sub(hints_used).to_string()),
+    ];
+    let fields: Vec<String> = fields.iter().map(|(key, value)| format!("  \"{}\": {}", key, value)).collect();
+    format!("{{\n{}\n}}", fields.join(",\n"))
+}
+
+fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_names_come_from_the_signature() {
+        assert_eq!(function_name("fn find_max(numbers: &[i32]) -> Option<i32>"), "find_max");
+        assert_eq!(function_name("fn largest<T: PartialOrd>(items: &[T]) -> &T"), "largest");
+    }
+
+    #[test]
+    fn a_definition_is_found_wherever_it_is() {
+        assert!(defines_function("fn find_max(numbers: &[i32]) -> Option<i32> {", "find_max"));
+        assert!(defines_function("pub fn find_max (v: &[i32])", "find_max"));
+        assert!(defines_function("fn main() {}\n\nfn  find_max<'a>(v: &'a [i32])", "find_max"));
+    }
+
+    #[test]
+    fn a_longer_name_is_not_the_function() {
+        assert!(!defines_function("fn find_maxx(numbers: &[i32]) -> Option<i32> {", "find_max"));
+        assert!(!defines_function("fn my_find_max(numbers: &[i32]) {", "find_max"));
+        assert!(!defines_function("fnfind_max(numbers: &[i32])", "find_max"));
+        assert!(!defines_function("let f = find_max(&v);", "find_max"));
+    }
+}
@@ -0,0 +1,301 @@
+// Guessing Game - reference solution for Lesson 2, exercise 4
+// ("Create a simple guessing game using loops and conditionals")
+// To compile and run: rustc guess.rs && ./guess (or guess.exe on Windows)
+//
+// Options:
+//   --seed N          use a fixed secret-number sequence (same seed = same game)
+//   --difficulty D    easy, normal or hard (otherwise you are asked)
+//   --scores FILE     where high scores are kept (default: .guess_scores)
+//
+// Because --seed makes the game repeatable, a whole game can be scripted:
+//   printf 'easy\n10\n15\n13\n14\nn\n' | ./guess --seed 42 --scores /tmp/scores
+// (seed 42 on easy picks 14, so this wins on the 4th guess). tests/guess_games.rs
+// plays whole games like this:
+//   rustc --test tests/guess_games.rs -o guess-games && ./guess-games
+
+mod prompt;
+
+use prompt::{Prompt, YesNo};
+use std::cmp::Ordering;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::process;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let options = match parse_args(env::args().skip(1).collect()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            eprintln!("Usage: guess [--seed N] [--difficulty easy|normal|hard] [--scores FILE]");
+            process::exit(2);
+        }
+    };
+
+    println!("=== Guessing Game ===");
+
+    let mut rng = Rng::new(options.seed.unwrap_or_else(seed_from_clock));
+    let mut scores = HighScores::load(&options.scores_path);
+
+    loop {
+        // A --difficulty flag skips the question
+        let difficulty = match options.difficulty {
+            Some(d) => d,
+            None => match Prompt::new("Difficulty (easy, normal, hard)")
+                .default(Difficulty::Normal)
+                .ask()
+            {
+                Some(d) => d,
+                None => break, // input ended
+            },
+        };
+
+        let secret = rng.range(1, difficulty.max());
+        let finished = play_round(difficulty, secret, &mut scores);
+
+        if let Err(e) = scores.save(&options.scores_path) {
+            eprintln!("Could not save high scores: {}", e);
+        }
+
+        if !finished {
+            break; // input ended in the middle of a game
+        }
+
+        let again = Prompt::new("Play again? (y/n)").default(YesNo(false)).ask();
+        if again != Some(YesNo(true)) {
+            break;
+        }
+    }
+
+    println!("Thanks for playing!");
+}
+
+// Plays one game. Returns false if input ran out before the game ended.
+fn play_round(difficulty: Difficulty, secret: u32, scores: &mut HighScores) -> bool {
+    let max = difficulty.max();
+    let allowed = difficulty.attempts();
+    println!(
+        "\nI'm thinking of a number between 1 and {}. You have {} attempts.",
+        max, allowed
+    );
+
+    let mut guesses: Vec<u32> = Vec::new();
+
+    while guesses.len() < allowed as usize {
+        let question = format!("Attempt {}/{}", guesses.len() + 1, allowed);
+        let guess = match Prompt::new(&question)
+            .check(move |n| (1..=max).contains(n), &format!("Pick a number from 1 to {}", max))
+            .ask()
+        {
+            Some(n) => n,
+            None => return false,
+        };
+
+        if guesses.contains(&guess) {
+            println!("You already tried {} - that one doesn't count.", guess);
+            continue;
+        }
+        guesses.push(guess);
+
+        match guess.cmp(&secret) {
+            Ordering::Less => println!("{} is too small!", guess),
+            Ordering::Greater => println!("{} is too big!", guess),
+            Ordering::Equal => {
+                println!("🎉 {} is correct! You got it in {} attempt(s).", secret, guesses.len());
+                report_score(difficulty, guesses.len() as u32, scores);
+                return true;
+            }
+        }
+    }
+
+    println!("Out of attempts! The number was {}.", secret);
+    println!("Your guesses: {:?}", guesses);
+    true
+}
+
+fn report_score(difficulty: Difficulty, attempts: u32, scores: &mut HighScores) {
+    match scores.best(difficulty) {
+        Some(best) if attempts >= best => {
+            println!("High score for {} is {} attempt(s).", difficulty, best);
+        }
+        _ => {
+            println!("New high score for {}!", difficulty);
+            scores.record(difficulty, attempts);
+        }
+    }
+}
+
+// COMMAND-LINE OPTIONS
+
+struct Options {
+    seed: Option<u64>,
+    difficulty: Option<Difficulty>,
+    scores_path: String,
+}
+
+fn parse_args(args: Vec<String>) -> Result<Options, String> {
+    let mut options = Options {
+        seed: None,
+        difficulty: None,
+        scores_path: String::from(".guess_scores"),
+    };
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        // Every option takes a value, so grab it right away
+        let value = args
+            .next()
+            .ok_or_else(|| format!("{} needs a value", flag))?;
+
+        match flag.as_str() {
+            "--seed" => {
+                let seed = value
+                    .parse()
+                    .map_err(|_| format!("--seed needs a whole number, got '{}'", value))?;
+                options.seed = Some(seed);
+            }
+            "--difficulty" => options.difficulty = Some(value.parse()?),
+            "--scores" => options.scores_path = value,
+            _ => return Err(format!("unknown option '{}'", flag)),
+        }
+    }
+
+    Ok(options)
+}
+
+// DIFFICULTY LEVELS
+
+#[derive(Clone, Copy, PartialEq)]
+enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    // Largest possible secret number
+    fn max(self) -> u32 {
+        match self {
+            Difficulty::Easy => 20,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 1000,
+        }
+    }
+
+    fn attempts(self) -> u32 {
+        match self {
+            Difficulty::Easy => 6,
+            Difficulty::Normal => 7,
+            Difficulty::Hard => 10,
+        }
+    }
+}
+
+// FromStr lets "hard".parse::<Difficulty>() work, so Prompt can ask for one
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        match s.to_lowercase().as_str() {
+            "easy" | "e" => Ok(Difficulty::Easy),
+            "normal" | "n" => Ok(Difficulty::Normal),
+            "hard" | "h" => Ok(Difficulty::Hard),
+            _ => Err(String::from("choose easy, normal or hard")),
+        }
+    }
+}
+
+// Display is what Prompt shows as the default, e.g. "[normal]"
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// HIGH SCORES
+// Stored as plain text, one "difficulty attempts" pair per line:
+//   easy 3
+//   hard 8
+
+struct HighScores {
+    best: Vec<(Difficulty, u32)>,
+}
+
+impl HighScores {
+    // A missing or unreadable file just means no high scores yet
+    fn load(path: &str) -> HighScores {
+        let text = fs::read_to_string(path).unwrap_or_default();
+        let best = text
+            .lines()
+            .filter_map(|line| {
+                let (name, attempts) = line.split_once(' ')?;
+                Some((name.parse().ok()?, attempts.trim().parse().ok()?))
+            })
+            .collect();
+        HighScores { best }
+    }
+
+    fn save(&self, path: &str) -> std::io::Result<()> {
+        let text: String = self
+            .best
+            .iter()
+            .map(|(difficulty, attempts)| format!("{} {}\n", difficulty, attempts))
+            .collect();
+        fs::write(path, text)
+    }
+
+    fn best(&self, difficulty: Difficulty) -> Option<u32> {
+        self.best
+            .iter()
+            .find(|(d, _)| *d == difficulty)
+            .map(|&(_, attempts)| attempts)
+    }
+
+    fn record(&mut self, difficulty: Difficulty, attempts: u32) {
+        self.best.retain(|(d, _)| *d != difficulty);
+        self.best.push((difficulty, attempts));
+    }
+}
+
+// RANDOM NUMBERS
+// A tiny SplitMix64 generator: not good enough for cryptography, but plenty
+// for a game, and the same seed always produces the same numbers.
+
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // wrapping_* because overflow is expected here (it would panic in debug builds)
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Number in low..=high (inclusive, like 1..=100)
+    fn range(&mut self, low: u32, high: u32) -> u32 {
+        let span = (high - low + 1) as u64;
+        low + (self.next_u64() % span) as u32
+    }
+}
+
+fn seed_from_clock() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
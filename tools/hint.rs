@@ -0,0 +1,98 @@
+// Exercise Hints
+// Stuck on an exercise? Each run reveals the next hint, starting with a small
+// nudge, then a pointer to the lesson code that helps, then a partial solution.
+// Hints you already saw are shown again above the new one, and the number you
+// used is kept in the progress file (the grader reads it too). --reset hides
+// the hints, but the grader still counts the ones you used before.
+// To compile and run: rustc hint.rs && ./hint 3.4
+//   ./hint --list           show every exercise and how many hints you used
+//   ./hint 3.4              reveal the next hint for exercise 3.4
+//   ./hint 3.4 --reset      hide the hints for 3.4 again
+//   --progress FILE         where progress is kept (default: .progress)
+
+mod args;
+mod exercises;
+mod progress;
+
+use args::take_option;
+use exercises::{Exercise, EXERCISES};
+use progress::Progress;
+use std::env;
+use std::process;
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let progress_path = match take_option(&mut args, "--progress") {
+        Ok(path) => path.unwrap_or_else(|| progress::DEFAULT_PATH.to_string()),
+        Err(e) => usage(&e),
+    };
+    let mut progress = Progress::load(&progress_path);
+
+    if args.iter().any(|a| a == "--list") {
+        for exercise in EXERCISES.iter() {
+            let entry = progress.get(exercise.id);
+            let done = if entry.passed { "  (passed)" } else { "" };
+            println!(
+                "{:<5} hints {}/{}  {}{}",
+                exercise.id,
+                entry.hints_used,
+                exercise.hints.len(),
+                exercise.text,
+                done
+            );
+        }
+        return;
+    }
+
+    let reset = args.iter().any(|a| a == "--reset");
+    args.retain(|a| a != "--reset");
+    let exercise = match args.as_slice() {
+        [id] => match exercises::find(id) {
+            Some(exercise) => exercise,
+            None => usage(&format!("there is no exercise {} (see --list)", id)),
+        },
+        _ => usage("pick one exercise, e.g. ./hint 3.4"),
+    };
+
+    let entry = progress.entry(exercise.id);
+    if reset {
+        entry.hide_hints();
+        println!("Hints for exercise {} are hidden again.", exercise.id);
+    } else {
+        let before = entry.reveal_next(exercise.hints.len());
+        show_hints(exercise, entry.hints_shown, before);
+    }
+
+    if let Err(e) = progress.save(&progress_path) {
+        eprintln!("Could not save progress: {}", e);
+    }
+}
+
+// Print hints 1..=shown; the ones after `seen` are new this time
+fn show_hints(exercise: &Exercise, shown: usize, seen: usize) {
+    println!("Exercise {}: {}", exercise.id, exercise.text);
+    println!("Your function: {}", exercise.signature);
+
+    for (i, hint) in exercise.hints.iter().take(shown).enumerate() {
+        let label = if i >= seen { "new" } else { "seen" };
+        println!("\nHint {} of {} ({}):", i + 1, exercise.hints.len(), label);
+        for line in hint.lines() {
+            println!("    {}", line);
+        }
+    }
+
+    println!();
+    if shown == seen {
+        println!("That was the last hint. Check your solution with: ./grader {} <file.rs>", exercise.id);
+    } else if shown < exercise.hints.len() {
+        println!("Still stuck? Run ./hint {} again for the next hint.", exercise.id);
+    } else {
+        println!("That was the last hint: the rest is up to you!");
+    }
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("Error: {}", error);
+    eprintln!("Usage: hint [--list] [<exercise> [--reset]] [--progress FILE]");
+    process::exit(2);
+}
@@ -0,0 +1,54 @@
+// Shared module: HTML Helpers
+// Builds standalone HTML pages: the styles are inlined, so a page works when
+// opened straight from disk, with nothing loaded from the internet.
+//
+// To use it from a program in this folder, add `mod html;` at the top, then:
+//     let body = format!("<p>{}</p>", html::escape("Vec<T> & friends"));
+//     fs::write("page.html", html::page("My page", &body))?;
+
+#![allow(dead_code)] // not every program uses every helper
+
+// Make text safe to put between tags or inside an attribute
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// A whole document around `body`
+pub fn page(title: &str, body: &str) -> String {
+    page_with(title, body, "")
+}
+
+// Same as page(), plus extra <head> content such as a <script> or <style>
+pub fn page_with(title: &str, body: &str, head: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>\n{}</style>\n{}</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        head,
+        body
+    )
+}
+
+const STYLE: &str = "body { font-family: system-ui, sans-serif; line-height: 1.5; margin: 0 auto; max-width: 70rem; padding: 1rem 2rem; color: #222; }
+a { color: #b7410e; }
+code, pre { font-family: ui-monospace, Menlo, Consolas, monospace; font-size: 0.9rem; }
+pre { background: #f6f4f2; padding: 0.75rem; overflow-x: auto; border-radius: 4px; }
+h1, h2, h3 { line-height: 1.2; }
+h2 { border-bottom: 1px solid #ddd; padding-bottom: 0.25rem; margin-top: 2rem; }
+nav { margin-bottom: 1rem; }
+nav a { margin-right: 1rem; }
+.muted { color: #777; font-size: 0.9rem; }
+";
@@ -0,0 +1,258 @@
+// Lesson Linter
+// Checks that every lesson follows the lesson file format (see lessons.rs):
+// header, compile line, section banners, exercises, a "Next lesson will cover"
+// line that matches the next lesson's title (the last lesson may leave it out),
+// and a summary block whose bullets each name the section they sum up.
+// Comments are also spell-checked against tools/dictionary.txt.
+// To compile and run: rustc lesson_lint.rs -o lesson-lint && ./lesson-lint
+//   ./lesson-lint           lint every lesson (exit code 1 if anything is wrong)
+//   ./lesson-lint 3         lint only lesson 3
+//   ./lesson-lint --words   list the unknown words, one per line, ready to
+//                           paste into the dictionary once you've checked them
+//
+// Run it from this folder or from the top of the repository.
+
+mod lessons;
+
+use lessons::Lesson;
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+const DICTIONARY: &str = "tools/dictionary.txt";
+
+// One thing to fix, reported as "file:line: message"
+struct Problem {
+    line: usize,
+    message: String,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let words_only = args.iter().any(|a| a == "--words");
+    let mut only = Vec::new();
+    for arg in args.iter().filter(|a| *a != "--words") {
+        match arg.parse::<u32>() {
+            Ok(n) => only.push(n),
+            Err(_) => {
+                eprintln!("Error: '{}' is not a lesson number", arg);
+                eprintln!("Usage: lesson-lint [--words] [lesson numbers...]");
+                process::exit(2);
+            }
+        }
+    }
+
+    let Some(root) = lessons::find_root() else {
+        eprintln!("Error: no lesson-1 folder here or one level up; run this from the repository");
+        process::exit(2);
+    };
+    let all = match lessons::load_all(&root) {
+        Ok(all) => all,
+        Err(e) => {
+            eprintln!("Error: could not read the lessons: {}", e);
+            process::exit(2);
+        }
+    };
+    let dictionary = load_dictionary(&root.join(DICTIONARY));
+
+    let mut unknown = BTreeSet::new();
+    let mut total = 0;
+    for lesson in all.iter().filter(|l| only.is_empty() || only.contains(&l.number)) {
+        let next_title = all.iter().find(|l| l.number == lesson.number + 1).and_then(|l| l.title());
+        let mut problems = check_format(lesson, next_title);
+        for (line, word) in misspelled(lesson, &dictionary) {
+            problems.push(Problem {
+                line,
+                message: format!("unknown word \"{}\" (add it to {} if it is spelled right)", word, DICTIONARY),
+            });
+            unknown.insert(word);
+        }
+
+        problems.sort_by_key(|p| p.line);
+        if !words_only {
+            for problem in &problems {
+                println!("{}:{}: {}", lesson.path.display(), problem.line, problem.message);
+            }
+        }
+        total += problems.len();
+    }
+
+    if words_only {
+        for word in &unknown {
+            println!("{}", word);
+        }
+        return;
+    }
+    if total == 0 {
+        println!("All lessons look good.");
+    } else {
+        println!("\n{} problem(s) found", total);
+        process::exit(1);
+    }
+}
+
+// FORMAT CHECKS
+
+fn check_format(lesson: &Lesson, next_title: Option<&str>) -> Vec<Problem> {
+    let n = lesson.number;
+    let mut problems = Vec::new();
+    let mut report = |line: usize, message: String| problems.push(Problem { line, message });
+
+    match &lesson.header {
+        None => report(1, format!("the first line should be `// Lesson {}: Title`", n)),
+        Some((number, _)) if *number != n => {
+            report(1, format!("the header says Lesson {}, but this is lesson {}", number, n))
+        }
+        Some(_) => {}
+    }
+
+    let expected_compile = format!("rustc lesson{}.rs && ./lesson{}", n, n);
+    match &lesson.compile_line {
+        None => report(2, format!("missing the `// To compile and run: {}` line", expected_compile)),
+        Some(line) if !line.text.starts_with(&expected_compile) => report(
+            line.number,
+            format!("the compile line should start with `{}`, not `{}`", expected_compile, line.text),
+        ),
+        Some(_) => {}
+    }
+
+    // The opening banner is optional, but when there is one it must agree with the header
+    if let (Some(banner), Some(title)) = (&lesson.banner, lesson.title()) {
+        let expected = format!("Lesson {}: {}", n, title);
+        if banner.text != expected {
+            report(banner.number, format!("the banner says \"{}\", but the header says \"{}\"", banner.text, expected));
+        }
+    }
+
+    if lesson.sections.is_empty() {
+        report(1, String::from("no `--- Section ---` banners: print one at the start of each section"));
+    }
+
+    match &lesson.exercises {
+        None => report(1, String::from("no exercises: add an `// EXERCISES FOR YOU TO TRY:` list")),
+        Some(exercises) if exercises.items.is_empty() => {
+            report(exercises.line, String::from("the exercises list has no numbered exercises"))
+        }
+        Some(exercises) => {
+            for (i, item) in exercises.items.iter().enumerate() {
+                if !item.text.starts_with(&format!("{}. ", i + 1)) {
+                    report(item.number, format!("exercise should be numbered {}", i + 1));
+                }
+            }
+        }
+    }
+
+    let expected_end = format!("End of Lesson {}", n);
+    match &lesson.end_banner {
+        None => report(1, format!("missing the `--- {} ---` banner", expected_end)),
+        Some(end) if end.text != expected_end => {
+            report(end.number, format!("the end banner should say \"{}\", not \"{}\"", expected_end, end.text))
+        }
+        Some(_) => {}
+    }
+
+    match &lesson.next {
+        // The last lesson ends the course, so it has no next lesson to name
+        None if next_title.is_none() => {}
+        None => report(1, String::from("missing the \"Next lesson will cover: ...\" line")),
+        Some(next) => {
+            let line_text = &lesson.source.lines().nth(next.number - 1).unwrap_or_default();
+            if !line_text.contains("Next lesson will cover: ") {
+                report(next.number, String::from("write this as \"Next lesson will cover: ...\""));
+            }
+            // The last lesson can announce one that isn't written yet
+            if let Some(title) = next_title {
+                if next.text != title {
+                    report(
+                        next.number,
+                        format!("\"Next lesson will cover: {}\", but lesson {} is \"{}\"", next.text, n + 1, title),
+                    );
+                }
+            }
+        }
+    }
+
+    let expected_summary = format!("LESSON {} SUMMARY", n);
+    match &lesson.summary {
+        None => report(1, format!("missing the `// {}:` block", expected_summary)),
+        Some(block) if block.title != expected_summary => {
+            report(block.line, format!("the summary should be titled `// {}:`", expected_summary))
+        }
+        Some(block) if block.groups.iter().all(|g| g.bullets.is_empty()) => {
+            report(block.line, String::from("the summary has no `// - ` bullets"))
+        }
+        Some(_) => {}
+    }
+
+    // Every bullet says which section it sums up, so the cheat sheet can link there
+    let blocks = lesson.summary.iter().chain(lesson.key_concepts.iter());
+    for bullet in blocks.flat_map(|block| block.groups.iter()).flat_map(|group| group.bullets.iter()) {
+        match &bullet.section {
+            None => report(bullet.number, String::from("end the bullet with \"(see <section title>)\"")),
+            Some(title) if lesson.section_named(title).is_none() => {
+                report(bullet.number, format!("there is no \"--- {} ---\" section to see", title))
+            }
+            Some(_) => {}
+        }
+    }
+
+    problems
+}
+
+// SPELLING
+// Only the words in comments are checked. Code inside comments (push_str,
+// Vec<T>, s.len(), `backticks`) is skipped, since it isn't English.
+
+fn load_dictionary(path: &Path) -> BTreeSet<String> {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Warning: could not read {} ({}), so every word is unknown", path.display(), e);
+        String::new()
+    });
+    text.lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| l.to_lowercase())
+        .collect()
+}
+
+fn misspelled(lesson: &Lesson, dictionary: &BTreeSet<String>) -> Vec<(usize, String)> {
+    let mut found = Vec::new();
+    for comment in lesson.comments() {
+        for word in words(&comment.text) {
+            if !dictionary.contains(&word) {
+                found.push((comment.number, word));
+            }
+        }
+    }
+    found
+}
+
+// The English words in a comment, lowercased
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    // Odd-numbered pieces between backticks are code
+    for (i, piece) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            continue;
+        }
+        for chunk in piece.split_whitespace() {
+            let chunk = chunk.trim_matches(|c: char| !c.is_alphanumeric());
+            // Anything with symbols or digits inside is code or a number
+            if chunk.is_empty() || !chunk.chars().all(|c| c.is_alphabetic() || c == '\'' || c == '-') {
+                continue;
+            }
+            for word in chunk.split('-') {
+                let word = word.trim_end_matches("'s").trim_matches('\'');
+                // camelCase and PascalCase are type names (HashMap, ParseIntError)
+                let is_type_name = word.chars().skip(1).any(|c| c.is_uppercase())
+                    && !word.chars().all(|c| c.is_uppercase());
+                if word.chars().count() > 1 && !is_type_name {
+                    words.push(word.to_lowercase());
+                }
+            }
+        }
+    }
+    words
+}
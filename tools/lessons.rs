@@ -0,0 +1,339 @@
+// Shared module: Lesson File Parser
+// Reads lesson-N/lessonN.rs and picks out the parts every lesson has:
+//   // Lesson N: Title                        first line
+//   // To compile and run: rustc lessonN.rs   second line
+//   println!("=== Lesson N: Title ===");      opening banner (optional)
+//   println!("\n--- Section ---");            one banner per section
+//   section!("Section");                      (the same, with lesson 13's macro)
+//   // EXERCISES FOR YOU TO TRY:              then "1. ..." as comments or println!s
+//   println!("Next lesson will cover: ...");
+//   // LESSON N SUMMARY:                      then "// - bullet (see Section)" lines
+//   // KEY CONCEPTS:                          (optional) same shape, with "Heading:" lines
+// The "(see Section)" at the end of a bullet names the section it sums up.
+//
+// To use it from a program in this folder, add `mod lessons;` at the top, then:
+//     let root = lessons::find_root().expect("run this from the repository");
+//     for lesson in lessons::load_all(&root)? { println!("{:?}", lesson.title); }
+// Tests: rustc --test lessons.rs -o lessons-tests && ./lessons-tests
+
+#![allow(dead_code)] // not every program uses every helper
+
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub struct Lesson {
+    pub number: u32,
+    pub path: PathBuf, // lesson-N/lessonN.rs, relative to the root it was loaded from
+    pub source: String,
+    pub header: Option<(u32, String)>, // number and title from "// Lesson N: Title"
+    pub compile_line: Option<Line>,    // the text after "To compile and run:"
+    pub banner: Option<Line>,          // "Lesson N: Title" from the === banner
+    pub sections: Vec<Line>,           // "--- Section ---" titles, without End of Lesson
+    pub end_banner: Option<Line>,      // "End of Lesson N"
+    pub exercises: Option<Exercises>,
+    pub next: Option<Line>, // the text after "Next lesson will cover:" (or "Next:")
+    pub summary: Option<Block>,
+    pub key_concepts: Option<Block>,
+}
+
+// Some text and the 1-based line number it came from
+#[derive(Clone)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+pub struct Exercises {
+    pub line: usize,     // the "EXERCISES FOR YOU TO TRY:" comment
+    pub items: Vec<Line>, // "1. Write a function...", numbers included
+}
+
+// A comment block of bullets, like "// LESSON 3 SUMMARY:"
+pub struct Block {
+    pub line: usize,
+    pub title: String, // "LESSON 3 SUMMARY"
+    pub groups: Vec<Group>,
+}
+
+// Bullets under an optional "Heading:" line (KEY CONCEPTS has several)
+pub struct Group {
+    pub heading: Option<String>,
+    pub bullets: Vec<Bullet>,
+}
+
+// "// - Borrowing: Use & ... (see Borrowing with References)"
+pub struct Bullet {
+    pub number: usize,
+    pub text: String,            // without the "(see ...)"
+    pub section: Option<String>, // "Borrowing with References"
+}
+
+impl Lesson {
+    pub fn title(&self) -> Option<&str> {
+        self.header.as_ref().map(|(_, title)| title.as_str())
+    }
+
+    // Every bullet of the summary, in order
+    pub fn summary_bullets(&self) -> Vec<&Bullet> {
+        self.summary
+            .iter()
+            .flat_map(|block| block.groups.iter())
+            .flat_map(|group| group.bullets.iter())
+            .collect()
+    }
+
+    // The section a line belongs to: the last banner at or before it
+    pub fn section_at(&self, line: usize) -> Option<&Line> {
+        self.sections.iter().take_while(|s| s.number <= line).last()
+    }
+
+    // The section banner with this title, e.g. the one a bullet points to
+    pub fn section_named(&self, title: &str) -> Option<&Line> {
+        self.sections.iter().find(|s| s.text == title)
+    }
+
+    // The text of every `//` comment with its line number
+    // (doc-style `///` comments included, the slashes removed)
+    pub fn comments(&self) -> Vec<Line> {
+        self.source
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let start = comment_start(line)?;
+                let text = line[start..].trim_start_matches('/').trim();
+                Some(Line { number: i + 1, text: text.to_string() })
+            })
+            .collect()
+    }
+}
+
+// The repository root is the folder holding lesson-1: here or one level up
+pub fn find_root() -> Option<PathBuf> {
+    [".", ".."]
+        .iter()
+        .map(PathBuf::from)
+        .find(|dir| dir.join("lesson-1").is_dir())
+}
+
+// lesson-1, lesson-2, ... up to the first number that doesn't exist
+pub fn load_all(root: &Path) -> io::Result<Vec<Lesson>> {
+    let mut lessons = Vec::new();
+    for number in 1.. {
+        let path = root.join(format!("lesson-{}", number)).join(format!("lesson{}.rs", number));
+        if !path.is_file() {
+            break;
+        }
+        let source = fs::read_to_string(&path)?;
+        lessons.push(parse(number, path, source));
+    }
+    Ok(lessons)
+}
+
+pub fn parse(number: u32, path: PathBuf, source: String) -> Lesson {
+    let lines: Vec<&str> = source.lines().collect();
+    let at = |i: usize, text: &str| Line { number: i + 1, text: text.to_string() };
+
+    let header = lines.first().and_then(|first| {
+        let rest = first.strip_prefix("// Lesson ")?;
+        let (n, title) = rest.split_once(": ")?;
+        Some((n.trim().parse().ok()?, title.trim().to_string()))
+    });
+
+    let mut compile_line = None;
+    let mut banner = None;
+    let mut sections = Vec::new();
+    let mut end_banner = None;
+    let mut next = None;
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(rest) = line.split_once("// To compile and run:").map(|(_, rest)| rest) {
+            compile_line.get_or_insert(at(i, rest.trim()));
+        }
+        let Some(printed) = printed_text(line) else {
+            continue;
+        };
+        let printed = printed.trim_start_matches("\\n");
+        if let Some(title) = printed.strip_prefix("=== ").and_then(|t| t.strip_suffix(" ===")) {
+            banner.get_or_insert(at(i, title));
+        } else if let Some(title) = printed.strip_prefix("--- ").and_then(|t| t.strip_suffix(" ---")) {
+            if title.contains('{') {
+                // A banner template like println!("\n--- {} ---", title) inside a macro
+                continue;
+            } else if title.starts_with("End of Lesson") {
+                end_banner = Some(at(i, title));
+            } else {
+                sections.push(at(i, title));
+            }
+        } else if let Some(rest) = printed.strip_prefix("Next lesson will cover:").or_else(|| printed.strip_prefix("Next:")) {
+            next = Some(at(i, rest.trim()));
+        }
+    }
+
+    let summary = find_block(&lines, &format!("LESSON {} SUMMARY", number))
+        .or_else(|| find_block_ending(&lines, " SUMMARY"));
+    let key_concepts = find_block(&lines, "KEY CONCEPTS");
+
+    Lesson {
+        number,
+        path,
+        header,
+        compile_line,
+        banner,
+        sections,
+        end_banner,
+        exercises: find_exercises(&lines),
+        next,
+        summary,
+        key_concepts,
+        source,
+    }
+}
+
+// Lowercase words joined by hyphens, for HTML anchors: "Control Flow: If/Else" -> "control-flow-if-else"
+pub fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+// A section starts at its banner, or at the comment lines just above it
+// (the ALL CAPS heading usually sits there). Returns a 0-based index.
+pub fn section_start(lines: &[&str], banner: usize) -> usize {
+    let mut start = banner - 1;
+    while start > 0 && lines[start - 1].trim().starts_with("//") {
+        start -= 1;
+    }
+    start
+}
+
+// The string inside println!("..."), still escaped; None in a comment.
+// section!("Title") counts as the banner it prints: "--- Title ---".
+fn printed_text(line: &str) -> Option<Cow<'_, str>> {
+    if line.trim_start().starts_with("//") {
+        return None;
+    }
+    if let Some(title) = macro_argument(line, "section!(\"") {
+        return Some(Cow::Owned(format!("--- {} ---", title)));
+    }
+    macro_argument(line, "println!(\"").map(Cow::Borrowed)
+}
+
+fn macro_argument<'a>(line: &'a str, call: &str) -> Option<&'a str> {
+    let start = line.find(call)? + call.len();
+    let rest = &line[start..];
+    let end = rest.find("\");").or_else(|| rest.find("\")"))?;
+    Some(&rest[..end])
+}
+
+// Where a `//` comment starts, skipping any "//" inside a string literal
+pub fn comment_start(line: &str) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut in_string = false;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_string => i += 1, // skip the escaped character
+            b'"' => in_string = !in_string,
+            b'/' if !in_string && bytes[i + 1] == b'/' => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// The exercise list: comment lines like "// 1. ..." or println!("1. ...")
+// after the "EXERCISES FOR YOU TO TRY:" comment, up to the next blank line
+fn find_exercises(lines: &[&str]) -> Option<Exercises> {
+    let start = lines.iter().position(|l| l.contains("EXERCISES FOR YOU TO TRY"))?;
+    let mut items = Vec::new();
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.contains("--- End of Lesson") {
+            break;
+        }
+        let text = match printed_text(line) {
+            Some(printed) => printed,
+            None => Cow::Borrowed(trimmed.trim_start_matches('/').trim()),
+        };
+        let numbered = text.split_once(". ").is_some_and(|(n, _)| n.parse::<u32>().is_ok());
+        if numbered {
+            items.push(Line { number: i + 1, text: text.replace("\\\"", "\"") });
+        }
+    }
+    Some(Exercises { line: start + 1, items })
+}
+
+fn find_block(lines: &[&str], title: &str) -> Option<Block> {
+    let start = lines.iter().position(|l| l.trim() == format!("// {}:", title))?;
+    Some(read_block(lines, start, title))
+}
+
+// A "// ... SUMMARY:" block with the wrong lesson number is still a summary
+fn find_block_ending(lines: &[&str], suffix: &str) -> Option<Block> {
+    let start = lines.iter().position(|l| {
+        l.starts_with("// ") && l.trim_end().ends_with(&format!("{}:", suffix))
+    })?;
+    let title = lines[start].trim().trim_start_matches("// ").trim_end_matches(':');
+    Some(read_block(lines, start, title))
+}
+
+// Read comment lines after the title until the first line that isn't a comment
+fn read_block(lines: &[&str], start: usize, title: &str) -> Block {
+    let mut groups = vec![Group { heading: None, bullets: Vec::new() }];
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        let Some(text) = line.trim().strip_prefix("//") else {
+            break;
+        };
+        let text = text.trim();
+        if let Some(bullet) = text.strip_prefix("- ") {
+            let group = groups.last_mut().expect("there is always a group");
+            group.bullets.push(read_bullet(i + 1, bullet));
+        } else if let Some(heading) = text.strip_suffix(':') {
+            groups.push(Group { heading: Some(heading.to_string()), bullets: Vec::new() });
+        }
+    }
+    groups.retain(|g| g.heading.is_some() || !g.bullets.is_empty());
+    Block { line: start + 1, title: title.to_string(), groups }
+}
+
+// Split "text (see Section)" into the text and the section title
+fn read_bullet(number: usize, bullet: &str) -> Bullet {
+    match bullet.strip_suffix(')').and_then(|rest| rest.rsplit_once(" (see ")) {
+        Some((text, section)) => Bullet { number, text: text.to_string(), section: Some(section.to_string()) },
+        None => Bullet { number, text: bullet.to_string(), section: None },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_bullet_names_its_section() {
+        let bullet = read_bullet(7, "mpsc channels send values to one receiver (see Channels (mpsc))");
+        assert_eq!(bullet.text, "mpsc channels send values to one receiver");
+        assert_eq!(bullet.section.as_deref(), Some("Channels (mpsc)"));
+
+        let untagged = read_bullet(8, "Use clone() to create independent copies");
+        assert_eq!(untagged.text, "Use clone() to create independent copies");
+        assert_eq!(untagged.section, None);
+    }
+
+    #[test]
+    fn the_named_section_is_found_by_title() {
+        let source = "// Lesson 1: Test\nfn main() {\n    println!(\"\\n--- Loops ---\");\n}\n\n\
+                      // LESSON 1 SUMMARY:\n// - for runs once per item (see Loops)\n";
+        let lesson = parse(1, PathBuf::from("lesson1.rs"), source.to_string());
+        let bullet = &lesson.summary_bullets()[0];
+        let section = bullet.section.as_deref().and_then(|title| lesson.section_named(title));
+        assert_eq!(section.map(|s| s.number), Some(3));
+    }
+}
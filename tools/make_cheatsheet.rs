@@ -0,0 +1,67 @@
+// Cheat Sheet Generator
+// Pulls the summary (and key concepts) out of every lesson and writes one
+// cheat sheet as cheatsheet.md and as a standalone cheatsheet.html.
+// Each bullet links to the banner of the lesson section it names with "(see ...)".
+// To compile and run: rustc make_cheatsheet.rs && ./make_cheatsheet
+//   ./make_cheatsheet              write into target/cheatsheet/
+//   ./make_cheatsheet --out DIR    write into DIR instead
+//
+// Run it from this folder or from the top of the repository.
+
+mod cheatsheet;
+mod html;
+mod lessons;
+
+use cheatsheet::Links;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+fn main() {
+    let Some(root) = lessons::find_root() else {
+        fail("no lesson-1 folder here or one level up; run this from the repository");
+    };
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let out = match args.as_slice() {
+        [] => root.join("target").join("cheatsheet"),
+        [flag, dir] if flag == "--out" => PathBuf::from(dir),
+        _ => fail("usage: make_cheatsheet [--out DIR]"),
+    };
+
+    let all = lessons::load_all(&root).unwrap_or_else(|e| fail(&format!("could not read the lessons: {}", e)));
+    if let Err(e) = fs::create_dir_all(&out) {
+        fail(&format!("could not create {}: {}", out.display(), e));
+    }
+
+    let links = Links::Source(path_to_root(&out, &root));
+    let files = [
+        ("cheatsheet.md", cheatsheet::markdown(&all, &links)),
+        ("cheatsheet.html", cheatsheet::html_page(&all, &links)),
+    ];
+    for (name, contents) in &files {
+        let path = out.join(name);
+        if let Err(e) = fs::write(&path, contents) {
+            fail(&format!("could not write {}: {}", path.display(), e));
+        }
+        println!("Wrote {}", path.display());
+    }
+}
+
+// How to get from `dir` back to `root` with a relative link ("../../"),
+// or root's absolute path when `dir` is somewhere else entirely
+fn path_to_root(dir: &Path, root: &Path) -> String {
+    let (Ok(dir), Ok(root)) = (fs::canonicalize(dir), fs::canonicalize(root)) else {
+        return String::new();
+    };
+    match dir.strip_prefix(&root) {
+        Ok(inside) => "../".repeat(inside.components().count()),
+        Err(_) => format!("{}/", root.display()),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
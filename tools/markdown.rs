@@ -0,0 +1,193 @@
+// Shared module: Markdown to HTML
+// Enough Markdown for questions.md: headings, paragraphs, **bold**, *italic*,
+// `code`, [links](url), fenced code blocks, > quotes, - lists, | tables |
+// and --- rules. Anything fancier comes out as plain text.
+//
+// To use it from a program in this folder, add `mod html;` and `mod markdown;`
+// at the top, then:
+//     let body = markdown::to_html(&fs::read_to_string("questions.md")?);
+
+#![allow(dead_code)] // not every program uses every helper
+
+use crate::html::escape;
+
+pub fn to_html(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        // Every block except a paragraph ends the paragraph before it
+        let starts_block = trimmed.is_empty()
+            || trimmed.starts_with("```")
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('>')
+            || trimmed.starts_with('|')
+            || is_rule(trimmed)
+            || list_item(trimmed).is_some();
+        if starts_block && !paragraph.is_empty() {
+            out.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+
+        if trimmed.is_empty() {
+            i += 1;
+        } else if let Some(info) = trimmed.strip_prefix("```") {
+            // Fenced code: everything up to the closing fence, untouched, except
+            // that "# " lines in ```rust blocks are setup only md_test.rs sees
+            let end = (i + 1..lines.len()).find(|&j| lines[j].trim() == "```").unwrap_or(lines.len());
+            // "rust,compile_fail(E0382)" -> "rust"
+            let lang = info.split([',', ' ']).next().unwrap_or("").trim();
+            let shown: Vec<&str> = lines[i + 1..end]
+                .iter()
+                .filter(|l| lang != "rust" || !(l.trim() == "#" || l.trim_start().starts_with("# ")))
+                .copied()
+                .collect();
+            let code = shown.join("\n");
+            let class = match lang {
+                "" => String::new(),
+                lang => format!(" class=\"language-{}\"", escape(lang)),
+            };
+            out.push_str(&format!("<pre><code{}>{}</code></pre>\n", class, escape(&code)));
+            i = end + 1;
+        } else if trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|&c| c == '#').count().min(6);
+            let title = trimmed[level..].trim();
+            out.push_str(&format!("<h{l}>{}</h{l}>\n", inline(title), l = level));
+            i += 1;
+        } else if is_rule(trimmed) {
+            out.push_str("<hr>\n");
+            i += 1;
+        } else if trimmed.starts_with('>') {
+            let quoted = take_while(&lines, &mut i, |l| l.trim().starts_with('>'));
+            let text: Vec<&str> = quoted.iter().map(|l| l.trim().trim_start_matches('>').trim()).collect();
+            out.push_str(&format!("<blockquote><p>{}</p></blockquote>\n", inline(&text.join(" "))));
+        } else if trimmed.starts_with('|') {
+            let rows = take_while(&lines, &mut i, |l| l.trim().starts_with('|'));
+            out.push_str(&table(&rows));
+        } else if let Some((ordered, _)) = list_item(trimmed) {
+            let items = take_while(&lines, &mut i, |l| list_item(l.trim()).is_some());
+            let tag = if ordered { "ol" } else { "ul" };
+            out.push_str(&format!("<{}>\n", tag));
+            for item in items {
+                if let Some((_, text)) = list_item(item.trim()) {
+                    out.push_str(&format!("<li>{}</li>\n", inline(text)));
+                }
+            }
+            out.push_str(&format!("</{}>\n", tag));
+        } else {
+            paragraph.push(trimmed);
+            i += 1;
+        }
+    }
+    if !paragraph.is_empty() {
+        out.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join(" "))));
+    }
+    out
+}
+
+fn is_rule(line: &str) -> bool {
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+// "- item" / "* item" -> (false, "item"), "1. item" -> (true, "item")
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((false, text));
+    }
+    let (number, text) = line.split_once(". ")?;
+    number.parse::<u32>().ok().map(|_| (true, text))
+}
+
+fn take_while<'a>(lines: &[&'a str], i: &mut usize, keep: impl Fn(&str) -> bool) -> Vec<&'a str> {
+    let start = *i;
+    while *i < lines.len() && keep(lines[*i]) {
+        *i += 1;
+    }
+    lines[start..*i].to_vec()
+}
+
+// | a | b |        the first row is the header,
+// |---|---|        the second only marks it as one
+// | 1 | 2 |
+fn table(rows: &[&str]) -> String {
+    let cells = |row: &str| -> Vec<String> {
+        let row = row.trim().trim_start_matches('|').trim_end_matches('|');
+        row.split('|').map(|cell| cell.trim().to_string()).collect()
+    };
+    let mut out = String::from("<table>\n");
+    for (n, row) in rows.iter().enumerate() {
+        if n == 1 && row.chars().all(|c| "|-: ".contains(c)) {
+            continue;
+        }
+        let tag = if n == 0 { "th" } else { "td" };
+        out.push_str("<tr>");
+        for cell in cells(row) {
+            out.push_str(&format!("<{t}>{}</{t}>", inline(&cell), t = tag));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+// Inline formatting inside one block of text
+pub fn inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let rest: String = chars[i..].iter().collect();
+        match chars[i] {
+            // \< means a literal <
+            '\\' if i + 1 < chars.len() && chars[i + 1].is_ascii_punctuation() => {
+                out.push_str(&escape(&chars[i + 1].to_string()));
+                i += 2;
+            }
+            '`' => match rest[1..].find('`') {
+                Some(end) => {
+                    out.push_str(&format!("<code>{}</code>", escape(&rest[1..1 + end])));
+                    i += 2 + rest[1..1 + end].chars().count();
+                }
+                None => {
+                    out.push('`');
+                    i += 1;
+                }
+            },
+            '*' if rest.starts_with("**") && rest[2..].contains("**") => {
+                let end = rest[2..].find("**").unwrap_or(0);
+                out.push_str(&format!("<strong>{}</strong>", inline(&rest[2..2 + end])));
+                i += 4 + rest[2..2 + end].chars().count();
+            }
+            '*' if rest.len() > 1 && !rest[1..].starts_with(' ') && rest[1..].contains('*') => {
+                let end = rest[1..].find('*').unwrap_or(0);
+                out.push_str(&format!("<em>{}</em>", inline(&rest[1..1 + end])));
+                i += 2 + rest[1..1 + end].chars().count();
+            }
+            '[' if rest.contains("](") => {
+                let close = rest.find("](").unwrap_or(0);
+                match rest[close..].find(')') {
+                    Some(end) => {
+                        let label = &rest[1..close];
+                        let url = &rest[close + 2..close + end];
+                        out.push_str(&format!("<a href=\"{}\">{}</a>", escape(url), inline(label)));
+                        i += rest[..close + end + 1].chars().count();
+                    }
+                    None => {
+                        out.push('[');
+                        i += 1;
+                    }
+                }
+            }
+            c => {
+                out.push_str(&escape(&c.to_string()));
+                i += 1;
+            }
+        }
+    }
+    out
+}
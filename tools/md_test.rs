@@ -0,0 +1,235 @@
+// Markdown Code Block Tests
+// Lessons and notes written in Markdown (like questions.md) show code in
+// fenced ```rust blocks. This pulls out every one of them, compiles and runs
+// it, and reports the blocks that no longer do what the text says they do.
+// To compile and run: rustc md_test.rs -o md-test && ./md-test
+//   ./md-test              check questions.md and every .md file in lesson-N/
+//   ./md-test FILE.md ...  check just these files
+//
+// Say what a block should do after the language, separated by commas:
+//   ```rust                          compiles and runs without panicking
+//   ```rust,run                      the same, spelled out
+//   ```rust,should_panic             compiles, then panics when run
+//   ```rust,compile_fail             does not compile
+//   ```rust,compile_fail(E0382)      does not compile, with error E0382
+//   ```rust,ignore                   not checked at all
+// A block without `fn main` is wrapped in one, and lines starting with "# "
+// are compiled but hidden from readers (the site and cheat sheet drop them):
+//   # let s1 = String::from("hello");
+//   let s2 = s1.clone();
+// Blocks in other languages (or none) are left alone.
+//
+// Run it from this folder or from the top of the repository.
+
+mod lessons;
+mod sandbox;
+
+use sandbox::{Limits, Sandbox, Status};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+// What the text around a block claims it does
+#[derive(Debug, PartialEq)]
+enum Expect {
+    Run,
+    ShouldPanic,
+    CompileFail(Option<String>), // the error code, if the block names one
+    Ignore,
+}
+
+struct CodeBlock {
+    line: usize, // of the opening fence, 1-based
+    expect: Expect,
+    code: String, // hidden lines included, "# " removed
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let files = if args.is_empty() {
+        let Some(root) = lessons::find_root() else {
+            fail("no lesson-1 folder here or one level up; run this from the repository");
+        };
+        markdown_files(&root)
+    } else {
+        args.iter().map(PathBuf::from).collect()
+    };
+
+    let (mut checked, mut failed, mut ignored) = (0, 0, 0);
+    for file in &files {
+        let text = fs::read_to_string(file).unwrap_or_else(|e| fail(&format!("could not read {}: {}", file.display(), e)));
+        for block in code_blocks(&text) {
+            let name = format!("{}:{} ({})", file.display(), block.line, describe(&block.expect));
+            if block.expect == Expect::Ignore {
+                ignored += 1;
+                continue;
+            }
+            checked += 1;
+            match check(&block) {
+                Ok(()) => println!("ok      {}", name),
+                Err(problem) => {
+                    println!("FAILED  {}", name);
+                    for line in problem.trim_end().lines() {
+                        println!("        {}", line);
+                    }
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!("\n{} block(s) checked, {} failed, {} ignored", checked, failed, ignored);
+    if failed > 0 {
+        println!("Fix the code, or change the tag if the text now says something else.");
+        process::exit(1);
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
+
+// questions.md, then lesson-1/*.md, lesson-2/*.md, ...
+fn markdown_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = vec![root.join("questions.md")];
+    for number in 1.. {
+        let dir = root.join(format!("lesson-{}", number));
+        let Ok(entries) = fs::read_dir(&dir) else { break };
+        let mut found: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|e| e == "md"))
+            .collect();
+        found.sort();
+        files.extend(found);
+    }
+    files.retain(|file| file.is_file());
+    files
+}
+
+// PARSING
+
+fn code_blocks(text: &str) -> Vec<CodeBlock> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(info) = lines[i].trim().strip_prefix("```") else {
+            i += 1;
+            continue;
+        };
+        let end = (i + 1..lines.len()).find(|&j| lines[j].trim() == "```").unwrap_or(lines.len());
+        if let Some(expect) = parse_info(info) {
+            let code: Vec<&str> = lines[i + 1..end].iter().map(|line| unhide(line)).collect();
+            blocks.push(CodeBlock { line: i + 1, expect, code: code.join("\n") });
+        }
+        i = end + 1;
+    }
+    blocks
+}
+
+// "rust,compile_fail(E0382)" -> CompileFail(Some("E0382")); None for other languages
+fn parse_info(info: &str) -> Option<Expect> {
+    let mut words = info.split([',', ' ']).map(str::trim).filter(|w| !w.is_empty());
+    if words.next() != Some("rust") {
+        return None;
+    }
+    let mut expect = Expect::Run;
+    for word in words {
+        expect = match word {
+            "run" => Expect::Run,
+            "should_panic" => Expect::ShouldPanic,
+            "compile_fail" => Expect::CompileFail(None),
+            "ignore" => Expect::Ignore,
+            _ => match word.strip_prefix("compile_fail(").and_then(|w| w.strip_suffix(')')) {
+                Some(code) => Expect::CompileFail(Some(code.to_string())),
+                None => {
+                    eprintln!("Warning: unknown code block tag '{}', checking the block as `run`", word);
+                    expect
+                }
+            },
+        };
+    }
+    Some(expect)
+}
+
+// "# let x = 1;" -> "let x = 1;" (attributes like #[derive] are not hidden lines)
+fn unhide(line: &str) -> &str {
+    let trimmed = line.trim_start();
+    if trimmed == "#" {
+        ""
+    } else {
+        trimmed.strip_prefix("# ").unwrap_or(line)
+    }
+}
+
+fn describe(expect: &Expect) -> String {
+    match expect {
+        Expect::Run => String::from("run"),
+        Expect::ShouldPanic => String::from("should_panic"),
+        Expect::CompileFail(None) => String::from("compile_fail"),
+        Expect::CompileFail(Some(code)) => format!("compile_fail({})", code),
+        Expect::Ignore => String::from("ignore"),
+    }
+}
+
+// CHECKING
+
+// "error[E0382]: borrow of moved value (main.rs:5:20)" -> "... (line 14)",
+// the line in the Markdown file rather than in the generated program
+fn markdown_location(headline: &str, fence: usize, lines_before: usize) -> String {
+    let Some((text, place)) = headline.rsplit_once(" (main.rs:") else {
+        return headline.to_string();
+    };
+    match place.split(':').next().and_then(|n| n.parse::<usize>().ok()) {
+        Some(line) if line > lines_before => format!("{} (line {})", text, fence + line - lines_before),
+        _ => text.to_string(),
+    }
+}
+
+// Ok if the block behaves as tagged, otherwise what happened instead
+fn check(block: &CodeBlock) -> Result<(), String> {
+    // `lines_before` is how many lines come before the block's first line
+    let (program, lines_before) = if block.code.contains("fn main") {
+        (format!("#![allow(unused)]\n{}\n", block.code), 1)
+    } else {
+        (format!("#![allow(unused)]\nfn main() {{\n{}\n}}\n", block.code), 2)
+    };
+    let locate = |headlines: &[String]| -> Vec<String> {
+        headlines.iter().map(|h| markdown_location(h, block.line, lines_before)).collect()
+    };
+
+    let sandbox = Sandbox::new().map_err(|e| e.to_string())?;
+    let limits = Limits::default();
+    sandbox.write("main.rs", &program).map_err(|e| e.to_string())?;
+    let compiled = sandbox
+        .compile("main.rs", &[], &limits)
+        .map_err(|e| format!("could not run rustc: {}", e))?;
+
+    if let Expect::CompileFail(code) = &block.expect {
+        return match code {
+            _ if compiled.success => Err(String::from("expected a compile error, but it compiled")),
+            Some(code) if !compiled.error_codes.contains(code) => Err(format!(
+                "expected error {}, got: {}",
+                code,
+                locate(&compiled.errors).join("; ")
+            )),
+            _ => Ok(()),
+        };
+    }
+    if !compiled.success {
+        return Err(format!("did not compile:\n{}", locate(&compiled.errors).join("\n")));
+    }
+
+    let run = sandbox.run(&[], "", &limits).map_err(|e| e.to_string())?;
+    match (&block.expect, &run.status) {
+        (_, Status::TimedOut) => Err(String::from("did not finish in time")),
+        (_, Status::TooMuchOutput) => Err(String::from("printed too much output")),
+        (Expect::ShouldPanic, _) if run.panicked() => Ok(()),
+        (Expect::ShouldPanic, _) => Err(String::from("expected a panic, but it finished normally")),
+        _ if run.success() => Ok(()),
+        _ => Err(format!("exited with {:?}\n{}", run.status, run.stderr)),
+    }
+}
@@ -0,0 +1,467 @@
+// Local Playground
+// A small web server for trying code in the browser: open the page, pick a
+// lesson (or start from an empty main), edit it and press Run. The code is
+// compiled with the local rustc and run under the same limits the grader
+// uses; compiler messages and program output appear as they are produced.
+// To compile and run: rustc playground.rs && ./playground
+//   ./playground              serve on http://127.0.0.1:8000/
+//   ./playground --port N     serve on port N instead
+//
+// The server only listens on 127.0.0.1, so other machines cannot reach it,
+// and it only accepts runs sent by its own page. It still compiles and runs
+// whatever that page sends, so don't leave it running on a shared machine.
+// Run it from this folder or from the top of the repository.
+
+mod html;
+mod lessons;
+mod sandbox;
+
+use html::escape;
+use lessons::Lesson;
+use sandbox::{Limits, Sandbox, Status, Stream};
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_PORT: u16 = 8000;
+const CPU_SECONDS: u64 = 5;
+const MEMORY_BYTES: u64 = 256 * 1024 * 1024;
+const MAX_BODY: usize = 256 * 1024; // the biggest lesson is well under 64 KB
+const MAX_OUTPUT_LINES: usize = 2000; // shown in the page; the program keeps running
+const MAX_OUTPUT_BYTES: usize = 1024 * 1024; // printed in all; past this the program is stopped
+
+const STARTER: &str = "fn main() {\n    println!(\"Hello, playground!\");\n}\n";
+
+struct Server {
+    root: PathBuf,
+    port: u16,
+    // One compile-and-run at a time, so a few clicks on Run can't swamp the machine
+    running: Mutex<()>,
+}
+
+fn main() {
+    let Some(root) = lessons::find_root() else {
+        fail("no lesson-1 folder here or one level up; run this from the repository");
+    };
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let port = match args.as_slice() {
+        [] => DEFAULT_PORT,
+        [flag, port] if flag == "--port" => port.parse().unwrap_or_else(|_| fail("the port must be a number")),
+        _ => fail("usage: playground [--port N]"),
+    };
+
+    // Loopback only: the playground runs arbitrary code, it must not be on the network
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .unwrap_or_else(|e| fail(&format!("could not listen on port {}: {}", port, e)));
+    println!("Playground running at http://127.0.0.1:{}/ (Ctrl+C to stop)", port);
+
+    let server = Arc::new(Server { root, port, running: Mutex::new(()) });
+    for connection in listener.incoming() {
+        let Ok(connection) = connection else { continue };
+        let server = Arc::clone(&server);
+        thread::spawn(move || {
+            if let Err(e) = server.handle(connection) {
+                eprintln!("Error: {}", e);
+            }
+        });
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(2);
+}
+
+// HTTP
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    headers: Vec<(String, String)>, // names in lowercase
+    body: String,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n == name).map(|(_, value)| value.as_str())
+    }
+}
+
+// Just enough HTTP/1.1 for one browser: a request line, headers and a
+// Content-Length body. Every connection serves one request and is closed.
+fn read_request(connection: &TcpStream) -> io::Result<Request> {
+    let bad = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let mut reader = BufReader::new(connection.take(MAX_BODY as u64 + 16 * 1024));
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(bad("not an HTTP request"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (method, path, query) = (method.to_string(), path.to_string(), query.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(bad("connection closed in the middle of the headers"));
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let mut request = Request { method, path, query, headers, body: String::new() };
+    let length: usize = request.header("content-length").and_then(|n| n.parse().ok()).unwrap_or(0);
+    if length > MAX_BODY {
+        return Err(bad("request body too large"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    request.body = String::from_utf8_lossy(&body).into_owned();
+    Ok(request)
+}
+
+fn respond(mut connection: &TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        connection,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
+
+// A response body sent in pieces as they become ready (chunked encoding).
+// Each piece is one line: a tag, a space and the text, e.g. "o Hello".
+//   s  status from the playground      c  compiler message
+//   o  program stdout                  e  program stderr
+struct Chunked<'a> {
+    connection: &'a TcpStream,
+    open: bool, // false once the browser has gone away
+}
+
+impl<'a> Chunked<'a> {
+    fn start(mut connection: &'a TcpStream) -> io::Result<Chunked<'a>> {
+        connection.write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\n\
+              Transfer-Encoding: chunked\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        )?;
+        Ok(Chunked { connection, open: true })
+    }
+
+    fn send(&mut self, tag: char, text: &str) {
+        let piece = format!("{} {}\n", tag, text);
+        if self.open {
+            let sent = write!(self.connection, "{:x}\r\n{}\r\n", piece.len(), piece).and_then(|_| self.connection.flush());
+            self.open = sent.is_ok();
+        }
+    }
+
+    fn finish(mut self) {
+        if self.open {
+            let _ = self.connection.write_all(b"0\r\n\r\n");
+        }
+    }
+}
+
+// application/x-www-form-urlencoded: "code=fn+main%28%29...&stdin="
+fn form_value(body: &str, name: &str) -> Option<String> {
+    body.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (url_decode(key) == name).then(|| url_decode(value))
+    })
+}
+
+fn url_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// ROUTES
+
+impl Server {
+    fn handle(&self, connection: TcpStream) -> io::Result<()> {
+        connection.set_read_timeout(Some(Duration::from_secs(10)))?;
+        let request = match read_request(&connection) {
+            Ok(request) => request,
+            Err(e) => return respond(&connection, "400 Bad Request", "text/plain", &format!("{}\n", e)),
+        };
+
+        // A web page on some other site can still point the browser at
+        // localhost; refuse anything that didn't come from our own page
+        if !self.is_from_our_page(&request) {
+            return respond(&connection, "403 Forbidden", "text/plain", "Only the playground page can use this server.\n");
+        }
+
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/") => {
+                let lesson = query_lesson(&request.query);
+                respond(&connection, "200 OK", "text/html; charset=utf-8", &self.editor_page(lesson))
+            }
+            ("POST", "/run") => self.run(&connection, &request),
+            _ => respond(&connection, "404 Not Found", "text/plain", "Not found.\n"),
+        }
+    }
+
+    // The Host header stops DNS rebinding (evil.example resolving to 127.0.0.1).
+    // Runs also need the X-Playground header: a browser only sends a custom
+    // header cross-site after asking first (CORS), and we never say yes.
+    fn is_from_our_page(&self, request: &Request) -> bool {
+        let ours = [format!("127.0.0.1:{}", self.port), format!("localhost:{}", self.port)];
+        let host_ok = request.header("host").is_some_and(|host| ours.iter().any(|o| o == host));
+        let origin_ok = match request.header("origin") {
+            None => true,
+            Some(origin) => ours.iter().any(|o| origin == format!("http://{}", o)),
+        };
+        let run_ok = request.path != "/run" || request.header("x-playground") == Some("run");
+        host_ok && origin_ok && run_ok
+    }
+
+    fn lessons(&self) -> Vec<Lesson> {
+        lessons::load_all(&self.root).unwrap_or_default()
+    }
+
+    // Compile the submitted code, then run it, streaming everything back
+    fn run(&self, connection: &TcpStream, request: &Request) -> io::Result<()> {
+        let code = form_value(&request.body, "code").unwrap_or_default();
+        let stdin = form_value(&request.body, "stdin").unwrap_or_default();
+        let mut out = Chunked::start(connection)?;
+
+        let _turn = match self.running.try_lock() {
+            Ok(turn) => turn,
+            Err(_) => {
+                out.send('s', "Waiting for the previous run to finish...");
+                self.running.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+            }
+        };
+
+        let sandbox = Sandbox::new()?;
+        sandbox.write("main.rs", &code)?;
+        // Lessons can have modules next to them (lesson 3 has trace.rs);
+        // copy those in unchanged so `mod trace;` still compiles
+        if let Some(lesson) = query_lesson(&request.query).and_then(|n| self.lessons().into_iter().find(|l| l.number == n)) {
+            for (name, contents) in sibling_modules(&self.root.join(&lesson.path)) {
+                sandbox.write(&name, &contents)?;
+            }
+        }
+
+        let limits = Limits {
+            run_time: Duration::from_secs(CPU_SECONDS * 2),
+            cpu_seconds: Some(CPU_SECONDS),
+            memory_bytes: Some(MEMORY_BYTES),
+            output_bytes: MAX_OUTPUT_BYTES,
+            ..Limits::default()
+        };
+
+        out.send('s', "Compiling...");
+        let compiled = sandbox.compile("main.rs", &[], &limits)?;
+        for line in compiled.diagnostics.lines() {
+            out.send('c', line);
+        }
+        if !compiled.success {
+            let message = match compiled.status {
+                Status::TimedOut => String::from("Compiling took too long and was stopped."),
+                _ => match compiled.errors.len() {
+                    1 => String::from("Compiling failed with 1 error."),
+                    n => format!("Compiling failed with {} errors.", n),
+                },
+            };
+            out.send('s', &message);
+            out.finish();
+            return Ok(());
+        }
+
+        out.send('s', "Running...");
+        let mut lines = 0;
+        let mut out_of_memory = false;
+        let status = sandbox.run_streaming(&[], &stdin, &limits, |stream, line| {
+            out_of_memory |= stream == Stream::Stderr && line.starts_with("memory allocation of");
+            lines += 1;
+            if lines <= MAX_OUTPUT_LINES {
+                out.send(if stream == Stream::Stdout { 'o' } else { 'e' }, line);
+            } else if lines == MAX_OUTPUT_LINES + 1 {
+                out.send('s', &format!("(output cut off after {} lines)", MAX_OUTPUT_LINES));
+            }
+        })?;
+        out.send('s', &describe(&status, out_of_memory));
+        out.finish();
+        Ok(())
+    }
+
+    // PAGES
+
+    fn editor_page(&self, lesson: Option<u32>) -> String {
+        let all = self.lessons();
+        let chosen = lesson.and_then(|n| all.iter().find(|l| l.number == n));
+        let code = chosen.map_or(STARTER, |l| l.source.as_str());
+
+        let mut options = String::from("<option value=\"\">Empty program</option>");
+        for l in &all {
+            let selected = if Some(l.number) == chosen.map(|c| c.number) { " selected" } else { "" };
+            let title = match l.title() {
+                Some(title) => format!("Lesson {}: {}", l.number, title),
+                None => format!("Lesson {}", l.number),
+            };
+            options.push_str(&format!("<option value=\"{}\"{}>{}</option>", l.number, selected, escape(&title)));
+        }
+
+        let body = format!(
+            "<h1>Rust Playground</h1>\n\
+             <div class=\"bar\"><label>Start from <select id=\"lesson\">{}</select></label>\n\
+             <button id=\"run\">Run</button> <button id=\"reset\">Reset</button>\n\
+             <span class=\"muted\">Ctrl+Enter runs. Limits: {} s of CPU, {} MB of memory.</span></div>\n\
+             <textarea id=\"code\" spellcheck=\"false\">{}</textarea>\n\
+             <details><summary>Input (stdin)</summary><textarea id=\"stdin\" spellcheck=\"false\"></textarea></details>\n\
+             <pre id=\"output\"><span class=\"s\">Press Run to compile and run the code.</span></pre>\n",
+            options,
+            CPU_SECONDS,
+            MEMORY_BYTES / (1024 * 1024),
+            escape(code)
+        );
+        html::page_with("Rust Playground", &body, PLAYGROUND_HEAD)
+    }
+}
+
+fn query_lesson(query: &str) -> Option<u32> {
+    query.split('&').find_map(|pair| pair.strip_prefix("lesson=")?.parse().ok())
+}
+
+// The other .rs files in a lesson's folder, and in its subfolders (lesson 11
+// has geometry/rectangle.rs), named by their path inside the folder.
+// Folders with a Cargo.toml are separate crates, so they are skipped.
+fn sibling_modules(lesson_path: &Path) -> Vec<(String, String)> {
+    let Some(dir) = lesson_path.parent() else { return Vec::new() };
+    let mut modules = Vec::new();
+    collect_modules(dir, "", lesson_path, &mut modules);
+    modules
+}
+
+fn collect_modules(dir: &Path, prefix: &str, lesson_path: &Path, modules: &mut Vec<(String, String)>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        if path.is_dir() {
+            if !path.join("Cargo.toml").exists() {
+                collect_modules(&path, &format!("{}/", name), lesson_path, modules);
+            }
+        } else if path.extension().is_some_and(|e| e == "rs") && path != lesson_path {
+            if let Ok(contents) = fs::read_to_string(&path) {
+                modules.push((name, contents));
+            }
+        }
+    }
+}
+
+fn describe(status: &Status, out_of_memory: bool) -> String {
+    match status {
+        _ if out_of_memory => format!("Stopped: used more than {} MB of memory.", MEMORY_BYTES / (1024 * 1024)),
+        Status::Exited(0) => String::from("Finished."),
+        Status::Exited(101) => String::from("The program panicked (exit code 101)."),
+        Status::Exited(code) => format!("Finished with exit code {}.", code),
+        Status::TimedOut | Status::Crashed(Some(sandbox::SIGXCPU)) | Status::Crashed(Some(sandbox::SIGKILL)) => {
+            format!("Stopped: ran longer than {} seconds (an infinite loop?)", CPU_SECONDS)
+        }
+        Status::TooMuchOutput => {
+            format!("Stopped: printed more than {} MB of output (printing in a loop?)", MAX_OUTPUT_BYTES / (1024 * 1024))
+        }
+        Status::Crashed(Some(sandbox::SIGSEGV)) => String::from("Crashed: stack overflow (endless recursion?)"),
+        Status::Crashed(_) => String::from("Crashed."),
+    }
+}
+
+const PLAYGROUND_HEAD: &str = r#"<style>
+.bar { display: flex; gap: 0.75rem; align-items: center; flex-wrap: wrap; margin-bottom: 0.5rem; }
+textarea { width: 100%; box-sizing: border-box; font-family: ui-monospace, Menlo, Consolas, monospace; font-size: 0.9rem; tab-size: 4; }
+#code { height: 28rem; }
+#stdin { height: 5rem; }
+#output { min-height: 6rem; max-height: 30rem; white-space: pre-wrap; }
+#output .s { color: #777; font-style: italic; }
+#output .c { color: #8a4b00; }
+#output .e { color: #b00020; }
+</style>
+<script>
+document.addEventListener("DOMContentLoaded", () => {
+  const code = document.getElementById("code");
+  const output = document.getElementById("output");
+  const original = code.value;
+
+  document.getElementById("lesson").addEventListener("change", (event) => {
+    location.search = event.target.value ? "?lesson=" + event.target.value : "";
+  });
+  document.getElementById("reset").addEventListener("click", () => { code.value = original; });
+  document.getElementById("run").addEventListener("click", run);
+  code.addEventListener("keydown", (event) => {
+    if (event.key === "Enter" && (event.ctrlKey || event.metaKey)) {
+      event.preventDefault();
+      run();
+    } else if (event.key === "Tab") {
+      event.preventDefault();
+      code.setRangeText("    ", code.selectionStart, code.selectionEnd, "end");
+    }
+  });
+
+  function show(line) {
+    const span = document.createElement("span");
+    span.className = line.slice(0, 1);
+    span.textContent = line.slice(2) + "\n";
+    output.appendChild(span);
+    output.scrollTop = output.scrollHeight;
+  }
+
+  async function run() {
+    output.textContent = "";
+    const body = new URLSearchParams({ code: code.value, stdin: document.getElementById("stdin").value });
+    try {
+      const response = await fetch("/run" + location.search, { method: "POST", headers: { "X-Playground": "run" }, body });
+      const reader = response.body.pipeThrough(new TextDecoderStream()).getReader();
+      let pending = "";
+      for (;;) {
+        const { value, done } = await reader.read();
+        if (done) break;
+        const lines = (pending + value).split("\n");
+        pending = lines.pop();
+        lines.forEach(show);
+      }
+    } catch (error) {
+      show("e Could not reach the playground server: " + error);
+    }
+  }
+});
+</script>
+"#;
@@ -0,0 +1,159 @@
+// Shared module: Exercise Progress File
+// Remembers, per exercise, how many hints were ever revealed, how many are
+// showing right now, and whether the grader has seen it pass. Stored as plain
+// text, one line per exercise:
+//     3.4 hints 2 shown 1 passed
+// (Older files without "shown" still load: every used hint counts as shown.)
+//
+// To use it from a program in this folder, add `mod progress;` at the top, then:
+//     let mut progress = Progress::load(progress::DEFAULT_PATH);
+//     progress.entry("3.4").reveal_next(3);
+//     progress.save(progress::DEFAULT_PATH)?;
+// Tests: rustc --test progress.rs -o progress-tests && ./progress-tests
+
+#![allow(dead_code)] // not every program uses every helper
+
+use std::fs;
+use std::io;
+
+pub const DEFAULT_PATH: &str = ".progress";
+
+#[derive(Default, Debug, PartialEq)]
+pub struct Entry {
+    pub hints_used: usize,  // the most ever revealed; never goes down
+    pub hints_shown: usize, // revealed since the last reset
+    pub passed: bool,
+}
+
+impl Entry {
+    // Reveal one more hint (out of `total`) and return how many were
+    // showing before, so the caller can tell which hint is new
+    pub fn reveal_next(&mut self, total: usize) -> usize {
+        let before = self.hints_shown;
+        self.hints_shown = (before + 1).min(total);
+        self.hints_used = self.hints_used.max(self.hints_shown);
+        before
+    }
+
+    // Hide the hints again. The grader still reports how many were used.
+    pub fn hide_hints(&mut self) {
+        self.hints_shown = 0;
+    }
+}
+
+pub struct Progress {
+    entries: Vec<(String, Entry)>, // kept in the order they were first seen
+}
+
+impl Progress {
+    // A missing or unreadable file just means no progress yet
+    pub fn load(path: &str) -> Progress {
+        let text = fs::read_to_string(path).unwrap_or_default();
+        let entries = text.lines().filter_map(parse_line).collect();
+        Progress { entries }
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut text = String::new();
+        for (id, entry) in &self.entries {
+            text.push_str(&to_line(id, entry));
+            text.push('\n');
+        }
+        fs::write(path, text)
+    }
+
+    // Progress on one exercise, or zero hints / not passed if it is new
+    pub fn get(&self, id: &str) -> Entry {
+        match self.entries.iter().find(|(i, _)| i == id) {
+            Some((_, entry)) => Entry {
+                hints_used: entry.hints_used,
+                hints_shown: entry.hints_shown,
+                passed: entry.passed,
+            },
+            None => Entry::default(),
+        }
+    }
+
+    // Mutable access, adding the exercise if it is new
+    pub fn entry(&mut self, id: &str) -> &mut Entry {
+        let index = match self.entries.iter().position(|(i, _)| i == id) {
+            Some(index) => index,
+            None => {
+                self.entries.push((id.to_string(), Entry::default()));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].1
+    }
+}
+
+fn to_line(id: &str, entry: &Entry) -> String {
+    let mut line = format!("{} hints {} shown {}", id, entry.hints_used, entry.hints_shown);
+    if entry.passed {
+        line.push_str(" passed");
+    }
+    line
+}
+
+// "3.4 hints 2 shown 1 passed" -> ("3.4", Entry { hints_used: 2, hints_shown: 1, passed: true }).
+// Lines that don't look like that are skipped rather than treated as errors.
+fn parse_line(line: &str) -> Option<(String, Entry)> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [id, "hints", count, rest @ ..] => {
+            let hints_used = count.parse().ok()?;
+            let hints_shown = match rest {
+                ["shown", shown, ..] => shown.parse::<usize>().ok()?.min(hints_used),
+                _ => hints_used,
+            };
+            let passed = rest.contains(&"passed");
+            Some((id.to_string(), Entry { hints_used, hints_shown, passed }))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_hides_hints_but_keeps_the_count() {
+        let mut entry = Entry::default();
+        entry.reveal_next(3);
+        entry.reveal_next(3);
+        entry.hide_hints();
+        assert_eq!(entry, Entry { hints_used: 2, hints_shown: 0, passed: false });
+
+        // Seeing the first hint again is not a new hint used
+        assert_eq!(entry.reveal_next(3), 0);
+        assert_eq!(entry, Entry { hints_used: 2, hints_shown: 1, passed: false });
+        entry.reveal_next(3);
+        entry.reveal_next(3);
+        assert_eq!(entry.hints_used, 3);
+    }
+
+    #[test]
+    fn revealing_stops_at_the_last_hint() {
+        let mut entry = Entry::default();
+        for _ in 0..5 {
+            entry.reveal_next(2);
+        }
+        assert_eq!(entry, Entry { hints_used: 2, hints_shown: 2, passed: false });
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        let entry = Entry { hints_used: 3, hints_shown: 1, passed: true };
+        let line = to_line("3.4", &entry);
+        assert_eq!(line, "3.4 hints 3 shown 1 passed");
+        assert_eq!(parse_line(&line), Some((String::from("3.4"), entry)));
+    }
+
+    #[test]
+    fn old_lines_count_every_used_hint_as_shown() {
+        let expected = Entry { hints_used: 2, hints_shown: 2, passed: true };
+        assert_eq!(parse_line("3.4 hints 2 passed"), Some((String::from("3.4"), expected)));
+        assert_eq!(parse_line("not a progress line"), None);
+    }
+}
@@ -0,0 +1,269 @@
+// Shared module: Safe Input Parsing
+// Lesson 1 parses input with `input.trim().parse::<i32>().unwrap()`, which crashes
+// on bad input. This module does the same pipeline without ever panicking:
+// read a line -> trim -> parse -> validate, and ask again if any step fails.
+//
+// To use it from a program in this folder, add `mod prompt;` at the top
+// (from a lesson folder: `#[path = "../tools/prompt.rs"] mod prompt;`), then:
+//     let age = Prompt::<u32>::new("How old are you?")
+//         .check(|n| *n < 150, "that seems a bit old")
+//         .ask();
+//     if let Some(age) = age {
+//         println!("Next year you will be {}", age + 1);
+//     }
+// ask() returns None when input runs out, so a program can stop cleanly.
+//
+// Tests: rustc --test prompt.rs -o prompt-tests && ./prompt-tests
+
+#![allow(dead_code)] // not every program uses every helper
+
+use std::any::Any;
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::num::{IntErrorKind, ParseFloatError, ParseIntError};
+use std::str::FromStr;
+
+// A validation rule: a closure that accepts or rejects a parsed value,
+// plus the message shown when it rejects it.
+struct Check<T> {
+    test: Box<dyn Fn(&T) -> bool>,
+    message: String,
+}
+
+// A question to ask the user, built step by step:
+// Prompt::new(..).default(..).check(..).ask()
+pub struct Prompt<T> {
+    message: String,
+    default: Option<T>,
+    checks: Vec<Check<T>>,
+}
+
+impl<T> Prompt<T>
+where
+    T: FromStr + Display + Clone,
+    T::Err: Display + 'static, // 'static so we can inspect the error type (see explain)
+{
+    pub fn new(message: &str) -> Prompt<T> {
+        Prompt {
+            message: message.to_string(),
+            default: None,
+            checks: Vec::new(),
+        }
+    }
+
+    // Value used when the user just presses Enter
+    pub fn default(mut self, value: T) -> Prompt<T> {
+        self.default = Some(value);
+        self
+    }
+
+    // Add a validation closure; the value is only accepted if every check passes
+    pub fn check(mut self, test: impl Fn(&T) -> bool + 'static, message: &str) -> Prompt<T> {
+        self.checks.push(Check {
+            test: Box::new(test),
+            message: message.to_string(),
+        });
+        self
+    }
+
+    // Ask on the real terminal (stdin/stdout).
+    // Returns None if input ends (Ctrl+D, or a closed pipe) before a valid answer.
+    pub fn ask(&self) -> Option<T> {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        self.ask_from(&mut stdin.lock(), &mut stdout.lock())
+    }
+
+    // Same as ask(), but reads from / writes to anything.
+    // Handy for scripting input in tests: ask_from(&mut "42\n".as_bytes(), &mut output)
+    pub fn ask_from(&self, input: &mut impl BufRead, output: &mut impl Write) -> Option<T> {
+        loop {
+            match &self.default {
+                Some(value) => write!(output, "{} [{}]: ", self.message, value).ok()?,
+                None => write!(output, "{}: ", self.message).ok()?,
+            }
+            output.flush().ok()?;
+
+            let line = read_line(input)?;
+            let text = line.trim(); // same first step as lesson 1
+
+            // Empty answer: use the default if there is one
+            if text.is_empty() {
+                if let Some(value) = &self.default {
+                    return Some(value.clone());
+                }
+            }
+
+            match text.parse::<T>() {
+                Ok(value) => match self.failed_check(&value) {
+                    None => return Some(value),
+                    Some(message) => writeln!(output, "  {}. Please try again.", message).ok()?,
+                },
+                // Instead of unwrap() crashing, explain the problem and loop
+                Err(e) => {
+                    let reason = explain(&e, text)
+                        .unwrap_or_else(|| format!("'{}' was not understood ({}).", text, e));
+                    writeln!(output, "  {} Please try again.", reason).ok()?;
+                }
+            }
+        }
+    }
+
+    // Returns the message of the first check that rejects the value
+    fn failed_check(&self, value: &T) -> Option<&str> {
+        self.checks
+            .iter()
+            .find(|check| !(check.test)(value))
+            .map(|check| check.message.as_str())
+    }
+}
+
+// A yes/no answer that Prompt can parse:
+// Prompt::new("Play again? (y/n)").default(YesNo(false)).ask()
+#[derive(Clone, Copy, PartialEq)]
+pub struct YesNo(pub bool);
+
+impl FromStr for YesNo {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<YesNo, String> {
+        match s.to_lowercase().as_str() {
+            "y" | "yes" => Ok(YesNo(true)),
+            "n" | "no" => Ok(YesNo(false)),
+            _ => Err(String::from("answer y or n")),
+        }
+    }
+}
+
+impl Display for YesNo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", if self.0 { "y" } else { "n" })
+    }
+}
+
+// Ask for a plain line of text; None if input has ended
+pub fn ask_line(message: &str) -> Option<String> {
+    print!("{}: ", message);
+    io::stdout().flush().ok()?;
+    read_line(&mut io::stdin().lock()).map(|line| line.trim().to_string())
+}
+
+// Reads one line; None means end of input (0 bytes read) or a read error
+fn read_line(input: &mut impl BufRead) -> Option<String> {
+    let mut line = String::new();
+    match input.read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line),
+    }
+}
+
+// Turn a parse error into a friendly sentence.
+// T::Err is generic, so we use Any to check whether it is one of the
+// number errors we know how to describe in more detail.
+// Returns None for other error types, so ask_from falls back to the error's own message.
+fn explain(error: &dyn Any, text: &str) -> Option<String> {
+    if text.is_empty() {
+        return Some(String::from("Please type something before pressing Enter."));
+    }
+
+    if let Some(e) = error.downcast_ref::<ParseIntError>() {
+        let reason = match e.kind() {
+            IntErrorKind::PosOverflow => format!("'{}' is too large for this number type.", text),
+            IntErrorKind::NegOverflow => format!("'{}' is too small for this number type.", text),
+            // "-1" for a u32: a whole number, just not one that can be negative
+            _ if text.starts_with('-') && text[1..].parse::<u128>().is_ok() => {
+                format!("'{}' is negative, but this number can't be.", text)
+            }
+            _ if text.parse::<f64>().is_ok() => {
+                format!("'{}' has a decimal part, but a whole number is needed.", text)
+            }
+            _ => format!("'{}' is not a whole number (try something like 42).", text),
+        };
+        return Some(reason);
+    }
+
+    if error.is::<ParseFloatError>() {
+        return Some(format!("'{}' is not a number (try something like 3.14).", text));
+    }
+
+    None
+}
+
+// ask_from with scripted input: every answer the user "types" is a line of
+// the input, and everything the prompt printed ends up in `output`
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<T>(prompt: &Prompt<T>, input: &str) -> (Option<T>, String)
+    where
+        T: FromStr + Display + Clone,
+        T::Err: Display + 'static,
+    {
+        let mut output = Vec::new();
+        let answer = prompt.ask_from(&mut input.as_bytes(), &mut output);
+        (answer, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn asks_again_after_input_that_does_not_parse() {
+        let (answer, output) = run(&Prompt::<u32>::new("Age"), "abc\n4.5\n42\n");
+        assert_eq!(answer, Some(42));
+        assert_eq!(output.matches("Age: ").count(), 3);
+        assert!(output.contains("'abc' is not a whole number"), "{}", output);
+        assert!(output.contains("'4.5' has a decimal part"), "{}", output);
+    }
+
+    #[test]
+    fn explains_numbers_that_do_not_fit() {
+        let (answer, output) = run(&Prompt::<u8>::new("Level"), "300\n-1\n7\n");
+        assert_eq!(answer, Some(7));
+        assert!(output.contains("'300' is too large"), "{}", output);
+        assert!(output.contains("'-1' is negative, but this number can't be."), "{}", output);
+    }
+
+    #[test]
+    fn empty_input_gives_the_default() {
+        let (answer, output) = run(&Prompt::new("Age").default(30u32), "\n");
+        assert_eq!(answer, Some(30));
+        assert_eq!(output, "Age [30]: ");
+    }
+
+    #[test]
+    fn empty_input_without_a_default_asks_again() {
+        let (answer, output) = run(&Prompt::<u32>::new("Age"), "\n  \n18\n");
+        assert_eq!(answer, Some(18));
+        assert_eq!(output.matches("Please type something before pressing Enter.").count(), 2);
+    }
+
+    #[test]
+    fn a_failed_check_shows_its_message_and_asks_again() {
+        let prompt = Prompt::<u32>::new("Age").check(|n| *n < 150, "that seems a bit old").check(|n| *n > 0, "too young");
+        let (answer, output) = run(&prompt, "200\n0\n20\n");
+        assert_eq!(answer, Some(20));
+        assert!(output.contains("  that seems a bit old. Please try again."), "{}", output);
+        assert!(output.contains("  too young. Please try again."), "{}", output);
+    }
+
+    #[test]
+    fn the_default_is_not_checked_again() {
+        let prompt = Prompt::new("Age").default(0u32).check(|n| *n > 0, "too young");
+        assert_eq!(run(&prompt, "\n").0, Some(0));
+    }
+
+    #[test]
+    fn end_of_input_gives_none() {
+        assert_eq!(run(&Prompt::<u32>::new("Age"), "").0, None);
+        assert_eq!(run(&Prompt::<u32>::new("Age"), "abc\n").0, None);
+    }
+
+    #[test]
+    fn yes_no_answers() {
+        let prompt = Prompt::new("Again? (y/n)").default(YesNo(false));
+        assert!(run(&prompt, "YES\n").0 == Some(YesNo(true)));
+        assert!(run(&prompt, "n\n").0 == Some(YesNo(false)));
+        let (answer, output) = run(&prompt, "maybe\ny\n");
+        assert!(answer == Some(YesNo(true)));
+        assert!(output.contains("'maybe' was not understood (answer y or n)."), "{}", output);
+    }
+}
@@ -0,0 +1,284 @@
+// Predict the Output Quiz
+// Like questions.md Question 1 ("What happens when you run this code?"),
+// but your answer is checked against reality: each snippet is compiled and
+// run with your local rustc, and you see the real compiler messages.
+// To compile and run: rustc quiz.rs && ./quiz
+//   ./quiz          go through every question
+//   ./quiz 3        answer just question 3
+//   ./quiz --list   show the questions
+
+mod prompt;
+mod sandbox;
+
+use prompt::{ask_line, Prompt, YesNo};
+use sandbox::{Limits, Sandbox, Status};
+use std::env;
+use std::process;
+
+// A snippet whose behaviour the learner predicts
+struct PredictQuestion {
+    title: &'static str,
+    from: &'static str, // where the idea comes from
+    code: &'static str,
+}
+
+// What the learner thinks will happen
+enum Prediction {
+    CompileError(Option<String>), // error code like "E0382", if they know it
+    Output(String),               // exact stdout
+}
+
+// What actually happened
+enum Actual {
+    CompileError { codes: Vec<String>, diagnostics: String },
+    Ran { stdout: String, stderr: String, status: Status },
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|a| a == "--list") {
+        for (i, question) in QUESTIONS.iter().enumerate() {
+            println!("{}. {} ({})", i + 1, question.title, question.from);
+        }
+        return;
+    }
+
+    let chosen: Vec<usize> = match args.first() {
+        None => (0..QUESTIONS.len()).collect(),
+        Some(n) => match n.parse::<usize>() {
+            Ok(n) if (1..=QUESTIONS.len()).contains(&n) => vec![n - 1],
+            _ => {
+                eprintln!("Error: pick a question from 1 to {} (see --list)", QUESTIONS.len());
+                process::exit(2);
+            }
+        },
+    };
+
+    println!("=== Predict the Output ===");
+    let mut correct = 0;
+    for &i in &chosen {
+        match ask_question(i + 1, &QUESTIONS[i]) {
+            Some(true) => correct += 1,
+            Some(false) => {}
+            None => break, // input ended
+        }
+    }
+    println!("\nScore: {}/{}", correct, chosen.len());
+}
+
+// Returns whether the prediction was right, or None if input ran out
+fn ask_question(number: usize, question: &PredictQuestion) -> Option<bool> {
+    println!("\n--- Question {}: {} ---", number, question.title);
+    println!("(from {})\n", question.from);
+    for line in question.code.lines() {
+        println!("    {}", line);
+    }
+    println!();
+
+    let prediction = ask_prediction()?;
+
+    println!("\nCompiling and running with rustc...");
+    let actual = match compile_and_run(question.code) {
+        Ok(actual) => actual,
+        Err(e) => {
+            println!("Could not run rustc ({}). Is Rust installed and on your PATH?", e);
+            return Some(false);
+        }
+    };
+
+    let right = judge(&prediction, &actual);
+    if right {
+        println!("✅ Correct!");
+    } else {
+        println!("❌ Not quite.");
+    }
+    explain(&actual, right);
+    Some(right)
+}
+
+fn ask_prediction() -> Option<Prediction> {
+    let YesNo(compiles) = Prompt::new("Does it compile? (y/n)").ask()?;
+    if !compiles {
+        let code = ask_line("Which error code? (e.g. E0382, or press Enter if unsure)")?;
+        let code = normalize_code(&code);
+        return Some(Prediction::CompileError(code));
+    }
+
+    println!("What does it print? Type each line, then an empty line to finish:");
+    let mut lines = Vec::new();
+    loop {
+        let line = ask_line("output")?;
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+    Some(Prediction::Output(lines.join("\n")))
+}
+
+// "e382", "0382" and "E0382" all mean E0382
+fn normalize_code(text: &str) -> Option<String> {
+    let digits = text.trim().trim_start_matches(['E', 'e']);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("E{:0>4}", digits))
+}
+
+fn compile_and_run(code: &str) -> std::io::Result<Actual> {
+    let sandbox = Sandbox::new()?;
+    let limits = Limits::default();
+    sandbox.write("main.rs", code)?;
+
+    let compiled = sandbox.compile("main.rs", &[], &limits)?;
+    if !compiled.success {
+        return Ok(Actual::CompileError {
+            codes: compiled.error_codes,
+            diagnostics: compiled.diagnostics,
+        });
+    }
+
+    let run = sandbox.run(&[], "", &limits)?;
+    Ok(Actual::Ran {
+        stdout: run.stdout,
+        stderr: run.stderr,
+        status: run.status,
+    })
+} // sandbox is dropped here and its temporary directory is deleted
+
+fn judge(prediction: &Prediction, actual: &Actual) -> bool {
+    match (prediction, actual) {
+        (Prediction::CompileError(None), Actual::CompileError { .. }) => true,
+        (Prediction::CompileError(Some(code)), Actual::CompileError { codes, .. }) => codes.contains(code),
+        (Prediction::Output(expected), Actual::Ran { stdout, .. }) => same_output(expected, stdout),
+        _ => false,
+    }
+}
+
+// Exact match, except trailing spaces and the final newline are hard to type
+fn same_output(expected: &str, actual: &str) -> bool {
+    let expected: Vec<&str> = expected.lines().map(|l| l.trim_end()).collect();
+    let actual: Vec<&str> = actual.lines().map(|l| l.trim_end()).collect();
+    expected == actual
+}
+
+fn explain(actual: &Actual, right: bool) {
+    match actual {
+        Actual::CompileError { codes, diagnostics } => {
+            if codes.is_empty() {
+                println!("It does not compile.");
+            } else {
+                println!("It does not compile: {}", codes.join(", "));
+            }
+            // The real compiler explanation is the best teacher when you were wrong
+            if !right {
+                println!("\nHere is what rustc says:\n{}", diagnostics.trim_end());
+            } else if let Some(code) = codes.first() {
+                println!("(Run `rustc --explain {}` for the full story.)", code);
+            }
+        }
+        Actual::Ran { stdout, stderr, status } => {
+            println!("It compiles, and prints:");
+            for line in stdout.lines() {
+                println!("    {}", line);
+            }
+            match status {
+                Status::Exited(0) => {}
+                Status::Exited(101) => println!("...and then panics:\n{}", stderr.trim_end()),
+                Status::Exited(code) => println!("...and exits with code {}", code),
+                Status::Crashed(_) => println!("...and then crashes"),
+                Status::TimedOut => println!("...and was stopped because it ran too long"),
+                Status::TooMuchOutput => println!("...and was stopped because it printed too much"),
+            }
+        }
+    }
+}
+
+// THE QUESTIONS
+
+const QUESTIONS: [PredictQuestion; 7] = [
+    PredictQuestion {
+        title: "Using a value after a move",
+        from: "questions.md, Question 1",
+        code: r#"fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{}", s1);
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Copy vs move",
+        from: "Lesson 3, Copy vs Move",
+        code: r#"fn main() {
+    let x = 5;
+    let y = x;
+    println!("x: {}, y: {}", x, y);
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Shadowing",
+        from: "Lesson 1, Variable Shadowing",
+        code: r#"fn main() {
+    let z = 20;
+    let z = z + 5;
+    let z = z * 2;
+    println!("The value of z is: {}", z);
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Mutable and immutable borrows together",
+        from: "questions.md, Question 2",
+        code: r#"fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r3 = &mut s;
+    r3.push_str("!");
+    println!("{}", r1);
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Borrows end at their last use",
+        from: "questions.md, Question 2",
+        code: r#"fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    println!("{}", r1);
+    let r3 = &mut s;
+    r3.push_str("!");
+    println!("{}", s);
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Exclusive ranges",
+        from: "Lesson 2, Loops",
+        code: r#"fn main() {
+    for i in 1..4 {
+        println!("Number: {}", i);
+    }
+}
+"#,
+    },
+    PredictQuestion {
+        title: "Printing a Result",
+        from: "Lesson 5, Result Basics",
+        code: r#"fn divide(a: i32, b: i32) -> Result<i32, String> {
+    if b == 0 {
+        Err(String::from("cannot divide by zero"))
+    } else {
+        Ok(a / b)
+    }
+}
+
+fn main() {
+    println!("{:?}", divide(10, 2));
+    println!("{:?}", divide(10, 0));
+}
+"#,
+    },
+];
@@ -0,0 +1,535 @@
+// Shared module: Compile and Run Snippets
+// Writes Rust source into a fresh temporary directory, compiles it with the
+// local rustc and runs the result, killing anything that takes too long or
+// prints too much.
+// On Linux the program also runs under CPU-time and memory limits (rlimits).
+// The directory is deleted automatically when the Sandbox is dropped.
+// This keeps honest mistakes (infinite loops, runaway allocations) contained;
+// it is not a security boundary against deliberately hostile code.
+//
+// To use it from a program in this folder, add `mod sandbox;` at the top, then:
+//     let sandbox = Sandbox::new()?;
+//     sandbox.write("main.rs", code)?;
+//     let compiled = sandbox.compile("main.rs", &[], &Limits::default())?;
+//     if compiled.success { let run = sandbox.run(&[], "", &Limits::default())?; }
+// Tests: rustc --test sandbox.rs -o sandbox-tests && ./sandbox-tests
+
+#![allow(dead_code)] // not every program uses every helper
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::process::{ChildStdin, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Name of the compiled program inside the sandbox directory
+pub const BINARY: &str = "main";
+
+// Gives each Sandbox in this process its own directory name
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Time and output limits apply everywhere; cpu_seconds and memory_bytes
+// only apply to run() (rustc itself needs plenty of both)
+pub struct Limits {
+    pub compile_time: Duration,
+    pub run_time: Duration,
+    pub cpu_seconds: Option<u64>,
+    pub memory_bytes: Option<u64>,
+    pub output_bytes: usize, // stdout and stderr together
+    pub line_bytes: usize,   // the longest line run_streaming() will hand over
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            compile_time: Duration::from_secs(20),
+            run_time: Duration::from_secs(5),
+            cpu_seconds: None,
+            memory_bytes: None,
+            output_bytes: 4 * 1024 * 1024,
+            line_bytes: 64 * 1024,
+        }
+    }
+}
+
+// Signals a limited program can be stopped by (Linux numbering)
+pub const SIGABRT: i32 = 6; // abort, e.g. "memory allocation failed"
+pub const SIGKILL: i32 = 9; // killed outright (past the hard CPU limit)
+pub const SIGSEGV: i32 = 11; // bad memory access, e.g. stack overflow
+pub const SIGXCPU: i32 = 24; // CPU time limit reached
+
+// How a child process finished
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Exited(i32),
+    Crashed(Option<i32>), // killed by a signal (the number, where the OS tells us)
+    TimedOut,             // we killed it for running past the wall-clock limit
+    TooMuchOutput,        // we killed it for printing past the output limits
+}
+
+pub struct Compiled {
+    pub success: bool,
+    pub status: Status,
+    pub diagnostics: String,      // everything rustc printed
+    pub error_codes: Vec<String>, // e.g. ["E0382"], in the order rustc reported them
+    pub errors: Vec<String>,      // "error[E0425]: cannot find ... (main.rs:3:5)"
+    pub warnings: Vec<String>,    // "unused variable: `x` (main.rs:2:9)"
+}
+
+// Which pipe a line of output came from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+pub struct Run {
+    pub status: Status,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Run {
+    pub fn success(&self) -> bool {
+        self.status == Status::Exited(0)
+    }
+
+    // Rust exits with code 101 when a program panics
+    pub fn panicked(&self) -> bool {
+        self.status == Status::Exited(101)
+    }
+}
+
+pub struct Sandbox {
+    pub dir: PathBuf,
+}
+
+impl Sandbox {
+    pub fn new() -> io::Result<Sandbox> {
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("rust-learning-{}-{}", std::process::id(), id));
+        fs::create_dir_all(&dir)?;
+        Ok(Sandbox { dir })
+    }
+
+    // `name` may include folders, like "geometry/rectangle.rs"
+    pub fn write(&self, name: &str, contents: &str) -> io::Result<PathBuf> {
+        let path = self.dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+        Ok(path)
+    }
+
+    // Compile `file` (already written into the sandbox) into the BINARY program.
+    // Extra arguments go straight to rustc, e.g. &["--test"].
+    pub fn compile(&self, file: &str, extra_args: &[&str], limits: &Limits) -> io::Result<Compiled> {
+        let mut command = Command::new("rustc");
+        command
+            .current_dir(&self.dir)
+            .args(["--edition", "2021", "--color", "never", "-o", BINARY, file])
+            .args(extra_args);
+
+        let (status, _, diagnostics) = run_with_limit(command, "", limits.compile_time, limits.output_bytes)?;
+        Ok(Compiled {
+            success: status == Status::Exited(0),
+            error_codes: error_codes(&diagnostics),
+            errors: headlines(&diagnostics, "error"),
+            warnings: headlines(&diagnostics, "warning: "),
+            status,
+            diagnostics,
+        })
+    }
+
+    // Run the compiled BINARY with the given arguments and stdin,
+    // with a clean environment so it only sees what we pass in
+    pub fn run(&self, args: &[&str], stdin: &str, limits: &Limits) -> io::Result<Run> {
+        let mut command = Command::new(self.dir.join(BINARY));
+        command.current_dir(&self.dir).args(args).env_clear();
+        apply_rlimits(&mut command, limits);
+        let (status, stdout, stderr) = run_with_limit(command, stdin, limits.run_time, limits.output_bytes)?;
+        Ok(Run { status, stdout, stderr })
+    }
+
+    // Like run(), but hands over each line of output as soon as the program
+    // prints it, instead of everything at the end
+    pub fn run_streaming(
+        &self,
+        args: &[&str],
+        stdin: &str,
+        limits: &Limits,
+        mut on_line: impl FnMut(Stream, &str),
+    ) -> io::Result<Status> {
+        let mut command = Command::new(self.dir.join(BINARY));
+        command.current_dir(&self.dir).args(args).env_clear();
+        apply_rlimits(&mut command, limits);
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let budget = Budget::new(limits.output_bytes);
+        let (sender, lines) = mpsc::channel();
+        let readers = [
+            lines_in_background(child.stdout.take(), Stream::Stdout, sender.clone(), budget.clone(), limits.line_bytes),
+            lines_in_background(child.stderr.take(), Stream::Stderr, sender, budget.clone(), limits.line_bytes),
+        ];
+        feed_stdin(child.stdin.take(), stdin);
+
+        let started = Instant::now();
+        let status = loop {
+            for (stream, line) in lines.try_iter() {
+                on_line(stream, &line);
+            }
+            if let Some(exit) = child.try_wait()? {
+                break status_of(exit);
+            }
+            if budget.exceeded() {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Status::TooMuchOutput;
+            }
+            if started.elapsed() > limits.run_time {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Status::TimedOut;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        // Whatever was printed just before the program ended
+        for reader in readers {
+            let _ = reader.join();
+        }
+        for (stream, line) in lines.try_iter() {
+            on_line(stream, &line);
+        }
+        Ok(if budget.exceeded() { Status::TooMuchOutput } else { status })
+    }
+}
+
+// Clean up the temporary directory when the Sandbox goes out of scope
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir); // nothing useful to do if this fails
+    }
+}
+
+// Pull the codes out of lines like "error[E0382]: borrow of moved value: `s1`"
+pub fn error_codes(diagnostics: &str) -> Vec<String> {
+    diagnostics
+        .lines()
+        .filter_map(|line| line.strip_prefix("error["))
+        .filter_map(|rest| rest.split_once(']'))
+        .map(|(code, _)| code.to_string())
+        .collect()
+}
+
+// One line per diagnostic of the given kind: its headline plus where it points.
+//   warning: unused variable: `x`        becomes   unused variable: `x` (main.rs:2:9)
+//    --> main.rs:2:9
+fn headlines(diagnostics: &str, prefix: &str) -> Vec<String> {
+    let lines: Vec<&str> = diagnostics.lines().collect();
+    let mut found = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        // Skip the summary lines: "error: aborting due to ..." and "warning: 2 warnings emitted"
+        if !line.starts_with(prefix) || line.starts_with("error: aborting") || line.ends_with("emitted") {
+            continue;
+        }
+        let headline = line.strip_prefix("warning: ").unwrap_or(line);
+        let location = lines
+            .get(i + 1)
+            .and_then(|next| next.trim_start().strip_prefix("--> "));
+        found.push(match location {
+            Some(location) => format!("{} ({})", headline, location),
+            None => headline.to_string(),
+        });
+    }
+    found
+}
+
+// rlimits are set in the child between fork and exec, so they only
+// limit the program being run, never this process
+#[cfg(target_os = "linux")]
+fn apply_rlimits(command: &mut Command, limits: &Limits) {
+    use std::os::unix::process::CommandExt;
+
+    #[repr(C)]
+    struct RLimit {
+        current: u64, // soft limit: the process gets a signal
+        maximum: u64, // hard limit: the process is killed
+    }
+
+    extern "C" {
+        fn setrlimit(resource: i32, limit: *const RLimit) -> i32;
+    }
+
+    const RLIMIT_CPU: i32 = 0;
+    const RLIMIT_AS: i32 = 9; // address space, i.e. memory
+
+    fn set(resource: i32, current: u64, maximum: u64) -> io::Result<()> {
+        let limit = RLimit { current, maximum };
+        // SAFETY: setrlimit only reads the struct we pass a pointer to
+        match unsafe { setrlimit(resource, &limit) } {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+
+    let cpu = limits.cpu_seconds;
+    let memory = limits.memory_bytes;
+    // SAFETY: the closure runs in the forked child before exec, where only
+    // async-signal-safe functions may be called; setrlimit is one of them
+    unsafe {
+        command.pre_exec(move || {
+            if let Some(seconds) = cpu {
+                set(RLIMIT_CPU, seconds, seconds + 1)?; // SIGXCPU first, SIGKILL a second later
+            }
+            if let Some(bytes) = memory {
+                set(RLIMIT_AS, bytes, bytes)?;
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn apply_rlimits(_command: &mut Command, _limits: &Limits) {
+    // Only the wall-clock limit applies on other systems
+}
+
+#[cfg(unix)]
+fn signal_of(status: &ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn signal_of(_status: &ExitStatus) -> Option<i32> {
+    None
+}
+
+// Spawn the command, feed it stdin, and wait at most `limit`, or until it
+// has printed more than `output_bytes`.
+// stdout and stderr are read on their own threads: if we waited for the child
+// first, a child that fills up a pipe buffer would wait for us forever.
+fn run_with_limit(
+    mut command: Command,
+    stdin: &str,
+    limit: Duration,
+    output_bytes: usize,
+) -> io::Result<(Status, String, String)> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let budget = Budget::new(output_bytes);
+    let stdout = read_in_background(child.stdout.take(), budget.clone());
+    let stderr = read_in_background(child.stderr.take(), budget.clone());
+
+    feed_stdin(child.stdin.take(), stdin);
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(exit) = child.try_wait()? {
+            break status_of(exit);
+        }
+        if budget.exceeded() {
+            let _ = child.kill();
+            let _ = child.wait();
+            break Status::TooMuchOutput;
+        }
+        if started.elapsed() > limit {
+            let _ = child.kill();
+            let _ = child.wait();
+            break Status::TimedOut;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    // It may have finished with more output still in the pipes than allowed
+    let status = if budget.exceeded() { Status::TooMuchOutput } else { status };
+    Ok((status, stdout, stderr))
+}
+
+fn status_of(exit: ExitStatus) -> Status {
+    match exit.code() {
+        Some(code) => Status::Exited(code),
+        None => Status::Crashed(signal_of(&exit)),
+    }
+}
+
+// Writing can block (if the child never reads), so it gets its own thread
+fn feed_stdin(input: Option<ChildStdin>, stdin: &str) {
+    let stdin = stdin.to_string();
+    thread::spawn(move || {
+        if let Some(mut input) = input {
+            // A program that never reads stdin closes the pipe early; that's fine
+            let _ = input.write_all(stdin.as_bytes());
+        } // input is dropped here, which closes the pipe so the child sees end of input
+    });
+}
+
+// How many bytes a child has printed, counted by the threads reading its
+// pipes. Once it is over the limit they stop reading and the child is killed,
+// so a program printing in an endless loop can't fill up our memory.
+#[derive(Clone)]
+struct Budget {
+    used: Arc<AtomicUsize>,
+    limit: usize,
+}
+
+impl Budget {
+    fn new(limit: usize) -> Budget {
+        Budget { used: Arc::new(AtomicUsize::new(0)), limit }
+    }
+
+    // Count `n` more bytes; false once the total is over the limit
+    fn spend(&self, n: usize) -> bool {
+        self.used.fetch_add(n, Ordering::SeqCst).saturating_add(n) <= self.limit
+    }
+
+    // Used up all at once, e.g. by a line that is too long
+    fn use_up(&self) {
+        self.used.fetch_max(self.limit.saturating_add(1), Ordering::SeqCst);
+    }
+
+    fn exceeded(&self) -> bool {
+        self.used.load(Ordering::SeqCst) > self.limit
+    }
+}
+
+// Send each line (without its newline) down the channel as it arrives.
+// A line longer than `line_bytes` is sent cut short, and uses up the budget.
+fn lines_in_background(
+    pipe: Option<impl Read + Send + 'static>,
+    stream: Stream,
+    sender: Sender<(Stream, String)>,
+    budget: Budget,
+    line_bytes: usize,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let Some(pipe) = pipe else { return };
+        let mut reader = BufReader::new(pipe);
+        let mut bytes = Vec::new();
+        let send = |bytes: &[u8]| {
+            let line = String::from_utf8_lossy(&bytes[..bytes.len().min(line_bytes)]);
+            let _ = sender.send((stream, line.trim_end_matches(['\n', '\r']).to_string()));
+        };
+        loop {
+            // Read up to the next newline, a buffer at a time, so a line with
+            // no end can't grow without limit the way read_until() would let it
+            let (n, line_ended) = match reader.fill_buf() {
+                Ok([]) | Err(_) => break,
+                Ok(buffer) => match buffer.iter().position(|&b| b == b'\n') {
+                    Some(i) => {
+                        bytes.extend_from_slice(&buffer[..=i]);
+                        (i + 1, true)
+                    }
+                    None => {
+                        bytes.extend_from_slice(buffer);
+                        (buffer.len(), false)
+                    }
+                },
+            };
+            reader.consume(n);
+            if bytes.len() > line_bytes {
+                budget.use_up();
+            }
+            let over = !budget.spend(n);
+            if line_ended || over {
+                send(&bytes);
+                bytes.clear();
+            }
+            if over {
+                return;
+            }
+        }
+        if !bytes.is_empty() {
+            send(&bytes); // the last line had no newline
+        }
+    })
+}
+
+// Everything the pipe gives, up to what the budget allows
+fn read_in_background(pipe: Option<impl Read + Send + 'static>, budget: Budget) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let mut chunk = [0; 8192];
+            loop {
+                match pipe.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(n) if budget.spend(n) => bytes.extend_from_slice(&chunk[..n]),
+                    Ok(_) => break,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(_) => break,
+                }
+            }
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compiled(code: &str) -> Sandbox {
+        let sandbox = Sandbox::new().expect("a temporary directory");
+        sandbox.write("main.rs", code).expect("writing main.rs");
+        let compiled = sandbox.compile("main.rs", &[], &Limits::default()).expect("running rustc");
+        assert!(compiled.success, "{}", compiled.diagnostics);
+        sandbox
+    }
+
+    const SMALL: Limits = Limits {
+        compile_time: Duration::from_secs(20),
+        run_time: Duration::from_secs(20),
+        cpu_seconds: None,
+        memory_bytes: None,
+        output_bytes: 1024 * 1024,
+        line_bytes: 16 * 1024,
+    };
+
+    #[test]
+    fn a_flood_of_output_is_stopped() {
+        let sandbox = compiled("fn main() { loop { println!(\"{}\", \"x\".repeat(1 << 16)); } }");
+        let run = sandbox.run(&[], "", &SMALL).expect("running the program");
+        assert_eq!(run.status, Status::TooMuchOutput);
+        assert!(run.stdout.len() <= SMALL.output_bytes, "kept {} bytes", run.stdout.len());
+    }
+
+    #[test]
+    fn a_line_that_never_ends_is_cut_short() {
+        let sandbox = compiled("fn main() { loop { print!(\"x\"); } }");
+        let mut longest = 0;
+        let status = sandbox
+            .run_streaming(&[], "", &SMALL, |_, line| longest = longest.max(line.len()))
+            .expect("running the program");
+        assert_eq!(status, Status::TooMuchOutput);
+        assert_eq!(longest, SMALL.line_bytes);
+    }
+
+    #[test]
+    fn output_under_the_limits_is_all_kept() {
+        let sandbox = compiled("fn main() { for i in 0..1000 { println!(\"line {}\", i); } eprintln!(\"done\"); }");
+        let mut lines = Vec::new();
+        let status = sandbox
+            .run_streaming(&[], "", &SMALL, |stream, line| lines.push((stream, line.to_string())))
+            .expect("running the program");
+        assert_eq!(status, Status::Exited(0));
+        assert_eq!(lines.iter().filter(|(stream, _)| *stream == Stream::Stdout).count(), 1000);
+        assert!(lines.contains(&(Stream::Stderr, String::from("done"))));
+    }
+}
@@ -0,0 +1,247 @@
+// Lesson Snapshot Tests
+// Every lesson prints a long, fixed transcript. This compiles and runs each
+// lesson, then compares what it printed with the checked-in snapshot next to
+// it (lesson-2/lesson2.snap), so an edit can't change the output by accident.
+// To compile and run: rustc snapshots.rs && ./snapshots
+//   ./snapshots            check every lesson (exit code 1 if any differ)
+//   ./snapshots 2 3        check only lessons 2 and 3
+//   ./snapshots --update   accept the current output as the new snapshots
+//
+// Run it from this folder or from the top of the repository.
+
+mod sandbox;
+
+use sandbox::{Limits, Sandbox};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+// Lesson modes that print something different (and deterministic) are
+// snapshotted too: ./lesson3 trace -> lesson-3/lesson3-trace.snap.
+// (lesson 3's memory mode prints real addresses, so it can't be snapshotted.)
+const EXTRA_MODES: [(u32, &str); 1] = [(3, "trace")];
+
+// Unchanged lines shown around each difference
+const CONTEXT: usize = 2;
+
+// One program run whose output is compared with a snapshot
+struct Case {
+    lesson: u32,
+    source: PathBuf,
+    args: Vec<&'static str>,
+    snapshot: PathBuf,
+}
+
+enum Outcome {
+    Same,
+    Updated,
+    Missing,
+    Different(String), // the diff to show
+    Broken(String),    // didn't compile or didn't exit cleanly
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let update = args.iter().any(|a| a == "--update");
+    let mut only = Vec::new();
+    for arg in args.iter().filter(|a| *a != "--update") {
+        match arg.parse::<u32>() {
+            Ok(n) => only.push(n),
+            Err(_) => {
+                eprintln!("Error: '{}' is not a lesson number", arg);
+                eprintln!("Usage: snapshots [--update] [lesson numbers...]");
+                process::exit(2);
+            }
+        }
+    }
+
+    let Some(root) = find_root() else {
+        eprintln!("Error: no lesson-1 folder here or one level up; run this from the repository");
+        process::exit(2);
+    };
+    let cases: Vec<Case> = find_cases(&root)
+        .into_iter()
+        .filter(|case| only.is_empty() || only.contains(&case.lesson))
+        .collect();
+
+    let mut failed = 0;
+    for case in &cases {
+        let name = case.snapshot.display();
+        match check(case, update) {
+            Outcome::Same => println!("ok       {}", name),
+            Outcome::Updated => println!("updated  {}", name),
+            Outcome::Missing => {
+                println!("MISSING  {} (run with --update to create it)", name);
+                failed += 1;
+            }
+            Outcome::Different(diff) => {
+                println!("CHANGED  {}", name);
+                println!("{}", diff);
+                failed += 1;
+            }
+            Outcome::Broken(reason) => {
+                println!("BROKEN   {}", name);
+                println!("{}", reason.trim_end());
+                failed += 1;
+            }
+        }
+    }
+
+    println!("\n{} snapshot(s), {} failed", cases.len(), failed);
+    if failed > 0 {
+        if !update {
+            println!("If the new output is what you want, run again with --update.");
+        }
+        process::exit(1);
+    }
+}
+
+// The repository root is the folder holding lesson-1
+fn find_root() -> Option<PathBuf> {
+    [".", ".."]
+        .iter()
+        .map(PathBuf::from)
+        .find(|dir| dir.join("lesson-1").is_dir())
+}
+
+// lesson-N/lessonN.rs for every N that exists, plus the EXTRA_MODES
+fn find_cases(root: &Path) -> Vec<Case> {
+    let mut cases = Vec::new();
+    for lesson in 1.. {
+        let dir = root.join(format!("lesson-{}", lesson));
+        let source = dir.join(format!("lesson{}.rs", lesson));
+        if !source.is_file() {
+            break;
+        }
+        cases.push(Case {
+            lesson,
+            source: source.clone(),
+            args: Vec::new(),
+            snapshot: dir.join(format!("lesson{}.snap", lesson)),
+        });
+        for &(_, mode) in EXTRA_MODES.iter().filter(|(n, _)| *n == lesson) {
+            cases.push(Case {
+                lesson,
+                source: source.clone(),
+                args: vec![mode],
+                snapshot: dir.join(format!("lesson{}-{}.snap", lesson, mode)),
+            });
+        }
+    }
+    cases
+}
+
+fn check(case: &Case, update: bool) -> Outcome {
+    let actual = match run_lesson(case) {
+        Ok(output) => output,
+        Err(reason) => return Outcome::Broken(reason),
+    };
+
+    let expected = fs::read_to_string(&case.snapshot).ok();
+    if expected.as_deref() == Some(actual.as_str()) {
+        return Outcome::Same;
+    }
+    if update {
+        return match fs::write(&case.snapshot, &actual) {
+            Ok(()) => Outcome::Updated,
+            Err(e) => Outcome::Broken(format!("could not write the snapshot: {}", e)),
+        };
+    }
+    match expected {
+        Some(expected) => Outcome::Different(diff(&expected, &actual)),
+        None => Outcome::Missing,
+    }
+}
+
+// Compile and run the lesson, capturing what it prints
+fn run_lesson(case: &Case) -> Result<String, String> {
+    let sandbox = Sandbox::new().map_err(|e| e.to_string())?;
+    let limits = Limits::default();
+    // An absolute path, so rustc finds sibling modules like lesson-3/trace.rs
+    let source = fs::canonicalize(&case.source).map_err(|e| e.to_string())?;
+    let compiled = sandbox
+        .compile(&source.to_string_lossy(), &[], &limits)
+        .map_err(|e| format!("could not run rustc: {}", e))?;
+    if !compiled.success {
+        return Err(compiled.diagnostics);
+    }
+
+    let run = sandbox.run(&case.args, "", &limits).map_err(|e| e.to_string())?;
+    if !run.success() {
+        return Err(format!("exited with {:?}\n{}", run.status, run.stderr));
+    }
+    Ok(run.stdout)
+}
+
+// DIFF
+// A line diff from the longest common subsequence of the two transcripts,
+// printed like `diff -u`: "-" lines are in the snapshot, "+" lines are new.
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // common[i][j] = length of the longest common subsequence of old[i..] and new[j..]
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    // Keep only the changes and CONTEXT lines around them
+    let changed: Vec<usize> = (0..lines.len())
+        .filter(|&k| !matches!(lines[k], Line::Same(_)))
+        .collect();
+    let near_change = |k: usize| changed.iter().any(|&c| c.abs_diff(k) <= CONTEXT);
+
+    let mut out = Vec::new();
+    let mut skipped = false;
+    for (k, line) in lines.iter().enumerate() {
+        if !near_change(k) {
+            skipped = true;
+            continue;
+        }
+        if skipped && !out.is_empty() {
+            out.push(String::from("  ..."));
+        }
+        skipped = false;
+        out.push(match line {
+            Line::Same(text) => format!("   {}", text),
+            Line::Removed(text) => format!(" - {}", text),
+            Line::Added(text) => format!(" + {}", text),
+        });
+    }
+    if out.is_empty() {
+        // Same lines, so the difference is a missing newline at the very end
+        return String::from("   (only the final newline differs)");
+    }
+    out.join("\n")
+}
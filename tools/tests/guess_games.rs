@@ -0,0 +1,149 @@
+// Integration tests for guess.rs: whole games, scripted with --seed
+// To compile and run (from tools): rustc --test tests/guess_games.rs -o guess-games && ./guess-games
+//
+// With --seed 42 on easy the secret number is 14, so a script of guesses
+// always plays out the same way. Each test pipes its answers into the game's
+// stdin and checks what it printed, like lesson-12/tests/lesson_binaries.rs
+// does for the lessons.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::OnceLock;
+
+// The repository: the first folder (here or above) with a tools folder in it
+fn root() -> PathBuf {
+    let start = std::env::current_dir().expect("no current directory");
+    start
+        .ancestors()
+        .find(|dir| dir.join("tools").join("guess.rs").is_file())
+        .expect("run the tests from inside the repository")
+        .to_path_buf()
+}
+
+// guess.rs, compiled once into target/tool-binaries/
+fn guess() -> &'static Path {
+    static BINARY: OnceLock<PathBuf> = OnceLock::new();
+    BINARY.get_or_init(|| {
+        let root = root();
+        let out_dir = root.join("target").join("tool-binaries");
+        fs::create_dir_all(&out_dir).expect("could not create target/tool-binaries");
+        let binary = out_dir.join("guess");
+        let output = Command::new("rustc")
+            .arg(root.join("tools").join("guess.rs"))
+            .arg("-o")
+            .arg(&binary)
+            .output()
+            .expect("could not run rustc");
+        assert!(output.status.success(), "guess.rs did not compile:\n{}", String::from_utf8_lossy(&output.stderr));
+        binary
+    })
+}
+
+// A scores file of the test's own, removed first so every run starts empty
+fn scores_file(test: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("guess-games-{}-{}", std::process::id(), test));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+// Runs the game with these arguments, typing `input`, and returns its output
+fn play(args: &[&str], scores: &Path, input: &str) -> Output {
+    let mut child = Command::new(guess())
+        .args(args)
+        .arg("--scores")
+        .arg(scores)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not start guess");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).expect("could not write to guess");
+    child.wait_with_output().expect("guess did not finish")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "guess failed: {}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).expect("output is not UTF-8")
+}
+
+#[test]
+fn a_scripted_game_is_won() {
+    let scores = scores_file("win");
+    let output = stdout(&play(&["--seed", "42"], &scores, "easy\n10\n15\n13\n14\nn\n"));
+    assert!(output.contains("between 1 and 20. You have 6 attempts."), "{}", output);
+    assert!(output.contains("10 is too small!"), "{}", output);
+    assert!(output.contains("15 is too big!"), "{}", output);
+    assert!(output.contains("14 is correct! You got it in 4 attempt(s)."), "{}", output);
+    assert!(output.contains("New high score for easy!"), "{}", output);
+    assert!(output.ends_with("Thanks for playing!\n"), "{}", output);
+    assert_eq!(fs::read_to_string(&scores).unwrap(), "easy 4\n");
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn a_scripted_game_is_lost() {
+    let scores = scores_file("lose");
+    let output = stdout(&play(&["--seed", "42"], &scores, "easy\n1\n2\n3\n4\n5\n6\nn\n"));
+    assert!(output.contains("6 is too small!"), "{}", output);
+    assert!(output.contains("Out of attempts! The number was 14."), "{}", output);
+    assert!(output.contains("Your guesses: [1, 2, 3, 4, 5, 6]"), "{}", output);
+    assert!(!output.contains("is correct!"), "{}", output);
+    assert!(!output.contains("high score"), "{}", output);
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn the_same_seed_plays_the_same_game() {
+    let scores = scores_file("repeat");
+    let input = "easy\n1\n2\n3\n4\n5\n6\nn\n";
+    let first = stdout(&play(&["--seed", "7"], &scores, input));
+    let second = stdout(&play(&["--seed", "7"], &scores, input));
+    assert_eq!(first, second);
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn a_beaten_high_score_is_kept_and_reported() {
+    let scores = scores_file("high-score");
+    // Two games with the same seed, so the secret is 14 both times.
+    // The first game sets the record; a slower win doesn't replace it.
+    let output = stdout(&play(&["--seed", "42", "--difficulty", "easy"], &scores, "10\n15\n13\n14\nn\n"));
+    assert!(output.contains("New high score for easy!"), "{}", output);
+    let output = stdout(&play(&["--seed", "42", "--difficulty", "easy"], &scores, "1\n2\n3\n4\n14\nn\n"));
+    assert!(output.contains("14 is correct! You got it in 5 attempt(s)."), "{}", output);
+    assert!(output.contains("High score for easy is 4 attempt(s)."), "{}", output);
+    assert_eq!(fs::read_to_string(&scores).unwrap(), "easy 4\n");
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn out_of_range_and_repeated_guesses_do_not_count() {
+    let scores = scores_file("invalid");
+    let output = stdout(&play(&["--seed", "42", "--difficulty", "easy"], &scores, "25\nten\n10\n10\n14\nn\n"));
+    assert!(output.contains("Pick a number from 1 to 20. Please try again."), "{}", output);
+    assert!(output.contains("'ten' is not a whole number"), "{}", output);
+    assert!(output.contains("You already tried 10 - that one doesn't count."), "{}", output);
+    assert!(output.contains("You got it in 2 attempt(s)."), "{}", output);
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn input_ending_mid_game_stops_cleanly() {
+    let scores = scores_file("eof");
+    let output = stdout(&play(&["--seed", "42"], &scores, "easy\n10\n"));
+    assert!(output.contains("10 is too small!"), "{}", output);
+    assert!(output.ends_with("Thanks for playing!\n"), "{}", output);
+    let _ = fs::remove_file(&scores);
+}
+
+#[test]
+fn bad_options_exit_with_status_2() {
+    let scores = scores_file("usage");
+    let output = play(&["--seed", "forty-two"], &scores, "");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Error: --seed needs a whole number, got 'forty-two'"), "{}", stderr);
+    assert!(stderr.contains("Usage: guess"), "{}", stderr);
+}